@@ -10,6 +10,7 @@ import fs from 'fs'
 import ExitCodes from '../../ExitCodes'
 import { ContentId } from '@joystream/types/media'
 import ipfsHash from 'ipfs-only-hash'
+import { blake2bInit, blake2bUpdate, blake2bFinal } from 'blakejs'
 import { cli } from 'cli-ux'
 import axios, { AxiosRequestConfig } from 'axios'
 import { URL } from 'url'
@@ -96,6 +97,19 @@ export default class UploadVideoCommand extends MediaCommandBase {
     return hash
   }
 
+  // Blake2-256 hash of the whole file, committed in the runtime data object.
+  private async calculateFileContentHash(filePath: string, fileSize: number): Promise<string> {
+    const { fileStream } = this.createReadStreamWithProgressBar(filePath, 'Calculating content hash', fileSize)
+    const hashContext = blake2bInit(32)
+
+    await new Promise((resolve) => {
+      fileStream.on('data', (data) => blake2bUpdate(hashContext, data)).on('end', resolve)
+      fileStream.resume()
+    })
+
+    return '0x' + Buffer.from(blake2bFinal(hashContext)).toString('hex')
+  }
+
   private async getDiscoveryDataViaLocalIpfsNode(ipnsIdentity: string): Promise<any> {
     const ipfs = ipfsHttpClient({
       // TODO: Allow customizing node url:
@@ -380,11 +394,13 @@ export default class UploadVideoCommand extends MediaCommandBase {
     // Calculate hash and create content id
     const contentId = ContentId.generate(this.getTypesRegistry())
     const ipfsCid = await this.calculateFileIpfsHash(filePath, fileSize)
+    const contentHash = await this.calculateFileContentHash(filePath, fileSize)
 
     this.log('Video identification established:', {
       contentId: contentId.toString(),
       encodedContentId: contentId.encode(),
       ipfsHash: ipfsCid,
+      contentHash,
     })
 
     // Send dataDirectory.addContent extrinsic
@@ -394,6 +410,8 @@ export default class UploadVideoCommand extends MediaCommandBase {
       DATA_OBJECT_TYPE_ID,
       fileSize,
       ipfsCid,
+      contentHash,
+      null, // chunk manifest
     ])
 
     const dataObject = await this.getApi().dataObjectByContentId(contentId)
@@ -1,12 +1,19 @@
 use codec::Decode;
 use node_runtime::{
     common::constraints::InputValidationLengthConstraint,
-    forum::{Category, CategoryId, Post, Thread},
-    AccountId, BlockNumber, ForumConfig, Moment, PostId, ThreadId,
+    forum::{
+        self,
+        migration::{LegacyPost, LegacyThread},
+        Category, CategoryId, Post, Thread,
+    },
+    membership, AccountId, BlockNumber, ForumConfig, Hash, MemberId, Moment, PostId, Runtime,
+    ThreadId,
 };
 use serde::Deserialize;
 use std::{fs, path::Path};
 
+type GenesisMember = membership::genesis::Member<MemberId, AccountId, Moment>;
+
 fn new_validation(min: u16, max_min_diff: u16) -> InputValidationLengthConstraint {
     InputValidationLengthConstraint { min, max_min_diff }
 }
@@ -14,12 +21,16 @@ fn new_validation(min: u16, max_min_diff: u16) -> InputValidationLengthConstrain
 #[derive(Decode)]
 struct ForumData {
     categories: Vec<Category<BlockNumber, Moment, AccountId>>,
-    posts: Vec<Post<BlockNumber, Moment, AccountId, ThreadId, PostId>>,
-    threads: Vec<Thread<BlockNumber, Moment, AccountId, ThreadId>>,
+    posts: Vec<Post<BlockNumber, Moment, AccountId, MemberId, ThreadId, PostId, Hash>>,
+    threads: Vec<Thread<BlockNumber, Moment, AccountId, MemberId, ThreadId>>,
 }
 
 #[derive(Deserialize)]
 struct EncodedForumData {
+    /// whether threads and posts are encoded with the member based authorship,
+    /// the exports preceding it lack the field and are converted on decoding
+    #[serde(default)]
+    member_authorship: bool,
     /// hex encoded categories
     categories: Vec<String>,
     /// hex encoded posts
@@ -28,34 +39,73 @@ struct EncodedForumData {
     threads: Vec<String>,
 }
 
+fn decode_hex<T: Decode>(encoded: &str, name: &str) -> T {
+    let encoded = hex::decode(&encoded[2..].as_bytes())
+        .unwrap_or_else(|_| panic!("failed to parse {} hex string", name));
+    Decode::decode(&mut encoded.as_slice()).unwrap_or_else(|_| panic!("failed to decode {}", name))
+}
+
+/// Resolves the member id of the author account of an exported thread or post the same way
+/// the runtime upgrade does: controlled members first, rooted members next and the unknown
+/// author otherwise.
+fn resolve_author_id(members: &[GenesisMember], account_id: &AccountId) -> MemberId {
+    members
+        .iter()
+        .find(|member| member.controller_account == *account_id)
+        .or_else(|| {
+            members
+                .iter()
+                .find(|member| member.root_account == *account_id)
+        })
+        .map_or_else(forum::Module::<Runtime>::unknown_author_id, |member| {
+            member.member_id
+        })
+}
+
 impl EncodedForumData {
-    fn decode(&self) -> ForumData {
+    fn decode(&self, members: &[GenesisMember]) -> ForumData {
+        let categories = self
+            .categories
+            .iter()
+            .map(|category| decode_hex(category, "category"))
+            .collect();
+
+        if self.member_authorship {
+            return ForumData {
+                categories,
+                posts: self
+                    .posts
+                    .iter()
+                    .map(|post| decode_hex(post, "post"))
+                    .collect(),
+                threads: self
+                    .threads
+                    .iter()
+                    .map(|thread| decode_hex(thread, "thread"))
+                    .collect(),
+            };
+        }
+
         ForumData {
-            categories: self
-                .categories
-                .iter()
-                .map(|category| {
-                    let encoded_category = hex::decode(&category[2..].as_bytes())
-                        .expect("failed to parse category hex string");
-                    Decode::decode(&mut encoded_category.as_slice()).unwrap()
-                })
-                .collect(),
+            categories,
             posts: self
                 .posts
                 .iter()
                 .map(|post| {
-                    let encoded_post = hex::decode(&post[2..].as_bytes())
-                        .expect("failed to parse post hex string");
-                    Decode::decode(&mut encoded_post.as_slice()).unwrap()
+                    let post: LegacyPost<BlockNumber, Moment, AccountId, ThreadId, PostId> =
+                        decode_hex(post, "post");
+                    let author_id = resolve_author_id(members, post.author_account());
+                    post.into_post(author_id)
                 })
                 .collect(),
             threads: self
                 .threads
                 .iter()
                 .map(|thread| {
-                    let encoded_thread = hex::decode(&thread[2..].as_bytes())
-                        .expect("failed to parse thread hex string");
-                    Decode::decode(&mut encoded_thread.as_slice()).unwrap()
+                    let thread: LegacyThread<BlockNumber, Moment, AccountId, ThreadId> =
+                        decode_hex(thread, "thread");
+                    let author_id = resolve_author_id(members, thread.author_account());
+                    thread.into_thread(author_id)
                 })
                 .collect(),
         }
@@ -69,25 +119,36 @@ fn parse_forum_json(data_file: &Path) -> EncodedForumData {
 
 /// Generates a `ForumConfig` geneis config pre-populated with
 /// categories, threads and posts parsed
-/// from a json file serialized as `EncodedForumData`
-pub fn from_json(forum_sudo: AccountId, data_file: &Path) -> ForumConfig {
+/// from a json file serialized as `EncodedForumData`.
+/// Authors of the exports preceding the member based authorship are resolved
+/// to the genesis `members`.
+pub fn from_json(
+    forum_sudo: AccountId,
+    data_file: &Path,
+    members: &[GenesisMember],
+) -> ForumConfig {
     let forum_data = parse_forum_json(data_file);
-    create(forum_sudo, forum_data)
+    create(forum_sudo, forum_data, members)
 }
 
 /// Generates a basic empty `ForumConfig` geneis config
 pub fn empty(forum_sudo: AccountId) -> ForumConfig {
     let forum_data = EncodedForumData {
+        member_authorship: true,
         categories: vec![],
         threads: vec![],
         posts: vec![],
     };
-    create(forum_sudo, forum_data)
+    create(forum_sudo, forum_data, &[])
 }
 
-fn create(forum_sudo: AccountId, forum_data: EncodedForumData) -> ForumConfig {
+fn create(
+    forum_sudo: AccountId,
+    forum_data: EncodedForumData,
+    members: &[GenesisMember],
+) -> ForumConfig {
     let first_id = 1;
-    let forum_data = forum_data.decode();
+    let forum_data = forum_data.decode(members);
 
     let next_category_id: CategoryId = forum_data
         .categories
@@ -145,5 +206,15 @@ fn create(forum_sudo: AccountId, forum_data: EncodedForumData) -> ForumConfig {
         post_text_constraint: new_validation(10, 2990),
         thread_moderation_rationale_constraint: new_validation(10, 290),
         post_moderation_rationale_constraint: new_validation(10, 290),
+        poll_description_constraint: new_validation(10, 490),
+        poll_alternative_text_constraint: new_validation(1, 199),
+        poll_alternatives_number_constraint: new_validation(2, 8),
+        max_sticky_threads_per_category: 5,
+        max_post_text_change_history: 10,
+        post_text_change_history_hashes_only: false,
+        max_posts_per_rate_limit_period: 20,
+        rate_limit_period: 100,
+        thread_deposit: 0,
+        post_deposit: 0,
     }
 }
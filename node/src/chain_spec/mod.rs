@@ -32,10 +32,10 @@ use node_runtime::{
     membership, AuthorityDiscoveryConfig, BabeConfig, Balance, BalancesConfig,
     ContentDirectoryConfig, ContentDirectoryWorkingGroupConfig, ContentWorkingGroupConfig,
     CouncilConfig, CouncilElectionConfig, DataDirectoryConfig, DataObjectStorageRegistryConfig,
-    DataObjectTypeRegistryConfig, ElectionParameters, ForumConfig, GrandpaConfig, ImOnlineConfig,
-    MembersConfig, Moment, ProposalsCodexConfig, SessionConfig, SessionKeys, Signature,
-    StakerStatus, StakingConfig, StorageWorkingGroupConfig, SudoConfig, SystemConfig,
-    VersionedStoreConfig, VersionedStorePermissionsConfig, DAYS, WASM_BINARY,
+    DataObjectTypeRegistryConfig, DistributionWorkingGroupConfig, ElectionParameters, ForumConfig,
+    GrandpaConfig, ImOnlineConfig, MembersConfig, Moment, ProposalsCodexConfig, SessionConfig,
+    SessionKeys, Signature, StakerStatus, StakingConfig, StorageWorkingGroupConfig, SudoConfig,
+    SystemConfig, VersionedStoreConfig, VersionedStorePermissionsConfig, DAYS, WASM_BINARY,
 };
 
 // Exported to be used by chain-spec-builder
@@ -328,6 +328,13 @@ pub fn testnet_genesis(
             worker_application_human_readable_text_constraint: default_text_constraint,
             worker_exit_rationale_text_constraint: default_text_constraint,
         }),
+        working_group_Instance4: Some(DistributionWorkingGroupConfig {
+            phantom: Default::default(),
+            working_group_mint_capacity: 0,
+            opening_human_readable_text_constraint: default_text_constraint,
+            worker_application_human_readable_text_constraint: default_text_constraint,
+            worker_exit_rationale_text_constraint: default_text_constraint,
+        }),
         content_directory: Some({
             ContentDirectoryConfig {
                 class_by_id: vec![],
@@ -388,6 +395,22 @@ pub fn testnet_genesis(
                 .terminate_working_group_leader_role_proposal_voting_period,
             terminate_working_group_leader_role_proposal_grace_period: cpcp
                 .terminate_working_group_leader_role_proposal_grace_period,
+            add_paid_membership_terms_proposal_voting_period: cpcp
+                .add_paid_membership_terms_proposal_voting_period,
+            add_paid_membership_terms_proposal_grace_period: cpcp
+                .add_paid_membership_terms_proposal_grace_period,
+            set_paid_membership_terms_active_proposal_voting_period: cpcp
+                .set_paid_membership_terms_active_proposal_voting_period,
+            set_paid_membership_terms_active_proposal_grace_period: cpcp
+                .set_paid_membership_terms_active_proposal_grace_period,
+            set_paid_membership_fee_proposal_voting_period: cpcp
+                .set_paid_membership_fee_proposal_voting_period,
+            set_paid_membership_fee_proposal_grace_period: cpcp
+                .set_paid_membership_fee_proposal_grace_period,
+            set_new_memberships_allowed_proposal_voting_period: cpcp
+                .set_new_memberships_allowed_proposal_voting_period,
+            set_new_memberships_allowed_proposal_grace_period: cpcp
+                .set_new_memberships_allowed_proposal_grace_period,
         }),
     }
 }
@@ -3,5 +3,6 @@ pub mod cli;
 #[macro_use]
 pub mod service;
 pub mod command;
+pub mod membership_rpc;
 pub mod node_executor;
 pub mod node_rpc;
@@ -0,0 +1,85 @@
+//! Membership RPC methods, backed by the membership runtime API.
+
+use std::sync::Arc;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use node_runtime::membership::MembershipSubscriptionApi;
+use node_runtime::{opaque::Block, BlockNumber, MemberId};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Error code of the failed runtime API calls.
+const RUNTIME_ERROR: i64 = 1;
+
+/// Membership RPC methods.
+#[rpc(server)]
+pub trait MembershipApi<BlockHash> {
+    /// Returns true if the member has a subscription which has not expired yet.
+    #[rpc(name = "membership_hasActiveSubscription")]
+    fn has_active_subscription(&self, member_id: MemberId, at: Option<BlockHash>) -> Result<bool>;
+
+    /// Returns the block at which the subscription of the member expires, if any.
+    #[rpc(name = "membership_subscriptionExpiresAt")]
+    fn subscription_expires_at(
+        &self,
+        member_id: MemberId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<BlockNumber>>;
+}
+
+/// Implements the membership RPC methods with the runtime API of the client.
+pub struct Membership<C> {
+    client: Arc<C>,
+}
+
+impl<C> Membership<C> {
+    /// Creates new membership RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+
+    fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block>
+    where
+        C: HeaderBackend<Block>,
+    {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: "Unable to query the member subscription.".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
+
+impl<C> MembershipApi<<Block as BlockT>::Hash> for Membership<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: MembershipSubscriptionApi<Block, MemberId, BlockNumber>,
+{
+    fn has_active_subscription(
+        &self,
+        member_id: MemberId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<bool> {
+        self.client
+            .runtime_api()
+            .has_active_subscription(&self.block_id(at), member_id)
+            .map_err(runtime_error)
+    }
+
+    fn subscription_expires_at(
+        &self,
+        member_id: MemberId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<BlockNumber>> {
+        self.client
+            .runtime_api()
+            .subscription_expires_at(&self.block_id(at), member_id)
+            .map_err(runtime_error)
+    }
+}
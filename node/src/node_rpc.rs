@@ -33,7 +33,7 @@
 use std::sync::Arc;
 
 use node_runtime::UncheckedExtrinsic;
-use node_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Index};
+use node_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Index, MemberId};
 use sc_consensus_babe::{Config, Epoch};
 use sc_consensus_babe_rpc::BabeRpcHandler;
 use sc_consensus_epochs::SharedEpochChanges;
@@ -108,10 +108,12 @@ where
     >,
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
+    C::Api: node_runtime::membership::MembershipSubscriptionApi<Block, MemberId, BlockNumber>,
     P: TransactionPool + 'static,
     M: jsonrpc_core::Metadata + Default,
     SC: SelectChain<Block> + 'static,
 {
+    use crate::membership_rpc::{Membership, MembershipApi};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
     use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
@@ -145,6 +147,7 @@ where
     io.extend_with(TransactionPaymentApi::to_delegate(TransactionPayment::new(
         client.clone(),
     )));
+    io.extend_with(MembershipApi::to_delegate(Membership::new(client.clone())));
     io.extend_with(sc_consensus_babe_rpc::BabeApi::to_delegate(
         BabeRpcHandler::new(
             client,
@@ -16,4 +16,6 @@ pub enum WorkingGroup {
     Storage,
     /// Storage working group: working_group::Instance3.
     Content,
+    /// Distribution working group: working_group::Instance4.
+    Distribution,
 }
@@ -104,6 +104,8 @@ pub static MSG_CHANNEL_DESCRIPTION_TOO_SHORT: &str = "Channel description too sh
 pub static MSG_CHANNEL_DESCRIPTION_TOO_LONG: &str = "Channel description too long";
 pub static MSG_CHANNEL_ID_INVALID: &str = "Channel id invalid";
 pub static MSG_CHANNEL_CREATION_DISABLED: &str = "Channel creation currently disabled";
+pub static MSG_CHANNEL_CREATION_REQUIRES_SUBSCRIPTION: &str =
+    "Channel creation requires an active membership subscription";
 static MSG_CHANNEL_HANDLE_ALREADY_TAKEN: &str = "Channel handle is already taken";
 static MSG_CHANNEL_TITLE_TOO_SHORT: &str = "Channel title too short";
 static MSG_CHANNEL_TITLE_TOO_LONG: &str = "Channel title too long";
@@ -231,6 +233,7 @@ pub static MSG_ORIGIN_IS_NIETHER_MEMBER_CONTROLLER_OR_ROOT: &str =
     "Origin must be controller or root account of member";
 pub static MSG_MEMBER_HAS_ACTIVE_APPLICATION_ON_OPENING: &str =
     "Member already has an active application on the opening";
+pub static MSG_MEMBER_SUSPENDED: &str = "Member is suspended";
 pub static MSG_ADD_CURATOR_OPENING_ROLE_STAKE_CANNOT_BE_ZERO: &str =
     "Add curator opening role stake cannot be zero";
 pub static MSG_ADD_CURATOR_OPENING_APPLICATION_STAKE_CANNOT_BE_ZERO: &str =
@@ -1026,6 +1029,9 @@ decl_storage! {
         /// Whether it is currently possible to create a channel via `create_channel` extrinsic.
         pub ChannelCreationEnabled get(fn channel_creation_enabled) config(): bool;
 
+        /// Whether the channel owner must have an active membership subscription to create a channel.
+        pub ChannelCreationRequiresSubscription get(fn channel_creation_requires_subscription): bool;
+
         /// Recover curator by the role stake which is currently unstaking.
         pub UnstakerByStakeId get(fn unstaker_by_stake_id) config(): map hasher(blake2_128_concat)
             StakeId<T> => WorkingGroupUnstaker<LeadId<T>, CuratorId<T>>;
@@ -1082,6 +1088,7 @@ decl_event! {
         CuratorRewardAccountUpdated(CuratorId, AccountId),
         ChannelUpdatedByCurationActor(ChannelId),
         ChannelCreationEnabledUpdated(bool),
+        ChannelCreationRequiresSubscriptionUpdated(bool),
         MintCapacityIncreased(MintId, MintBalanceOf, MintBalanceOf),
         MintCapacityDecreased(MintId, MintBalanceOf, MintBalanceOf),
     }
@@ -1124,6 +1131,13 @@ decl_module! {
                 MSG_CHANNEL_CREATION_DISABLED
             );
 
+            // Ensure owner has an active subscription, if required (ChannelCreationRequiresSubscription).
+            ensure!(
+                !ChannelCreationRequiresSubscription::get() ||
+                    membership::Module::<T>::has_active_subscription(&owner),
+                MSG_CHANNEL_CREATION_REQUIRES_SUBSCRIPTION
+            );
+
             // Ensure channel handle is acceptable length
             Self::ensure_channel_handle_is_valid(&handle)?;
 
@@ -1650,6 +1664,12 @@ decl_module! {
                 MSG_ORIGIN_IS_NIETHER_MEMBER_CONTROLLER_OR_ROOT
             );
 
+            // Suspended members can't apply
+            ensure!(
+                !membership::Module::<T>::is_member_suspended(&member_id),
+                MSG_MEMBER_SUSPENDED
+            );
+
             // Ensure curator opening exists
             let (curator_opening, _opening) = Self::ensure_curator_opening_exists(&curator_opening_id)?;
 
@@ -1864,6 +1884,24 @@ decl_module! {
             Self::deposit_event(RawEvent::ChannelCreationEnabledUpdated(enabled));
         }
 
+        /// Require an active membership subscription from the channel owners to create channels.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_channel_creation_requires_subscription(origin, required: bool)  {
+
+            // Ensure lead is set and is origin signer
+            Self::ensure_origin_is_set_lead(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Update storage value
+            ChannelCreationRequiresSubscription::put(required);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ChannelCreationRequiresSubscriptionUpdated(required));
+        }
+
         /// Add to capacity of current acive mint.
         /// This may be deprecated in the future, since set_mint_capacity is sufficient to
         /// both increase and decrease capacity. Although when considering that it may be executed
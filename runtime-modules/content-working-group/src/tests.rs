@@ -60,6 +60,45 @@ fn create_channel_is_not_a_member() {
         });
 }
 
+#[test]
+fn create_channel_requires_subscription() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            add_member_and_set_as_lead();
+
+            assert_ok!(
+                ContentWorkingGroup::set_channel_creation_requires_subscription(
+                    Origin::signed(LEAD_ROLE_ACCOUNT),
+                    true
+                )
+            );
+
+            let channel_creator_member_id = add_channel_creator_member();
+
+            let fixture = CreateChannelFixture::make_valid_unpulished_video_channel_for(
+                channel_creator_member_id,
+                None,
+            );
+
+            fixture.call_and_assert_error(MSG_CHANNEL_CREATION_REQUIRES_SUBSCRIPTION);
+
+            let subscription_id = membership::Module::<Test>::next_subscription_id();
+            assert_ok!(membership::Module::<Test>::add_subscription_plan(
+                RawOrigin::Root.into(),
+                0,
+                100
+            ));
+            assert_ok!(membership::Module::<Test>::buy_subscription(
+                Origin::signed(fixture.controller_account),
+                channel_creator_member_id,
+                subscription_id
+            ));
+
+            fixture.call_and_assert_success();
+        });
+}
+
 #[test]
 fn create_channel_not_enabled() {
     TestExternalitiesBuilder::<Test>::default()
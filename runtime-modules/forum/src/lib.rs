@@ -14,26 +14,27 @@ use serde::{Deserialize, Serialize};
 pub type DispatchResult = Result<(), &'static str>;
 
 use codec::{Codec, Decode, Encode};
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::{Currency, Get, OnUnbalanced, ReservableCurrency};
 use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
-use sp_arithmetic::traits::{BaseArithmetic, One};
-use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
+use sp_runtime::traits::{Hash, MaybeSerialize, Member};
 use sp_std::borrow::ToOwned;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
+pub mod migration;
 mod mock;
 mod tests;
 
 use common::constraints::InputValidationLengthConstraint;
+use common::currency::{BalanceOf, GovernanceCurrency};
+use common::origin::ActorOriginValidator;
 use common::BlockAndTime;
 
 /// Constants
 /////////////////////////////////////////////////////////////////
 
-/// The greatest valid depth of a category.
-/// The depth of a root category is 0.
-const MAX_CATEGORY_DEPTH: u16 = 3;
-
 /// Error messages for dispatchables
 const ERROR_FORUM_SUDO_NOT_SET: &str = "Forum sudo not set.";
 const ERROR_ORIGIN_NOT_FORUM_SUDO: &str = "Origin not forum sudo.";
@@ -56,31 +57,51 @@ const ERROR_THREAD_MODERATION_RATIONALE_TOO_LONG: &str = "Thread moderation rati
 const ERROR_THREAD_ALREADY_MODERATED: &str = "Thread already moderated.";
 const ERROR_THREAD_MODERATED: &str = "Thread is moderated.";
 const ERROR_POST_DOES_NOT_EXIST: &str = "Post does not exist.";
-const ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR: &str = "Account does not match post author.";
+const ERROR_MEMBER_DOES_NOT_MATCH_POST_AUTHOR: &str = "Member does not match post author.";
 const ERROR_POST_MODERATED: &str = "Post is moderated.";
 const ERROR_POST_MODERATION_RATIONALE_TOO_SHORT: &str = "Post moderation rationale too short.";
 const ERROR_POST_MODERATION_RATIONALE_TOO_LONG: &str = "Post moderation rationale too long.";
 const ERROR_CATEGORY_NOT_BEING_UPDATED: &str = "Category not being updated.";
 const ERROR_CATEGORY_CANNOT_BE_UNARCHIVED_WHEN_DELETED: &str =
     "Category cannot be unarchived when deleted.";
+const ERROR_POLL_DESCRIPTION_TOO_SHORT: &str = "Poll description too short.";
+const ERROR_POLL_DESCRIPTION_TOO_LONG: &str = "Poll description too long.";
+const ERROR_POLL_ALTERNATIVE_TEXT_TOO_SHORT: &str = "Poll alternative text too short.";
+const ERROR_POLL_ALTERNATIVE_TEXT_TOO_LONG: &str = "Poll alternative text too long.";
+const ERROR_POLL_ALTERNATIVES_TOO_FEW: &str = "Poll has too few alternatives.";
+const ERROR_POLL_ALTERNATIVES_TOO_MANY: &str = "Poll has too many alternatives.";
+const ERROR_POLL_END_BLOCK_NOT_IN_FUTURE: &str = "Poll end block is not in the future.";
+const ERROR_POLL_DOES_NOT_EXIST: &str = "Thread has no poll.";
+const ERROR_POLL_ENDED: &str = "Poll has ended.";
+const ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST: &str = "Poll alternative does not exist.";
+const ERROR_ALREADY_VOTED_ON_POLL: &str = "Member already voted on poll.";
+const ERROR_THREAD_LOCKED: &str = "Thread is locked.";
+const ERROR_THREAD_ARCHIVED: &str = "Thread is archived.";
+const ERROR_THREAD_NOT_ARCHIVED: &str = "Thread is not archived.";
+const ERROR_ARCHIVED_THREAD_MODERATION_PERIOD_NOT_OVER: &str =
+    "Moderation period of the archived thread is not over.";
+const ERROR_THREAD_DEPOSIT_DOES_NOT_EXIST: &str = "Thread deposit does not exist.";
+const ERROR_POST_DEPOSIT_DOES_NOT_EXIST: &str = "Post deposit does not exist.";
+const ERROR_POST_DEPOSIT_NOT_WITHDRAWABLE: &str =
+    "Post deposit can be withdrawn only in an archived or deleted category.";
+const ERROR_MAX_STICKY_THREADS_EXCEEDED: &str =
+    "Maximum number of sticky threads in category exceeded.";
+const ERROR_THREAD_ALREADY_IN_CATEGORY: &str = "Thread is already in the category.";
+const ERROR_ORIGIN_NOT_CATEGORY_MODERATOR: &str = "Origin not moderator of the category.";
+const ERROR_REPLY_TO_POST_NOT_IN_THREAD: &str = "Replied post is not in the thread.";
+const ERROR_REPLY_TO_POST_MODERATED: &str = "Replied post is moderated.";
+const ERROR_POST_DELETED: &str = "Post is deleted.";
+const ERROR_REPLY_TO_POST_DELETED: &str = "Replied post is deleted.";
+const ERROR_FORUM_USER_BANNED: &str = "Forum user is banned.";
+const ERROR_BAN_EXPIRY_NOT_IN_FUTURE: &str = "Ban expiry block is not in the future.";
+const ERROR_POSTING_RATE_LIMIT_EXCEEDED: &str = "Forum user posting rate limit exceeded.";
+const ERROR_INSUFFICIENT_BALANCE_FOR_DEPOSIT: &str = "Insufficient balance for the deposit.";
+const ERROR_MEMBER_DOES_NOT_MATCH_THREAD_AUTHOR: &str = "Member does not match thread author.";
+const ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITS_SUBTREE: &str =
+    "Category cannot be moved into its own subtree.";
 
 use system::{ensure_root, ensure_signed};
 
-/// Represents a user in this forum.
-#[derive(Debug, Copy, Clone)]
-pub struct ForumUser<AccountId> {
-    /// Identifier of user
-    pub id: AccountId, // In the future one could add things like
-                       // - updating post count of a user
-                       // - updating status (e.g. hero, new, etc.)
-                       //
-}
-
-/// Represents a regsitry of `ForumUser` instances.
-pub trait ForumUserRegistry<AccountId> {
-    fn get_forum_user(id: &AccountId) -> Option<ForumUser<AccountId>>;
-}
-
 /// Represents a moderation outcome applied to a post or a thread.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
@@ -88,28 +109,97 @@ pub struct ModerationAction<BlockNumber, Moment, AccountId> {
     /// When action occured.
     moderated_at: BlockAndTime<BlockNumber, Moment>,
 
-    /// Account forum sudo which acted.
+    /// Account of the forum sudo or the category moderator which acted.
     moderator_id: AccountId,
 
     /// Moderation rationale
     rationale: Vec<u8>,
 }
 
+/// Represents an expired text of a Post, kept either in full or as a hash
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+pub enum PostRevisionText<Hash> {
+    /// Full expired text
+    Text(Vec<u8>),
+
+    /// Hash of the expired text
+    Hash(Hash),
+}
+
+impl<Hash> Default for PostRevisionText<Hash> {
+    fn default() -> Self {
+        PostRevisionText::Text(Vec::new())
+    }
+}
+
 /// Represents a revision of the text of a Post
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct PostTextChange<BlockNumber, Moment> {
+pub struct PostTextChange<BlockNumber, Moment, Hash> {
     /// When this expiration occured
     expired_at: BlockAndTime<BlockNumber, Moment>,
 
     /// Text that expired
-    text: Vec<u8>,
+    text: PostRevisionText<Hash>,
+}
+
+/// Reaction kinds forum users can put on a post
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq)]
+pub enum PostReaction {
+    /// Agreement with the post
+    Like,
+
+    /// Disagreement with the post
+    Dislike,
+
+    /// Post is insightful
+    Insightful,
+
+    /// Post is funny
+    Funny,
+}
+
+/// Aggregate counts of the post reactions, per reaction kind
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PostReactionCounts {
+    pub like: u32,
+    pub dislike: u32,
+    pub insightful: u32,
+    pub funny: u32,
+}
+
+impl PostReactionCounts {
+    fn count_mut(&mut self, reaction: PostReaction) -> &mut u32 {
+        match reaction {
+            PostReaction::Like => &mut self.like,
+            PostReaction::Dislike => &mut self.dislike,
+            PostReaction::Insightful => &mut self.insightful,
+            PostReaction::Funny => &mut self.funny,
+        }
+    }
+
+    /// Moves the reaction of a forum user from the old reaction kind to the new one,
+    /// `None` stands for no reaction.
+    fn update(&mut self, old_reaction: Option<PostReaction>, new_reaction: Option<PostReaction>) {
+        if let Some(reaction) = old_reaction {
+            let count = self.count_mut(reaction);
+            *count = count.saturating_sub(1);
+        }
+
+        if let Some(reaction) = new_reaction {
+            let count = self.count_mut(reaction);
+            *count = count.saturating_add(1);
+        }
+    }
 }
 
 /// Represents a thread post
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct Post<BlockNumber, Moment, AccountId, ThreadId, PostId> {
+pub struct Post<BlockNumber, Moment, AccountId, MemberId, ThreadId, PostId, Hash> {
     /// Post identifier
     pub id: PostId,
 
@@ -129,20 +219,33 @@ pub struct Post<BlockNumber, Moment, AccountId, ThreadId, PostId> {
     /// Possible moderation of this post
     moderation: Option<ModerationAction<BlockNumber, Moment, AccountId>>,
 
-    /// Edits of post ordered chronologically by edit time.
-    text_change_history: Vec<PostTextChange<BlockNumber, Moment>>,
+    /// Latest edits of post ordered chronologically by edit time.
+    /// Only `MaxPostTextChangeHistory` most recent edits are retained.
+    text_change_history: Vec<PostTextChange<BlockNumber, Moment, Hash>>,
 
     /// When post was submitted.
     created_at: BlockAndTime<BlockNumber, Moment>,
 
-    /// Author of post.
-    author_id: AccountId,
+    /// Member id of the post author.
+    author_id: MemberId,
+
+    /// Aggregate counts of the reactions to this post.
+    reaction_counts: PostReactionCounts,
+
+    /// Earlier post of the same thread this post replies to.
+    reply_to: Option<PostId>,
+
+    /// Total number of edits of post, including the ones dropped from the history.
+    edit_count: u32,
+
+    /// Whether the author deleted the post. Deleted post keeps its place in the thread.
+    deleted: bool,
 }
 
 /// Represents a thread
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct Thread<BlockNumber, Moment, AccountId, ThreadId> {
+pub struct Thread<BlockNumber, Moment, AccountId, MemberId, ThreadId> {
     /// Thread identifier
     pub id: ThreadId,
 
@@ -178,19 +281,101 @@ pub struct Thread<BlockNumber, Moment, AccountId, ThreadId> {
     /// When thread was established.
     created_at: BlockAndTime<BlockNumber, Moment>,
 
-    /// Author of post.
-    author_id: AccountId,
+    /// Member id of the thread author.
+    author_id: MemberId,
 }
 
-impl<BlockNumber, Moment, AccountId, ThreadId> Thread<BlockNumber, Moment, AccountId, ThreadId> {
+impl<BlockNumber, Moment, AccountId, MemberId, ThreadId>
+    Thread<BlockNumber, Moment, AccountId, MemberId, ThreadId>
+{
     fn num_posts_ever_created(&self) -> u32 {
         self.num_unmoderated_posts + self.num_moderated_posts
     }
 }
 
+/// Represents an alternative of a thread poll
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PollAlternative {
+    /// Alternative description
+    pub text: Vec<u8>,
+
+    /// Number of votes cast for this alternative
+    pub vote_count: u32,
+}
+
+/// Represents a poll attached to a thread
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct Poll<BlockNumber> {
+    /// Poll description
+    pub description: Vec<u8>,
+
+    /// Block at which the poll ends, no votes are accepted from this block on
+    pub end_block: BlockNumber,
+
+    /// Alternatives with their vote tallies
+    pub alternatives: Vec<PollAlternative>,
+}
+
+/// Poll parameters provided on the thread creation
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PollInput<BlockNumber> {
+    /// Poll description
+    pub description: Vec<u8>,
+
+    /// Block at which the poll ends
+    pub end_block: BlockNumber,
+
+    /// Descriptions of the alternatives
+    pub alternatives: Vec<Vec<u8>>,
+}
+
 /// Represents a category identifier
 pub type CategoryId = u64;
 
+/// Balance reserved from the author account for creating a thread or a post
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct Deposit<AccountId, Balance> {
+    /// Account the deposit is reserved from
+    pub account_id: AccountId,
+
+    /// Reserved amount
+    pub amount: Balance,
+}
+
+/// Scope of the forum user ban
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq)]
+pub enum BanScope {
+    /// Ban from posting anywhere in the forum
+    Forum,
+
+    /// Ban from posting in the category and its subcategories
+    Category(CategoryId),
+}
+
+/// Ban of the forum user from posting
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct Ban<BlockNumber> {
+    /// Block from which the ban no longer applies, the ban is permanent if not set
+    pub expires_at: Option<BlockNumber>,
+}
+
+/// Counts the threads and posts created by the forum user in the current rate limit period
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PostingActivity<BlockNumber> {
+    /// Block at which the current rate limit period started
+    pub period_started_at: BlockNumber,
+
+    /// Number of threads and posts created in the current period
+    pub count: u32,
+}
+
 /// Represents
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
@@ -256,15 +441,64 @@ impl<BlockNumber, Moment, AccountId> Category<BlockNumber, Moment, AccountId> {
     }
 }
 
+/// Alias for the forum thread.
+pub type ThreadOf<T> = Thread<
+    <T as system::Trait>::BlockNumber,
+    <T as pallet_timestamp::Trait>::Moment,
+    <T as system::Trait>::AccountId,
+    <T as Trait>::MemberId,
+    <T as Trait>::ThreadId,
+>;
+
+/// Alias for the forum post.
+pub type PostOf<T> = Post<
+    <T as system::Trait>::BlockNumber,
+    <T as pallet_timestamp::Trait>::Moment,
+    <T as system::Trait>::AccountId,
+    <T as Trait>::MemberId,
+    <T as Trait>::ThreadId,
+    <T as Trait>::PostId,
+    <T as system::Trait>::Hash,
+>;
+
 /// Represents a sequence of categories which have child-parent relatioonship
 /// where last element is final ancestor, or root, in the context of the category tree.
 type CategoryTreePath<BlockNumber, Moment, AccountId> =
     Vec<Category<BlockNumber, Moment, AccountId>>;
 
-pub trait Trait: system::Trait + pallet_timestamp::Trait + Sized {
+/// Negative imbalance of the deposit currency.
+pub type NegativeImbalance<T> = <<T as GovernanceCurrency>::Currency as Currency<
+    <T as system::Trait>::AccountId,
+>>::NegativeImbalance;
+
+type DepositOf<T> = Deposit<<T as system::Trait>::AccountId, BalanceOf<T>>;
+
+pub trait Trait: system::Trait + pallet_timestamp::Trait + GovernanceCurrency + Sized {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
-    type MembershipRegistry: ForumUserRegistry<Self::AccountId>;
+    /// Handles the deposits slashed from the moderated threads and posts.
+    type SlashedDepositHandler: OnUnbalanced<NegativeImbalance<Self>>;
+
+    /// The greatest valid depth of a category.
+    /// The depth of a root category is 0.
+    type MaxCategoryDepth: Get<u16>;
+
+    /// Number of blocks the deposit of an archived thread stays reserved,
+    /// so the moderators can still slash it.
+    type ArchivedThreadModerationPeriod: Get<Self::BlockNumber>;
+
+    /// Member id type of the forum users.
+    type MemberId: Parameter
+        + Member
+        + BaseArithmetic
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerialize
+        + PartialEq;
+
+    /// Validates member id and origin combination of the forum users.
+    type MemberOriginValidator: ActorOriginValidator<Self::Origin, Self::MemberId, Self::AccountId>;
 
     /// Thread Id type
     type ThreadId: Parameter
@@ -299,14 +533,14 @@ decl_storage! {
 
         /// Map thread identifier to corresponding thread.
         pub ThreadById get(fn thread_by_id) config(): map hasher(blake2_128_concat)
-            T::ThreadId => Thread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId>;
+            T::ThreadId => ThreadOf<T>;
 
         /// Thread identifier value to be used for next Thread in threadById.
         pub NextThreadId get(fn next_thread_id) config(): T::ThreadId;
 
         /// Map post identifier to corresponding post.
         pub PostById get(fn post_by_id) config(): map hasher(blake2_128_concat)
-            T::PostId => Post<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId, T::PostId>;
+            T::PostId => PostOf<T>;
 
         /// Post identifier value to be used for for next post created.
         pub NextPostId get(fn next_post_id) config(): T::PostId;
@@ -323,6 +557,115 @@ decl_storage! {
         pub PostTextConstraint get(fn post_text_constraint) config(): InputValidationLengthConstraint;
         pub ThreadModerationRationaleConstraint get(fn thread_moderation_rationale_constraint) config(): InputValidationLengthConstraint;
         pub PostModerationRationaleConstraint get(fn post_moderation_rationale_constraint) config(): InputValidationLengthConstraint;
+
+        /// Whether the thread and post authors are keyed by the member ids. Set at genesis,
+        /// the chains upgraded from the account based authorship are migrated on runtime upgrade.
+        pub AuthorsMigratedToMembers get(fn authors_migrated_to_members) build(|_| true): bool;
+
+        /// Map thread identifier to the poll attached to the thread.
+        pub PollByThreadId get(fn poll_by_thread_id): map hasher(blake2_128_concat)
+            T::ThreadId => Option<Poll<T::BlockNumber>>;
+
+        /// Index of the poll alternative voted for by the member, per thread.
+        pub PollVoteByMemberId get(fn poll_vote_by_member_id): double_map
+            hasher(blake2_128_concat) T::ThreadId,
+            hasher(blake2_128_concat) T::MemberId => Option<u32>;
+
+        /// Poll input constraints, default values apply to the chains upgraded before polls.
+        pub PollDescriptionConstraint get(fn poll_description_constraint) config():
+            InputValidationLengthConstraint = InputValidationLengthConstraint::new(10, 490);
+        pub PollAlternativeTextConstraint get(fn poll_alternative_text_constraint) config():
+            InputValidationLengthConstraint = InputValidationLengthConstraint::new(1, 199);
+        pub PollAlternativesNumberConstraint get(fn poll_alternatives_number_constraint) config():
+            InputValidationLengthConstraint = InputValidationLengthConstraint::new(2, 8);
+
+        /// Sticky threads of the category, in the order they were pinned.
+        pub StickyThreadIds get(fn sticky_thread_ids): map hasher(blake2_128_concat)
+            CategoryId => Vec<T::ThreadId>;
+
+        /// Maximum number of sticky threads per category.
+        pub MaxStickyThreadsPerCategory get(fn max_sticky_threads_per_category) config(): u32 = 5;
+
+        /// Number of threads ever added to the category, by creation or by moving in. Unlike the
+        /// thread counts of the category it never decreases, so the `nr_in_category` of the
+        /// moved out threads is never reused. Stored once a thread is added or moved out.
+        pub NumThreadsAddedByCategoryId get(fn num_threads_added_by_category_id):
+            map hasher(blake2_128_concat) CategoryId => u32;
+
+        /// Number of subcategories ever added to the category, by creation or by moving in.
+        /// Unlike the subcategory count of the category it never decreases, so the
+        /// `child_nr_in_parent_category` of the moved out subcategories is never reused.
+        /// Stored once a subcategory is added or moved out.
+        pub NumSubcategoriesAddedByCategoryId get(fn num_subcategories_added_by_category_id):
+            map hasher(blake2_128_concat) CategoryId => u32;
+
+        /// Number of subcategories below the category per level, starting with its direct
+        /// subcategories. Its length is the height of the subcategory tree of the category.
+        pub SubcategoryCountsByDepth get(fn subcategory_counts_by_depth):
+            map hasher(blake2_128_concat) CategoryId => Vec<u32>;
+
+        /// Whether the subcategory counts per level are kept. Initialized at genesis,
+        /// the chains upgraded from the forum without them are migrated on runtime upgrade.
+        pub SubcategoryCountsInitialized get(fn subcategory_counts_initialized): bool;
+
+        /// Threads locked against new posts.
+        pub LockedThreads get(fn locked_threads): map hasher(blake2_128_concat) T::ThreadId => bool;
+
+        /// Block at which the thread was archived by its author.
+        pub ArchivedThreads get(fn archived_threads): map hasher(blake2_128_concat)
+            T::ThreadId => Option<T::BlockNumber>;
+
+        /// Bans of the forum users from posting, per scope.
+        pub ForumUserBans get(fn forum_user_bans): double_map
+            hasher(blake2_128_concat) BanScope,
+            hasher(blake2_128_concat) T::MemberId => Option<Ban<T::BlockNumber>>;
+
+        /// Posting activity of the forum users, used for the rate limiting.
+        pub PostingActivityByMemberId get(fn posting_activity_by_member_id): map
+            hasher(blake2_128_concat) T::MemberId => PostingActivity<T::BlockNumber>;
+
+        /// Maximum number of threads and posts a forum user can create in a rate limit period.
+        pub MaxPostsPerRateLimitPeriod get(fn max_posts_per_rate_limit_period) config(): u32 = 20;
+
+        /// Length of the posting rate limit period in blocks.
+        pub RateLimitPeriod get(fn rate_limit_period) config(): T::BlockNumber = T::BlockNumber::from(100u32);
+
+        /// Deposit reserved from the author when creating a thread, covers its initial post.
+        pub ThreadDeposit get(fn thread_deposit) config(): BalanceOf<T>;
+
+        /// Deposit reserved from the author when adding a post to a thread.
+        pub PostDeposit get(fn post_deposit) config(): BalanceOf<T>;
+
+        /// Deposits reserved for the threads.
+        pub ThreadDepositById get(fn thread_deposit_by_id): map
+            hasher(blake2_128_concat) T::ThreadId => Option<DepositOf<T>>;
+
+        /// Deposits reserved for the posts, per thread.
+        pub PostDepositById get(fn post_deposit_by_id): double_map
+            hasher(blake2_128_concat) T::ThreadId,
+            hasher(blake2_128_concat) T::PostId => Option<DepositOf<T>>;
+
+        /// Maximum number of the post text revisions retained, the oldest are dropped first.
+        pub MaxPostTextChangeHistory get(fn max_post_text_change_history) config(): u32 = 10;
+
+        /// Whether only the hashes of the expired post texts are retained.
+        pub PostTextChangeHistoryHashesOnly get(fn post_text_change_history_hashes_only) config(): bool;
+
+        /// Reaction of the forum user to the post.
+        pub PostReactionByMemberId get(fn post_reaction_by_member_id): double_map
+            hasher(blake2_128_concat) T::PostId,
+            hasher(blake2_128_concat) T::MemberId => Option<PostReaction>;
+
+        /// Moderator accounts assigned to the category. Moderators of the category
+        /// also moderate all its subcategories.
+        pub CategoryModerators get(fn category_moderators): double_map
+            hasher(blake2_128_concat) CategoryId,
+            hasher(blake2_128_concat) T::AccountId => bool;
+    }
+    add_extra_genesis {
+        build(|_config: &GenesisConfig<T>| {
+            Module::<T>::initialize_subcategory_counts();
+        });
     }
 }
 
@@ -330,6 +673,8 @@ decl_event!(
     pub enum Event<T>
     where
         <T as system::Trait>::AccountId,
+        <T as system::Trait>::BlockNumber,
+        <T as Trait>::MemberId,
         <T as Trait>::ThreadId,
         <T as Trait>::PostId,
     {
@@ -341,6 +686,10 @@ decl_event!(
         /// The third argument reflects the new deletion status of the category, if changed.
         CategoryUpdated(CategoryId, Option<bool>, Option<bool>),
 
+        /// A category with given id was moved under the new parent category,
+        /// or made a root category (None).
+        CategoryMoved(CategoryId, Option<CategoryId>),
+
         /// A thread with given id was created.
         ThreadCreated(ThreadId),
 
@@ -357,8 +706,61 @@ decl_event!(
         /// The second argument reflects the number of total edits when the text update occurs.
         PostTextUpdated(PostId, u64),
 
+        /// Post with given id was deleted by its author.
+        PostDeleted(PostId),
+
+        /// Thread with given id was archived by its author.
+        ThreadArchived(ThreadId),
+
+        /// Deposit of the thread with given id was refunded to its author.
+        ThreadDepositWithdrawn(ThreadId),
+
+        /// Deposit of the post with given id was refunded to its author.
+        PostDepositWithdrawn(PostId),
+
+        /// Forum user was banned from posting in the scope until the block, if set.
+        ForumUserBanned(MemberId, BanScope, Option<BlockNumber>),
+
+        /// Forum user ban in the scope was lifted.
+        ForumUserUnbanned(MemberId, BanScope),
+
         /// Given account was set as forum sudo.
         ForumSudoSet(Option<AccountId>, Option<AccountId>),
+
+        /// Posting rate limit was set to the number of threads and posts (first argument)
+        /// per the period in blocks (second argument).
+        PostingRateLimitSet(u32, BlockNumber),
+
+        /// Maximum number of sticky threads per category was set.
+        MaxStickyThreadsPerCategorySet(u32),
+
+        /// Maximum number of the post text revisions retained was set.
+        MaxPostTextChangeHistorySet(u32),
+
+        /// Member voted on the poll of the thread with given id.
+        /// The third argument is the index of the chosen alternative.
+        VoteOnPoll(MemberId, ThreadId, u32),
+
+        /// A thread with given id was pinned to (true) or unpinned from (false) its category.
+        ThreadStickyStatusUpdated(ThreadId, bool),
+
+        /// A thread with given id was locked (true) or unlocked (false).
+        ThreadLockStatusUpdated(ThreadId, bool),
+
+        /// A thread with given id was moved from the category (second argument)
+        /// to the category (third argument).
+        ThreadMoved(ThreadId, CategoryId, CategoryId),
+
+        /// Given account was assigned as (true) or removed from (false) the moderators
+        /// of the category with given id.
+        CategoryModeratorStatusUpdated(CategoryId, AccountId, bool),
+
+        /// Forum user set (Some) or removed (None) the reaction to the post with given id.
+        PostReactionUpdated(MemberId, PostId, Option<PostReaction>),
+
+        /// Post with given id was added as a reply to the post (second argument)
+        /// of the forum user (third argument).
+        PostRepliedTo(PostId, PostId, MemberId),
     }
 );
 
@@ -367,6 +769,12 @@ decl_module! {
 
         fn deposit_event() = default;
 
+        /// Exports const - the greatest valid depth of a category.
+        const MaxCategoryDepth: u16 = T::MaxCategoryDepth::get();
+
+        /// Exports const - number of blocks the deposit of an archived thread stays reserved.
+        const ArchivedThreadModerationPeriod: T::BlockNumber = T::ArchivedThreadModerationPeriod::get();
+
         /// Set forum sudo.
         #[weight = 10_000_000] // TODO: adjust weight
         fn set_forum_sudo(origin, new_forum_sudo: Option<T::AccountId>) -> DispatchResult {
@@ -393,6 +801,55 @@ decl_module! {
             Ok(())
         }
 
+        /// Set the maximum number of threads and posts a forum user can create per the period.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn set_posting_rate_limit(
+            origin,
+            max_posts_per_rate_limit_period: u32,
+            rate_limit_period: T::BlockNumber
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            MaxPostsPerRateLimitPeriod::put(max_posts_per_rate_limit_period);
+            <RateLimitPeriod<T>>::put(rate_limit_period);
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostingRateLimitSet(
+                max_posts_per_rate_limit_period,
+                rate_limit_period
+            ));
+
+            Ok(())
+        }
+
+        /// Set the maximum number of sticky threads per category.
+        /// Categories already over the maximum keep their sticky threads.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn set_max_sticky_threads_per_category(origin, max_sticky_threads: u32) -> DispatchResult {
+            ensure_root(origin)?;
+
+            MaxStickyThreadsPerCategory::put(max_sticky_threads);
+
+            // Generate event
+            Self::deposit_event(RawEvent::MaxStickyThreadsPerCategorySet(max_sticky_threads));
+
+            Ok(())
+        }
+
+        /// Set the maximum number of the post text revisions retained.
+        /// Longer histories are trimmed on the next edit of the post.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn set_max_post_text_change_history(origin, max_history: u32) -> DispatchResult {
+            ensure_root(origin)?;
+
+            MaxPostTextChangeHistory::put(max_history);
+
+            // Generate event
+            Self::deposit_event(RawEvent::MaxPostTextChangeHistorySet(max_history));
+
+            Ok(())
+        }
+
         /// Add a new category.
         #[weight = 10_000_000] // TODO: adjust weight
         fn create_category(origin, parent: Option<CategoryId>, title: Vec<u8>, description: Vec<u8>) -> DispatchResult {
@@ -424,18 +881,22 @@ decl_module! {
                  * Here we are safe to mutate
                  */
 
+                let child_nr_in_parent_category =
+                    Self::add_subcategory_nr_in_parent_category(parent_category_id);
+
                 // Increment number of subcategories to reflect this new category being
                 // added as a child
                 <CategoryById<T>>::mutate(parent_category_id, |c| {
                     c.num_direct_subcategories += 1;
                 });
 
-                // Set `position_in_parent_category_field`
-                let parent_category = category_tree_path.first().unwrap();
+                // Count the new category in the subcategories of its ancestors
+                Self::update_subcategory_counts(&category_tree_path, &[], true);
 
+                // Set `position_in_parent_category_field`
                 position_in_parent_category_field = Some(ChildPositionInParentCategory{
                     parent_id: parent_category_id,
-                    child_nr_in_parent_category: parent_category.num_direct_subcategories
+                    child_nr_in_parent_category
                 });
 
             }
@@ -537,53 +998,104 @@ decl_module! {
             Ok(())
         }
 
-        /// Create new thread in category
+        /// Move category with its subcategories under the new parent category,
+        /// or make it a root category.
         #[weight = 10_000_000] // TODO: adjust weight
-        fn create_thread(origin, category_id: CategoryId, title: Vec<u8>, text: Vec<u8>) -> DispatchResult {
-
-            /*
-             * Update SPEC with new errors,
-             * and mutation of Category class,
-             * as well as side effect to update Category::num_threads_created.
-             */
+        fn move_category(origin, category_id: CategoryId, new_parent: Option<CategoryId>) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Check that account is forum member
-            Self::ensure_is_forum_member(&who)?;
+            // Not signed by forum SUDO
+            Self::ensure_is_forum_sudo(&who)?;
 
-            // Get path from parent to root of category tree.
-            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
+            ensure!(<CategoryById<T>>::contains_key(category_id), ERROR_CATEGORY_DOES_NOT_EXIST);
 
-            // No ancestor is blocking us doing mutation in this category
-            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+            let category = <CategoryById<T>>::get(category_id);
+            let old_parent = category.position_in_parent_category.map(|position| position.parent_id);
 
-            // Validate title
-            Self::ensure_thread_title_is_valid(&title)?;
+            // Make sure something is actually being changed
+            ensure!(old_parent != new_parent, ERROR_CATEGORY_NOT_BEING_UPDATED);
 
-            // Validate post text
-            Self::ensure_post_text_is_valid(&text)?;
+            // Path from the new parent to root of category tree, empty for the root category.
+            let new_parent_tree_path = match new_parent {
+                Some(new_parent_id) => {
+                    let path = Self::ensure_valid_category_and_build_category_tree_path(new_parent_id)?;
+
+                    // New parent can not be the category itself or its subcategory
+                    ensure!(
+                        !path.iter().any(|c| c.id == category_id),
+                        ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITS_SUBTREE
+                    );
+
+                    // Can we mutate in the new parent category?
+                    Self::ensure_can_mutate_in_path_leaf(&path)?;
+
+                    path
+                }
+                None => vec![],
+            };
+
+            // The deepest subcategory stays within the maximum depth
+            let num_ancestors_of_deepest = new_parent_tree_path.len() + Self::subcategory_tree_height(category_id);
+
+            if num_ancestors_of_deepest > 0 {
+                Self::ensure_category_depth_is_valid(num_ancestors_of_deepest)?;
+            }
 
             /*
-             * Here it is safe to mutate state.
+             * Here we are safe to mutate
              */
 
-            // Add thread
-            let thread = Self::add_new_thread(category_id, &title, &who);
+            let subtree_counts = Self::subcategory_counts_by_depth(category_id);
 
-            // Add inital post to thread
-            Self::add_new_post(thread.id, &text, &who);
+            if let Some(old_parent_id) = old_parent {
+                // Keep the subcategory numbers of the old parent from being reused
+                let num_subcategories_added = Self::num_subcategories_added(old_parent_id);
+                NumSubcategoriesAddedByCategoryId::insert(old_parent_id, num_subcategories_added);
+
+                <CategoryById<T>>::mutate(old_parent_id, |c| {
+                    c.num_direct_subcategories -= 1;
+                });
+
+                let old_parent_tree_path = Self::build_category_tree_path(old_parent_id);
+                Self::update_subcategory_counts(&old_parent_tree_path, &subtree_counts, false);
+            }
+
+            let position_in_parent_category = new_parent.map(|new_parent_id| {
+                let child_nr_in_parent_category =
+                    Self::add_subcategory_nr_in_parent_category(new_parent_id);
+
+                <CategoryById<T>>::mutate(new_parent_id, |c| {
+                    c.num_direct_subcategories += 1;
+                });
+
+                ChildPositionInParentCategory {
+                    parent_id: new_parent_id,
+                    child_nr_in_parent_category
+                }
+            });
+
+            Self::update_subcategory_counts(&new_parent_tree_path, &subtree_counts, true);
+
+            <CategoryById<T>>::mutate(category_id, |c| {
+                c.position_in_parent_category = position_in_parent_category;
+            });
 
             // Generate event
-            Self::deposit_event(RawEvent::ThreadCreated(thread.id));
+            Self::deposit_event(RawEvent::CategoryMoved(category_id, new_parent));
 
             Ok(())
         }
 
-        /// Moderate thread
+        /// Assign the account as the category moderator or remove it from the moderators.
         #[weight = 10_000_000] // TODO: adjust weight
-        fn moderate_thread(origin, thread_id: T::ThreadId, rationale: Vec<u8>) -> DispatchResult {
+        fn set_category_moderator_status(
+            origin,
+            category_id: CategoryId,
+            account_id: T::AccountId,
+            is_moderator: bool
+        ) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
@@ -591,119 +1103,675 @@ decl_module! {
             // Signed by forum SUDO
             Self::ensure_is_forum_sudo(&who)?;
 
-            // Get thread
-            let mut thread = Self::ensure_thread_exists(thread_id)?;
-
-            // Thread is not already moderated
-            ensure!(thread.moderation.is_none(), ERROR_THREAD_ALREADY_MODERATED);
-
-            // Rationale valid
-            Self::ensure_thread_moderation_rationale_is_valid(&rationale)?;
-
-            // Can mutate in corresponding category
-            let path = Self::build_category_tree_path(thread.category_id);
-
-            // Path must be non-empty, as category id is from thread in state
-            assert!(!path.is_empty());
-
-            Self::ensure_can_mutate_in_path_leaf(&path)?;
+            // Make sure category exists
+            ensure!(
+                <CategoryById<T>>::contains_key(category_id),
+                ERROR_CATEGORY_DOES_NOT_EXIST
+            );
 
             /*
              * Here we are safe to mutate
              */
 
-            // Add moderation to thread
-            thread.moderation = Some(ModerationAction {
-                moderated_at: common::current_block_time::<T>(),
-                moderator_id: who,
-                rationale
-            });
-
-            <ThreadById<T>>::insert(thread_id, thread.clone());
-
-            // Update moderation/umoderation count of corresponding category
-            <CategoryById<T>>::mutate(thread.category_id, |category| {
-                category.num_direct_unmoderated_threads -= 1;
-                category.num_direct_moderated_threads += 1;
-            });
+            if is_moderator {
+                <CategoryModerators<T>>::insert(category_id, &account_id, true);
+            } else {
+                <CategoryModerators<T>>::remove(category_id, &account_id);
+            }
 
             // Generate event
-            Self::deposit_event(RawEvent::ThreadModerated(thread_id));
+            Self::deposit_event(
+                RawEvent::CategoryModeratorStatusUpdated(category_id, account_id, is_moderator)
+            );
 
             Ok(())
         }
 
-        /// Edit post text
+        /// Ban forum user from posting in the scope, optionally until the expiry block.
+        /// Forum wide bans are set by forum sudo, category bans by the category moderators.
         #[weight = 10_000_000] // TODO: adjust weight
-        fn add_post(origin, thread_id: T::ThreadId, text: Vec<u8>) -> DispatchResult {
-
-            /*
-             * Update SPEC with new errors,
-             */
+        fn ban_forum_user(
+            origin,
+            forum_user_id: T::MemberId,
+            scope: BanScope,
+            expires_at: Option<T::BlockNumber>
+        ) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Check that account is forum member
-            Self::ensure_is_forum_member(&who)?;
-
-            // Validate post text
-            Self::ensure_post_text_is_valid(&text)?;
-
-            // Make sure thread exists and is mutable
-            let thread = Self::ensure_thread_is_mutable(thread_id)?;
-
-            // Get path from parent to root of category tree.
-            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(thread.category_id)?;
+            // Signed by the moderator of the scope
+            Self::ensure_can_ban_in_scope(&who, scope)?;
 
-            // No ancestor is blocking us doing mutation in this category
-            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+            if let Some(expires_at) = expires_at {
+                ensure!(
+                    expires_at > <system::Module<T>>::block_number(),
+                    ERROR_BAN_EXPIRY_NOT_IN_FUTURE
+                );
+            }
 
             /*
              * Here we are safe to mutate
              */
 
-            let post = Self::add_new_post(thread_id, &text, &who);
+            <ForumUserBans<T>>::insert(scope, forum_user_id, Ban { expires_at });
 
             // Generate event
-            Self::deposit_event(RawEvent::PostAdded(post.id));
+            Self::deposit_event(RawEvent::ForumUserBanned(forum_user_id, scope, expires_at));
 
             Ok(())
         }
 
-        /// Edit post text
+        /// Lift the forum user ban in the scope.
         #[weight = 10_000_000] // TODO: adjust weight
-        fn edit_post_text(origin, post_id: T::PostId, new_text: Vec<u8>) -> DispatchResult {
-
-            /* Edit spec.
-              - forum member guard missing
-              - check that both post and thread and category are mutable
-            */
+        fn unban_forum_user(origin, forum_user_id: T::MemberId, scope: BanScope) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Check that account is forum member
-            Self::ensure_is_forum_member(&who)?;
-
-            // Validate post text
-            Self::ensure_post_text_is_valid(&new_text)?;
-
-            // Make sure there exists a mutable post with post id `post_id`
-            let post = Self::ensure_post_is_mutable(post_id)?;
-
-            // Signer does not match creator of post with identifier postId
-            ensure!(post.author_id == who, ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR);
+            // Signed by the moderator of the scope
+            Self::ensure_can_ban_in_scope(&who, scope)?;
 
             /*
              * Here we are safe to mutate
              */
 
-            <PostById<T>>::mutate(post_id, |p| {
+            <ForumUserBans<T>>::remove(scope, forum_user_id);
 
-                let expired_post_text = PostTextChange {
-                    expired_at: common::current_block_time::<T>(),
-                    text: post.current_text.clone()
+            // Generate event
+            Self::deposit_event(RawEvent::ForumUserUnbanned(forum_user_id, scope));
+
+            Ok(())
+        }
+
+        /// Create new thread in category
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn create_thread(
+            origin,
+            forum_user_id: T::MemberId,
+            category_id: CategoryId,
+            title: Vec<u8>,
+            text: Vec<u8>,
+            poll: Option<PollInput<T::BlockNumber>>
+        ) -> DispatchResult {
+
+            /*
+             * Update SPEC with new errors,
+             * and mutation of Category class,
+             * as well as side effect to update Category::num_threads_created.
+             */
+
+            // Check that the origin is the forum member
+            let who = Self::ensure_is_forum_member(origin, forum_user_id)?;
+
+            // Get path from parent to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
+
+            // No ancestor is blocking us doing mutation in this category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            // Validate title
+            Self::ensure_thread_title_is_valid(&title)?;
+
+            // Validate post text
+            Self::ensure_post_text_is_valid(&text)?;
+
+            // Validate poll
+            if let Some(ref poll) = poll {
+                Self::ensure_poll_is_valid(poll)?;
+            }
+
+            // Forum user is allowed to post in the category
+            Self::ensure_not_banned(forum_user_id, &category_tree_path)?;
+            let posting_activity = Self::ensure_posting_rate_limit_not_exceeded(forum_user_id)?;
+
+            // Author can afford the thread deposit
+            let deposit = Self::thread_deposit();
+            Self::ensure_can_reserve_deposit(&who, deposit)?;
+
+            /*
+             * Here it is safe to mutate state.
+             */
+
+            <PostingActivityByMemberId<T>>::insert(forum_user_id, posting_activity);
+
+            // Add thread
+            let thread = Self::add_new_thread(category_id, &title, forum_user_id);
+
+            // Reserve thread deposit
+            if let Some(deposit) = Self::reserve_deposit(who, deposit) {
+                <ThreadDepositById<T>>::insert(thread.id, deposit);
+            }
+
+            // Attach poll to thread
+            if let Some(poll) = poll {
+                Self::add_new_poll(thread.id, poll);
+            }
+
+            // Add inital post to thread
+            Self::add_new_post(thread.id, &text, forum_user_id, None);
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadCreated(thread.id));
+
+            Ok(())
+        }
+
+        /// Moderate thread
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn moderate_thread(origin, thread_id: T::ThreadId, rationale: Vec<u8>) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Get thread
+            let mut thread = Self::ensure_thread_exists(thread_id)?;
+
+            // Signed by the moderator of the thread category
+            Self::ensure_is_category_moderator(&who, thread.category_id)?;
+
+            // Thread is not already moderated
+            ensure!(thread.moderation.is_none(), ERROR_THREAD_ALREADY_MODERATED);
+
+            // Rationale valid
+            Self::ensure_thread_moderation_rationale_is_valid(&rationale)?;
+
+            // Can mutate in corresponding category
+            let path = Self::build_category_tree_path(thread.category_id);
+
+            // Path must be non-empty, as category id is from thread in state
+            assert!(!path.is_empty());
+
+            Self::ensure_can_mutate_in_path_leaf(&path)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Add moderation to thread
+            thread.moderation = Some(ModerationAction {
+                moderated_at: common::current_block_time::<T>(),
+                moderator_id: who,
+                rationale
+            });
+
+            <ThreadById<T>>::insert(thread_id, thread.clone());
+
+            // Update moderation/umoderation count of corresponding category
+            <CategoryById<T>>::mutate(thread.category_id, |category| {
+                category.num_direct_unmoderated_threads -= 1;
+                category.num_direct_moderated_threads += 1;
+            });
+
+            // Moderated thread is hidden, so it can not stay sticky
+            Self::unpin_thread(thread.category_id, thread_id);
+
+            // Slash thread deposit
+            Self::slash_deposit(<ThreadDepositById<T>>::take(thread_id));
+
+            // Posts of the moderated thread can no longer be deleted, refund their deposits
+            for (_, deposit) in <PostDepositById<T>>::drain_prefix(thread_id) {
+                Self::refund_deposit(Some(deposit));
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadModerated(thread_id));
+
+            Ok(())
+        }
+
+        /// Archive thread by its author. Archived thread accepts no new posts, its deposit
+        /// can be withdrawn once the moderation period of the archived thread is over.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn archive_thread(origin, forum_user_id: T::MemberId, thread_id: T::ThreadId) -> DispatchResult {
+
+            // Check that the origin is the forum member
+            Self::ensure_is_forum_member(origin, forum_user_id)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Forum user does not match creator of the thread
+            ensure!(thread.author_id == forum_user_id, ERROR_MEMBER_DOES_NOT_MATCH_THREAD_AUTHOR);
+
+            // Thread is not already archived
+            ensure!(Self::archived_threads(thread_id).is_none(), ERROR_THREAD_ARCHIVED);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <ArchivedThreads<T>>::insert(thread_id, <system::Module<T>>::block_number());
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadArchived(thread_id));
+
+            Ok(())
+        }
+
+        /// Refund the deposit of the archived thread to its author,
+        /// once the moderation period of the archived thread is over.
+        /// Deposit of the thread in an archived or deleted category can be withdrawn at any time.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn withdraw_thread_deposit(origin, forum_user_id: T::MemberId, thread_id: T::ThreadId) -> DispatchResult {
+
+            // Check that the origin is the forum member
+            Self::ensure_is_forum_member(origin, forum_user_id)?;
+
+            // Make sure thread exists
+            let thread = Self::ensure_thread_exists(thread_id)?;
+
+            // Forum user does not match creator of the thread
+            ensure!(thread.author_id == forum_user_id, ERROR_MEMBER_DOES_NOT_MATCH_THREAD_AUTHOR);
+
+            // Thread in an archived or deleted category can not be moderated anymore
+            if !Self::category_is_immutable(thread.category_id) {
+                // Thread is archived
+                let archived_at = Self::archived_threads(thread_id)
+                    .ok_or(ERROR_THREAD_NOT_ARCHIVED)?;
+
+                // Moderators had their time to slash the deposit
+                ensure!(
+                    <system::Module<T>>::block_number() >=
+                        archived_at + T::ArchivedThreadModerationPeriod::get(),
+                    ERROR_ARCHIVED_THREAD_MODERATION_PERIOD_NOT_OVER
+                );
+            }
+
+            // Deposit was neither slashed nor withdrawn
+            ensure!(
+                <ThreadDepositById<T>>::contains_key(thread_id),
+                ERROR_THREAD_DEPOSIT_DOES_NOT_EXIST
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Refund thread deposit
+            Self::refund_deposit(<ThreadDepositById<T>>::take(thread_id));
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadDepositWithdrawn(thread_id));
+
+            Ok(())
+        }
+
+        /// Refund the deposit of the post in an archived or deleted category to its author.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn withdraw_post_deposit(origin, forum_user_id: T::MemberId, post_id: T::PostId) -> DispatchResult {
+
+            // Check that the origin is the forum member
+            Self::ensure_is_forum_member(origin, forum_user_id)?;
+
+            // Make sure post exists
+            let post = Self::ensure_post_exists(post_id)?;
+
+            // Forum user does not match creator of the post
+            ensure!(post.author_id == forum_user_id, ERROR_MEMBER_DOES_NOT_MATCH_POST_AUTHOR);
+
+            // Post can not be deleted by its author anymore
+            let thread = <ThreadById<T>>::get(post.thread_id);
+            ensure!(
+                Self::category_is_immutable(thread.category_id),
+                ERROR_POST_DEPOSIT_NOT_WITHDRAWABLE
+            );
+
+            // Deposit was neither slashed nor withdrawn
+            ensure!(
+                <PostDepositById<T>>::contains_key(post.thread_id, post_id),
+                ERROR_POST_DEPOSIT_DOES_NOT_EXIST
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Refund post deposit
+            Self::refund_deposit(<PostDepositById<T>>::take(post.thread_id, post_id));
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostDepositWithdrawn(post_id));
+
+            Ok(())
+        }
+
+        /// Pin thread to the top of its category or unpin it.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn set_thread_sticky_status(origin, thread_id: T::ThreadId, sticky: bool) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Signed by the moderator of the thread category
+            Self::ensure_is_category_moderator(&who, thread.category_id)?;
+
+            let sticky_thread_ids = Self::sticky_thread_ids(thread.category_id);
+
+            if sticky_thread_ids.contains(&thread_id) == sticky {
+                // Nothing to update
+                return Ok(());
+            }
+
+            if sticky {
+                ensure!(
+                    (sticky_thread_ids.len() as u32) < Self::max_sticky_threads_per_category(),
+                    ERROR_MAX_STICKY_THREADS_EXCEEDED
+                );
+            }
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if sticky {
+                <StickyThreadIds<T>>::mutate(thread.category_id, |ids| ids.push(thread_id));
+            } else {
+                Self::unpin_thread(thread.category_id, thread_id);
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadStickyStatusUpdated(thread_id, sticky));
+
+            Ok(())
+        }
+
+        /// Lock thread against new posts or unlock it. Locked thread stays visible.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn set_thread_lock_status(origin, thread_id: T::ThreadId, locked: bool) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Make sure thread exists
+            let thread = Self::ensure_thread_exists(thread_id)?;
+
+            // Signed by the moderator of the thread category
+            Self::ensure_is_category_moderator(&who, thread.category_id)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if locked {
+                <LockedThreads<T>>::insert(thread_id, true);
+            } else {
+                <LockedThreads<T>>::remove(thread_id);
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadLockStatusUpdated(thread_id, locked));
+
+            Ok(())
+        }
+
+        /// Move thread to another category.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn move_thread(origin, thread_id: T::ThreadId, new_category_id: CategoryId) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Make sure thread exists
+            let mut thread = Self::ensure_thread_exists(thread_id)?;
+
+            let old_category_id = thread.category_id;
+
+            ensure!(old_category_id != new_category_id, ERROR_THREAD_ALREADY_IN_CATEGORY);
+
+            // Get path from new category to root of category tree.
+            let category_tree_path =
+                Self::ensure_valid_category_and_build_category_tree_path(new_category_id)?;
+
+            // Signed by the moderator of both categories
+            Self::ensure_is_category_moderator(&who, old_category_id)?;
+            Self::ensure_is_category_moderator(&who, new_category_id)?;
+
+            // No ancestor is blocking us from adding the thread to the new category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            let moderated = thread.moderation.is_some();
+
+            // Keep the thread numbers of the old category from being reused
+            let num_threads_added = Self::num_threads_added(old_category_id);
+            NumThreadsAddedByCategoryId::insert(old_category_id, num_threads_added);
+
+            let nr_in_category = Self::add_thread_nr_in_category(new_category_id);
+
+            // Update thread counts of both categories
+            <CategoryById<T>>::mutate(old_category_id, |category| {
+                if moderated {
+                    category.num_direct_moderated_threads -= 1;
+                } else {
+                    category.num_direct_unmoderated_threads -= 1;
+                }
+            });
+
+            <CategoryById<T>>::mutate(new_category_id, |category| {
+                if moderated {
+                    category.num_direct_moderated_threads += 1;
+                } else {
+                    category.num_direct_unmoderated_threads += 1;
+                }
+            });
+
+            // Sticky status is specific to the category
+            Self::unpin_thread(old_category_id, thread_id);
+
+            thread.category_id = new_category_id;
+            thread.nr_in_category = nr_in_category;
+
+            <ThreadById<T>>::insert(thread_id, thread);
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadMoved(thread_id, old_category_id, new_category_id));
+
+            Ok(())
+        }
+
+        /// Edit post text
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn add_post(
+            origin,
+            forum_user_id: T::MemberId,
+            thread_id: T::ThreadId,
+            text: Vec<u8>,
+            reply_to: Option<T::PostId>
+        ) -> DispatchResult {
+
+            /*
+             * Update SPEC with new errors,
+             */
+
+            // Check that the origin is the forum member
+            let who = Self::ensure_is_forum_member(origin, forum_user_id)?;
+
+            // Validate post text
+            Self::ensure_post_text_is_valid(&text)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Locked thread accepts no new posts
+            ensure!(!Self::locked_threads(thread_id), ERROR_THREAD_LOCKED);
+
+            // Archived thread accepts no new posts
+            ensure!(Self::archived_threads(thread_id).is_none(), ERROR_THREAD_ARCHIVED);
+
+            // Get path from parent to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(thread.category_id)?;
+
+            // No ancestor is blocking us doing mutation in this category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            // Replied post is an unmoderated post of the same thread
+            let replied_post = match reply_to {
+                Some(replied_post_id) => Some(Self::ensure_can_reply_to_post(thread_id, replied_post_id)?),
+                None => None,
+            };
+
+            // Forum user is allowed to post in the category
+            Self::ensure_not_banned(forum_user_id, &category_tree_path)?;
+            let posting_activity = Self::ensure_posting_rate_limit_not_exceeded(forum_user_id)?;
+
+            // Author can afford the post deposit
+            let deposit = Self::post_deposit();
+            Self::ensure_can_reserve_deposit(&who, deposit)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <PostingActivityByMemberId<T>>::insert(forum_user_id, posting_activity);
+
+            let post = Self::add_new_post(thread_id, &text, forum_user_id, reply_to);
+
+            // Reserve post deposit
+            if let Some(deposit) = Self::reserve_deposit(who, deposit) {
+                <PostDepositById<T>>::insert(thread_id, post.id, deposit);
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostAdded(post.id));
+
+            if let Some(replied_post) = replied_post {
+                Self::deposit_event(
+                    RawEvent::PostRepliedTo(post.id, replied_post.id, replied_post.author_id)
+                );
+            }
+
+            Ok(())
+        }
+
+        /// Vote on the poll attached to the thread, once per member.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn vote_on_poll(
+            origin,
+            forum_user_id: T::MemberId,
+            thread_id: T::ThreadId,
+            alternative_index: u32
+        ) -> DispatchResult {
+
+            // Check that the origin is the forum member
+            Self::ensure_is_forum_member(origin, forum_user_id)?;
+
+            // Make sure thread exists and is mutable
+            Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Make sure poll exists and is still running
+            let mut poll = Self::poll_by_thread_id(thread_id).ok_or(ERROR_POLL_DOES_NOT_EXIST)?;
+
+            ensure!(<system::Module<T>>::block_number() < poll.end_block, ERROR_POLL_ENDED);
+
+            ensure!(
+                (alternative_index as usize) < poll.alternatives.len(),
+                ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST
+            );
+
+            ensure!(
+                !<PollVoteByMemberId<T>>::contains_key(thread_id, forum_user_id),
+                ERROR_ALREADY_VOTED_ON_POLL
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            poll.alternatives[alternative_index as usize].vote_count += 1;
+
+            <PollByThreadId<T>>::insert(thread_id, poll);
+            <PollVoteByMemberId<T>>::insert(thread_id, forum_user_id, alternative_index);
+
+            // Generate event
+            Self::deposit_event(RawEvent::VoteOnPoll(forum_user_id, thread_id, alternative_index));
+
+            Ok(())
+        }
+
+        /// React to the post, replacing the previous reaction of the forum user.
+        /// `None` removes the reaction.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn react_to_post(
+            origin,
+            forum_user_id: T::MemberId,
+            post_id: T::PostId,
+            reaction: Option<PostReaction>
+        ) -> DispatchResult {
+
+            // Check that the origin is the forum member
+            Self::ensure_is_forum_member(origin, forum_user_id)?;
+
+            // Make sure post exists and is mutable
+            let mut post = Self::ensure_post_is_mutable(post_id)?;
+
+            let old_reaction = Self::post_reaction_by_member_id(post_id, forum_user_id);
+
+            if old_reaction == reaction {
+                // Nothing to update
+                return Ok(());
+            }
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            post.reaction_counts.update(old_reaction, reaction);
+
+            <PostById<T>>::insert(post_id, post);
+
+            match reaction {
+                Some(reaction) => <PostReactionByMemberId<T>>::insert(post_id, forum_user_id, reaction),
+                None => <PostReactionByMemberId<T>>::remove(post_id, forum_user_id),
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostReactionUpdated(forum_user_id, post_id, reaction));
+
+            Ok(())
+        }
+
+        /// Edit post text
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn edit_post_text(
+            origin,
+            forum_user_id: T::MemberId,
+            post_id: T::PostId,
+            new_text: Vec<u8>
+        ) -> DispatchResult {
+
+            /* Edit spec.
+              - forum member guard missing
+              - check that both post and thread and category are mutable
+            */
+
+            // Check that the origin is the forum member
+            Self::ensure_is_forum_member(origin, forum_user_id)?;
+
+            // Validate post text
+            Self::ensure_post_text_is_valid(&new_text)?;
+
+            // Make sure there exists a mutable post with post id `post_id`
+            let post = Self::ensure_post_is_mutable(post_id)?;
+
+            // Forum user does not match creator of post with identifier postId
+            ensure!(post.author_id == forum_user_id, ERROR_MEMBER_DOES_NOT_MATCH_POST_AUTHOR);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <PostById<T>>::mutate(post_id, |p| {
+
+                let expired_post_text = PostTextChange {
+                    expired_at: common::current_block_time::<T>(),
+                    text: Self::post_revision_text(&post.current_text)
                 };
 
                 // Set current text to new text
@@ -711,10 +1779,51 @@ decl_module! {
 
                 // Copy current text to history of expired texts
                 p.text_change_history.push(expired_post_text);
+                p.edit_count += 1;
+
+                // Drop the oldest revisions over the limit
+                let max_history_len = Self::max_post_text_change_history() as usize;
+                if p.text_change_history.len() > max_history_len {
+                    let excess = p.text_change_history.len() - max_history_len;
+                    p.text_change_history.drain(..excess);
+                }
             });
 
             // Generate event
-            Self::deposit_event(RawEvent::PostTextUpdated(post.id, post.text_change_history.len() as u64));
+            Self::deposit_event(RawEvent::PostTextUpdated(post.id, post.edit_count.into()));
+
+            Ok(())
+        }
+
+        /// Delete post by its author. Text and history are cleared, the post keeps its place
+        /// in the thread.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn delete_post(origin, forum_user_id: T::MemberId, post_id: T::PostId) -> DispatchResult {
+
+            // Check that the origin is the forum member
+            Self::ensure_is_forum_member(origin, forum_user_id)?;
+
+            // Make sure there exists a mutable post with post id `post_id`
+            let post = Self::ensure_post_is_mutable(post_id)?;
+
+            // Forum user does not match creator of post with identifier postId
+            ensure!(post.author_id == forum_user_id, ERROR_MEMBER_DOES_NOT_MATCH_POST_AUTHOR);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <PostById<T>>::mutate(post_id, |p| {
+                p.current_text = Vec::new();
+                p.text_change_history = Vec::new();
+                p.deleted = true;
+            });
+
+            // Refund post deposit
+            Self::refund_deposit(<PostDepositById<T>>::take(post.thread_id, post_id));
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostDeleted(post_id));
 
             Ok(())
         }
@@ -726,12 +1835,13 @@ decl_module! {
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
-
             // Make sure post exists and is mutable
             let post = Self::ensure_post_is_mutable(post_id)?;
 
+            // Signed by the moderator of the thread category
+            let thread = <ThreadById<T>>::get(post.thread_id);
+            Self::ensure_is_category_moderator(&who, thread.category_id)?;
+
             Self::ensure_post_moderation_rationale_is_valid(&rationale)?;
 
             /*
@@ -755,6 +1865,9 @@ decl_module! {
                 t.num_moderated_posts += 1;
             });
 
+            // Slash post deposit
+            Self::slash_deposit(<PostDepositById<T>>::take(post.thread_id, post_id));
+
             // Generate event
             Self::deposit_event(RawEvent::PostModerated(post.id));
 
@@ -797,6 +1910,35 @@ impl<T: Trait> Module<T> {
         )
     }
 
+    fn ensure_poll_is_valid(poll: &PollInput<T::BlockNumber>) -> DispatchResult {
+        PollDescriptionConstraint::get().ensure_valid(
+            poll.description.len(),
+            ERROR_POLL_DESCRIPTION_TOO_SHORT,
+            ERROR_POLL_DESCRIPTION_TOO_LONG,
+        )?;
+
+        PollAlternativesNumberConstraint::get().ensure_valid(
+            poll.alternatives.len(),
+            ERROR_POLL_ALTERNATIVES_TOO_FEW,
+            ERROR_POLL_ALTERNATIVES_TOO_MANY,
+        )?;
+
+        for alternative in poll.alternatives.iter() {
+            PollAlternativeTextConstraint::get().ensure_valid(
+                alternative.len(),
+                ERROR_POLL_ALTERNATIVE_TEXT_TOO_SHORT,
+                ERROR_POLL_ALTERNATIVE_TEXT_TOO_LONG,
+            )?;
+        }
+
+        ensure!(
+            poll.end_block > <system::Module<T>>::block_number(),
+            ERROR_POLL_END_BLOCK_NOT_IN_FUTURE
+        );
+
+        Ok(())
+    }
+
     fn ensure_post_text_is_valid(text: &[u8]) -> DispatchResult {
         PostTextConstraint::get().ensure_valid(
             text.len(),
@@ -813,26 +1955,49 @@ impl<T: Trait> Module<T> {
         )
     }
 
-    fn ensure_post_is_mutable(
-        post_id: T::PostId,
-    ) -> Result<Post<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId, T::PostId>, &'static str>
-    {
+    fn ensure_post_is_mutable(post_id: T::PostId) -> Result<PostOf<T>, &'static str> {
         // Make sure post exists
         let post = Self::ensure_post_exists(post_id)?;
 
         // and is unmoderated
         ensure!(post.moderation.is_none(), ERROR_POST_MODERATED);
 
+        // and not deleted
+        ensure!(!post.deleted, ERROR_POST_DELETED);
+
         // and make sure thread is mutable
         Self::ensure_thread_is_mutable(post.thread_id)?;
 
         Ok(post)
     }
 
-    fn ensure_post_exists(
+    fn ensure_can_reply_to_post(
+        thread_id: T::ThreadId,
         post_id: T::PostId,
-    ) -> Result<Post<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId, T::PostId>, &'static str>
-    {
+    ) -> Result<PostOf<T>, &'static str> {
+        let post = Self::ensure_post_exists(post_id)?;
+
+        ensure!(
+            post.thread_id == thread_id,
+            ERROR_REPLY_TO_POST_NOT_IN_THREAD
+        );
+        ensure!(post.moderation.is_none(), ERROR_REPLY_TO_POST_MODERATED);
+        ensure!(!post.deleted, ERROR_REPLY_TO_POST_DELETED);
+
+        Ok(post)
+    }
+
+    /// Expired post text to retain in the history, as configured by
+    /// `PostTextChangeHistoryHashesOnly`.
+    fn post_revision_text(text: &[u8]) -> PostRevisionText<T::Hash> {
+        if Self::post_text_change_history_hashes_only() {
+            PostRevisionText::Hash(T::Hashing::hash(text))
+        } else {
+            PostRevisionText::Text(text.to_vec())
+        }
+    }
+
+    fn ensure_post_exists(post_id: T::PostId) -> Result<PostOf<T>, &'static str> {
         if <PostById<T>>::contains_key(post_id) {
             Ok(<PostById<T>>::get(post_id))
         } else {
@@ -840,9 +2005,7 @@ impl<T: Trait> Module<T> {
         }
     }
 
-    fn ensure_thread_is_mutable(
-        thread_id: T::ThreadId,
-    ) -> Result<Thread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId>, &'static str> {
+    fn ensure_thread_is_mutable(thread_id: T::ThreadId) -> Result<ThreadOf<T>, &'static str> {
         // Make sure thread exists
         let thread = Self::ensure_thread_exists(thread_id)?;
 
@@ -855,9 +2018,7 @@ impl<T: Trait> Module<T> {
         Ok(thread)
     }
 
-    fn ensure_thread_exists(
-        thread_id: T::ThreadId,
-    ) -> Result<Thread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId>, &'static str> {
+    fn ensure_thread_exists(thread_id: T::ThreadId) -> Result<ThreadOf<T>, &'static str> {
         if <ThreadById<T>>::contains_key(thread_id) {
             Ok(<ThreadById<T>>::get(thread_id))
         } else {
@@ -882,15 +2043,133 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
-    fn ensure_is_forum_member(
+    /// Forum sudo and the moderators of the category or any of its ancestors
+    /// are allowed to moderate in the category.
+    fn ensure_is_category_moderator(
         account_id: &T::AccountId,
-    ) -> Result<ForumUser<T::AccountId>, &'static str> {
-        let forum_user_query = T::MembershipRegistry::get_forum_user(account_id);
+        category_id: CategoryId,
+    ) -> DispatchResult {
+        if Self::forum_sudo().as_ref() == Some(account_id) {
+            return Ok(());
+        }
+
+        let is_moderator = Self::build_category_tree_path(category_id)
+            .iter()
+            .any(|category| <CategoryModerators<T>>::get(category.id, account_id));
+
+        ensure!(is_moderator, ERROR_ORIGIN_NOT_CATEGORY_MODERATOR);
+
+        Ok(())
+    }
 
-        if let Some(forum_user) = forum_user_query {
-            Ok(forum_user)
+    fn ensure_can_ban_in_scope(account_id: &T::AccountId, scope: BanScope) -> DispatchResult {
+        match scope {
+            BanScope::Forum => Self::ensure_is_forum_sudo(account_id),
+            BanScope::Category(category_id) => {
+                ensure!(
+                    <CategoryById<T>>::contains_key(category_id),
+                    ERROR_CATEGORY_DOES_NOT_EXIST
+                );
+
+                Self::ensure_is_category_moderator(account_id, category_id)
+            }
+        }
+    }
+
+    #[allow(clippy::ptr_arg)] // disable it because of possible frontend API break
+    fn ensure_not_banned(
+        forum_user_id: T::MemberId,
+        category_tree_path: &CategoryTreePath<T::BlockNumber, T::Moment, T::AccountId>,
+    ) -> DispatchResult {
+        let now = <system::Module<T>>::block_number();
+
+        let is_active_ban = |scope: BanScope| {
+            <ForumUserBans<T>>::get(scope, forum_user_id).map_or(false, |ban| {
+                ban.expires_at.map_or(true, |expires_at| now < expires_at)
+            })
+        };
+
+        ensure!(
+            !is_active_ban(BanScope::Forum)
+                && !category_tree_path
+                    .iter()
+                    .any(|category| is_active_ban(BanScope::Category(category.id))),
+            ERROR_FORUM_USER_BANNED
+        );
+
+        Ok(())
+    }
+
+    /// Returns the posting activity of the forum user updated with the new thread or post.
+    fn ensure_posting_rate_limit_not_exceeded(
+        forum_user_id: T::MemberId,
+    ) -> Result<PostingActivity<T::BlockNumber>, &'static str> {
+        let now = <system::Module<T>>::block_number();
+        let activity = Self::posting_activity_by_member_id(forum_user_id);
+
+        let activity = if now >= activity.period_started_at + Self::rate_limit_period() {
+            PostingActivity {
+                period_started_at: now,
+                count: 1,
+            }
         } else {
-            Err(ERROR_NOT_FORUM_USER)
+            PostingActivity {
+                count: activity.count + 1,
+                ..activity
+            }
+        };
+
+        ensure!(
+            activity.count <= Self::max_posts_per_rate_limit_period(),
+            ERROR_POSTING_RATE_LIMIT_EXCEEDED
+        );
+
+        Ok(activity)
+    }
+
+    fn ensure_is_forum_member(
+        origin: T::Origin,
+        forum_user_id: T::MemberId,
+    ) -> Result<T::AccountId, &'static str> {
+        T::MemberOriginValidator::ensure_actor_origin(origin, forum_user_id)
+            .map_err(|_| ERROR_NOT_FORUM_USER)
+    }
+
+    fn ensure_can_reserve_deposit(
+        account_id: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        ensure!(
+            amount.is_zero() || T::Currency::can_reserve(account_id, amount),
+            ERROR_INSUFFICIENT_BALANCE_FOR_DEPOSIT
+        );
+
+        Ok(())
+    }
+
+    /// Reserves the deposit, checked by `ensure_can_reserve_deposit`. Zero deposits are not recorded.
+    fn reserve_deposit(account_id: T::AccountId, amount: BalanceOf<T>) -> Option<DepositOf<T>> {
+        if amount.is_zero() {
+            return None;
+        }
+
+        // Reserve can not fail after the successful check
+        let _ = T::Currency::reserve(&account_id, amount);
+
+        Some(Deposit { account_id, amount })
+    }
+
+    fn refund_deposit(deposit: Option<DepositOf<T>>) {
+        if let Some(deposit) = deposit {
+            T::Currency::unreserve(&deposit.account_id, deposit.amount);
+        }
+    }
+
+    fn slash_deposit(deposit: Option<DepositOf<T>>) {
+        if let Some(deposit) = deposit {
+            let (imbalance, _) = T::Currency::slash_reserved(&deposit.account_id, deposit.amount);
+
+            T::SlashedDepositHandler::on_unbalanced(imbalance);
         }
     }
 
@@ -900,6 +2179,11 @@ impl<T: Trait> Module<T> {
         Self::ensure_can_mutate_in_path_leaf(&category_tree_path)
     }
 
+    /// Whether the category or any of its ancestors is archived or deleted.
+    fn category_is_immutable(category_id: CategoryId) -> bool {
+        Self::ensure_catgory_is_mutable(category_id).is_err()
+    }
+
     // TODO: remove post-Constaninople
     // Clippy linter warning.
     // Disable it because of possible frontend API break.
@@ -927,16 +2211,80 @@ impl<T: Trait> Module<T> {
         Self::ensure_can_mutate_in_path_leaf(category_tree_path)?;
 
         // Does adding a new category exceed maximum depth
-        let depth_of_new_category = 1 + 1 + category_tree_path.len();
+        Self::ensure_category_depth_is_valid(category_tree_path.len())
+    }
+
+    /// Ensures the subcategory with given number of ancestors does not exceed maximum depth.
+    fn ensure_category_depth_is_valid(num_ancestors: usize) -> DispatchResult {
+        let depth_of_category = 1 + 1 + num_ancestors;
 
         ensure!(
-            depth_of_new_category <= MAX_CATEGORY_DEPTH as usize,
+            depth_of_category <= T::MaxCategoryDepth::get() as usize,
             ERROR_MAX_VALID_CATEGORY_DEPTH_EXCEEDED
         );
 
         Ok(())
     }
 
+    /// Number of levels of subcategories below the category, zero when it has none.
+    fn subcategory_tree_height(category_id: CategoryId) -> usize {
+        Self::subcategory_counts_by_depth(category_id).len()
+    }
+
+    /// Adds (`added`) or removes the subcategory with its subtree, given by the subcategory
+    /// counts per level below it, to the subcategory counts of the ancestors on the path
+    /// from its parent to the root.
+    fn update_subcategory_counts(
+        parent_tree_path: &[Category<T::BlockNumber, T::Moment, T::AccountId>],
+        subtree_counts: &[u32],
+        added: bool,
+    ) {
+        for (depth, ancestor) in parent_tree_path.iter().enumerate() {
+            SubcategoryCountsByDepth::mutate(ancestor.id, |counts| {
+                // The subcategory itself is one level above its subtree
+                let levels = sp_std::iter::once(&1).chain(subtree_counts.iter());
+
+                for (level, count) in levels.enumerate() {
+                    let level = depth + level;
+
+                    if counts.len() <= level {
+                        counts.resize(level + 1, 0);
+                    }
+
+                    counts[level] = if added {
+                        counts[level].saturating_add(*count)
+                    } else {
+                        counts[level].saturating_sub(*count)
+                    };
+                }
+
+                // Emptied deepest levels no longer count to the height
+                while counts.last() == Some(&0) {
+                    counts.pop();
+                }
+            });
+        }
+    }
+
+    /// Number of subcategories ever added to the category. Categories without a stored number
+    /// had no subcategories moved out, so all subcategories added are still counted by the
+    /// category.
+    fn num_subcategories_added(category_id: CategoryId) -> u32 {
+        let category = <CategoryById<T>>::get(category_id);
+
+        Self::num_subcategories_added_by_category_id(category_id)
+            .max(category.num_direct_subcategories)
+    }
+
+    /// Counts the subcategory added to the category and returns its `child_nr_in_parent_category`
+    fn add_subcategory_nr_in_parent_category(category_id: CategoryId) -> u32 {
+        let child_nr_in_parent_category = Self::num_subcategories_added(category_id) + 1;
+
+        NumSubcategoriesAddedByCategoryId::insert(category_id, child_nr_in_parent_category);
+
+        child_nr_in_parent_category
+    }
+
     fn ensure_valid_category_and_build_category_tree_path(
         category_id: CategoryId,
     ) -> Result<CategoryTreePath<T::BlockNumber, T::Moment, T::AccountId>, &'static str> {
@@ -994,11 +2342,8 @@ impl<T: Trait> Module<T> {
     fn add_new_thread(
         category_id: CategoryId,
         title: &[u8],
-        author_id: &T::AccountId,
-    ) -> Thread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId> {
-        // Get category
-        let category = <CategoryById<T>>::get(category_id);
-
+        author_id: T::MemberId,
+    ) -> ThreadOf<T> {
         // Create and add new thread
         let new_thread_id = NextThreadId::<T>::get();
 
@@ -1006,12 +2351,12 @@ impl<T: Trait> Module<T> {
             id: new_thread_id,
             title: title.to_owned(),
             category_id,
-            nr_in_category: category.num_threads_created() + 1,
+            nr_in_category: Self::add_thread_nr_in_category(category_id),
             moderation: None,
             num_unmoderated_posts: 0,
             num_moderated_posts: 0,
             created_at: common::current_block_time::<T>(),
-            author_id: author_id.clone(),
+            author_id,
         };
 
         // Store thread
@@ -1030,13 +2375,56 @@ impl<T: Trait> Module<T> {
         new_thread
     }
 
+    /// Number of threads ever added to the category. Categories without a stored number had
+    /// no threads moved out, so all threads added are still counted by the category.
+    fn num_threads_added(category_id: CategoryId) -> u32 {
+        let category = <CategoryById<T>>::get(category_id);
+
+        Self::num_threads_added_by_category_id(category_id).max(category.num_threads_created())
+    }
+
+    /// Counts the thread added to the category and returns its `nr_in_category`
+    fn add_thread_nr_in_category(category_id: CategoryId) -> u32 {
+        let nr_in_category = Self::num_threads_added(category_id) + 1;
+
+        NumThreadsAddedByCategoryId::insert(category_id, nr_in_category);
+
+        nr_in_category
+    }
+
+    /// Removes the thread from the sticky threads of the category, if present
+    fn unpin_thread(category_id: CategoryId, thread_id: T::ThreadId) {
+        if <StickyThreadIds<T>>::get(category_id).contains(&thread_id) {
+            <StickyThreadIds<T>>::mutate(category_id, |ids| ids.retain(|id| *id != thread_id));
+        }
+    }
+
+    /// Attaches a new poll with empty tallies to the given thread
+    fn add_new_poll(thread_id: T::ThreadId, poll: PollInput<T::BlockNumber>) {
+        let new_poll = Poll {
+            description: poll.description,
+            end_block: poll.end_block,
+            alternatives: poll
+                .alternatives
+                .into_iter()
+                .map(|text| PollAlternative {
+                    text,
+                    vote_count: 0,
+                })
+                .collect(),
+        };
+
+        <PollByThreadId<T>>::insert(thread_id, new_poll);
+    }
+
     /// Creates and ads a new post ot the given thread, and makes all required state updates
     /// `thread_id` must be valid
     fn add_new_post(
         thread_id: T::ThreadId,
         text: &[u8],
-        author_id: &T::AccountId,
-    ) -> Post<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId, T::PostId> {
+        author_id: T::MemberId,
+        reply_to: Option<T::PostId>,
+    ) -> PostOf<T> {
         // Get thread
         let thread = <ThreadById<T>>::get(thread_id);
 
@@ -1051,7 +2439,11 @@ impl<T: Trait> Module<T> {
             moderation: None,
             text_change_history: vec![],
             created_at: common::current_block_time::<T>(),
-            author_id: author_id.clone(),
+            author_id,
+            reaction_counts: PostReactionCounts::default(),
+            reply_to,
+            edit_count: 0,
+            deleted: false,
         };
 
         // Store post
@@ -0,0 +1,177 @@
+use crate::*;
+use frame_support::storage::IterableStorageMap;
+use frame_support::StorageValue;
+use sp_arithmetic::traits::Bounded;
+
+/// Thread post in the format preceding the member based authorship.
+#[derive(Decode)]
+pub struct LegacyPost<BlockNumber, Moment, AccountId, ThreadId, PostId> {
+    id: PostId,
+    thread_id: ThreadId,
+    nr_in_thread: u32,
+    current_text: Vec<u8>,
+    moderation: Option<ModerationAction<BlockNumber, Moment, AccountId>>,
+    text_change_history: Vec<LegacyPostTextChange<BlockNumber, Moment>>,
+    created_at: BlockAndTime<BlockNumber, Moment>,
+    author_id: AccountId,
+}
+
+impl<BlockNumber, Moment, AccountId, ThreadId, PostId>
+    LegacyPost<BlockNumber, Moment, AccountId, ThreadId, PostId>
+{
+    /// Account of the post author.
+    pub fn author_account(&self) -> &AccountId {
+        &self.author_id
+    }
+
+    /// Converts the post to the current format, attributing it to the `author_id` member.
+    pub fn into_post<MemberId, Hash>(
+        self,
+        author_id: MemberId,
+    ) -> Post<BlockNumber, Moment, AccountId, MemberId, ThreadId, PostId, Hash> {
+        let edit_count = self.text_change_history.len() as u32;
+        let text_change_history = self
+            .text_change_history
+            .into_iter()
+            .map(|change| PostTextChange {
+                expired_at: change.expired_at,
+                text: PostRevisionText::Text(change.text),
+            })
+            .collect();
+
+        Post {
+            id: self.id,
+            thread_id: self.thread_id,
+            nr_in_thread: self.nr_in_thread,
+            current_text: self.current_text,
+            moderation: self.moderation,
+            text_change_history,
+            created_at: self.created_at,
+            author_id,
+            reaction_counts: PostReactionCounts::default(),
+            reply_to: None,
+            edit_count,
+            deleted: false,
+        }
+    }
+}
+
+// Post text revision in the format preceding the hashed revisions.
+#[derive(Decode)]
+struct LegacyPostTextChange<BlockNumber, Moment> {
+    expired_at: BlockAndTime<BlockNumber, Moment>,
+    text: Vec<u8>,
+}
+
+/// Thread in the format preceding the member based authorship.
+#[derive(Decode)]
+pub struct LegacyThread<BlockNumber, Moment, AccountId, ThreadId> {
+    id: ThreadId,
+    title: Vec<u8>,
+    category_id: CategoryId,
+    nr_in_category: u32,
+    moderation: Option<ModerationAction<BlockNumber, Moment, AccountId>>,
+    num_unmoderated_posts: u32,
+    num_moderated_posts: u32,
+    created_at: BlockAndTime<BlockNumber, Moment>,
+    author_id: AccountId,
+}
+
+impl<BlockNumber, Moment, AccountId, ThreadId>
+    LegacyThread<BlockNumber, Moment, AccountId, ThreadId>
+{
+    /// Account of the thread author.
+    pub fn author_account(&self) -> &AccountId {
+        &self.author_id
+    }
+
+    /// Converts the thread to the current format, attributing it to the `author_id` member.
+    pub fn into_thread<MemberId>(
+        self,
+        author_id: MemberId,
+    ) -> Thread<BlockNumber, Moment, AccountId, MemberId, ThreadId> {
+        Thread {
+            id: self.id,
+            title: self.title,
+            category_id: self.category_id,
+            nr_in_category: self.nr_in_category,
+            moderation: self.moderation,
+            num_unmoderated_posts: self.num_unmoderated_posts,
+            num_moderated_posts: self.num_moderated_posts,
+            created_at: self.created_at,
+            author_id,
+        }
+    }
+}
+
+type LegacyPostOf<T> = LegacyPost<
+    <T as system::Trait>::BlockNumber,
+    <T as pallet_timestamp::Trait>::Moment,
+    <T as system::Trait>::AccountId,
+    <T as Trait>::ThreadId,
+    <T as Trait>::PostId,
+>;
+
+type LegacyThreadOf<T> = LegacyThread<
+    <T as system::Trait>::BlockNumber,
+    <T as pallet_timestamp::Trait>::Moment,
+    <T as system::Trait>::AccountId,
+    <T as Trait>::ThreadId,
+>;
+
+impl<T: Trait> Module<T> {
+    /// Member id the threads and posts of the authors without a membership are attributed to.
+    /// It is never assigned to a member, the member ids are assigned sequentially.
+    pub fn unknown_author_id() -> T::MemberId {
+        T::MemberId::max_value()
+    }
+
+    /// Replaces the author accounts of the existing threads and posts with the member ids
+    /// provided by the `resolve_member_id`. Authors without a member id are replaced with the
+    /// `unknown_author_id`. Does nothing if the authors were already migrated.
+    /// Should be called on the runtime upgrade.
+    pub fn migrate_authors_to_members<F: Fn(&T::AccountId) -> Option<T::MemberId>>(
+        resolve_member_id: F,
+    ) {
+        if Self::authors_migrated_to_members() {
+            return;
+        }
+
+        let resolve_author_id = |account_id: &T::AccountId| {
+            resolve_member_id(account_id).unwrap_or_else(Self::unknown_author_id)
+        };
+
+        <ThreadById<T>>::translate(|_, thread: LegacyThreadOf<T>| {
+            let author_id = resolve_author_id(thread.author_account());
+
+            Some(thread.into_thread(author_id))
+        });
+
+        <PostById<T>>::translate(|_, post: LegacyPostOf<T>| {
+            let author_id = resolve_author_id(post.author_account());
+
+            Some(post.into_post(author_id))
+        });
+
+        AuthorsMigratedToMembers::put(true);
+    }
+    /// Counts the subcategories of the existing categories per level below their ancestors.
+    /// Does nothing if the counts were already initialized. Called at genesis and should be
+    /// called on the runtime upgrade.
+    pub fn initialize_subcategory_counts() {
+        if Self::subcategory_counts_initialized() {
+            return;
+        }
+
+        let category_ids: Vec<CategoryId> = <CategoryById<T>>::iter().map(|(id, _)| id).collect();
+
+        for category_id in category_ids {
+            // The path starts with the category itself, followed by its ancestors
+            let category_tree_path = Self::build_category_tree_path(category_id);
+
+            Self::update_subcategory_counts(&category_tree_path[1..], &[], true);
+        }
+
+        SubcategoryCountsInitialized::put(true);
+    }
+}
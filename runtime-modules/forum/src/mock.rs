@@ -2,6 +2,7 @@
 
 use crate::*;
 use common::BlockAndTime;
+pub use common::currency::GovernanceCurrency;
 
 use frame_support::{impl_outer_origin, parameter_types};
 use sp_core::H256;
@@ -12,20 +13,21 @@ use sp_runtime::{
 };
 
 /// Module which has a full Substrate module for
-/// mocking behaviour of MembershipRegistry
+/// mocking behaviour of the forum users membership
 pub mod registry {
 
     use super::*;
 
     #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-    pub struct Member<AccountId> {
-        pub id: AccountId,
+    pub struct Member<MemberId, AccountId> {
+        pub id: MemberId,
+        pub account_id: AccountId,
     }
 
     decl_storage! {
         trait Store for Module<T: Trait> as MockForumUserRegistry {
             pub ForumUserById get(fn forum_user_by_id) config(): map hasher(blake2_128_concat)
-                T::AccountId => Member<T::AccountId>;
+                T::MemberId => Member<T::MemberId, T::AccountId>;
         }
     }
 
@@ -34,20 +36,28 @@ pub mod registry {
     }
 
     impl<T: Trait> Module<T> {
-        pub fn add_member(member: &Member<T::AccountId>) {
-            <ForumUserById<T>>::insert(member.id.clone(), member.clone());
+        pub fn add_member(member: &Member<T::MemberId, T::AccountId>) {
+            <ForumUserById<T>>::insert(member.id, member.clone());
         }
     }
 
-    impl<T: Trait> ForumUserRegistry<T::AccountId> for Module<T> {
-        fn get_forum_user(id: &T::AccountId) -> Option<ForumUser<T::AccountId>> {
-            if <ForumUserById<T>>::contains_key(id) {
-                let m = <ForumUserById<T>>::get(id);
-
-                Some(ForumUser { id: m.id })
-            } else {
-                None
-            }
+    impl<T: Trait> ActorOriginValidator<T::Origin, T::MemberId, T::AccountId> for Module<T> {
+        fn ensure_actor_origin(
+            origin: T::Origin,
+            member_id: T::MemberId,
+        ) -> Result<T::AccountId, &'static str> {
+            let account_id = system::ensure_signed(origin)?;
+
+            ensure!(
+                <ForumUserById<T>>::contains_key(member_id),
+                "Member does not exist"
+            );
+            ensure!(
+                <ForumUserById<T>>::get(member_id).account_id == account_id,
+                "Account does not match member"
+            );
+
+            Ok(account_id)
         }
     }
 
@@ -67,6 +77,9 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MinimumPeriod: u64 = 5;
+    pub const ExistentialDeposit: u32 = 0;
+    pub const MaxCategoryDepth: u16 = 3;
+    pub const ArchivedThreadModerationPeriod: u64 = 10;
 }
 
 impl system::Trait for Runtime {
@@ -91,7 +104,7 @@ impl system::Trait for Runtime {
     type AvailableBlockRatio = AvailableBlockRatio;
     type Version = ();
     type ModuleToIndex = ();
-    type AccountData = ();
+    type AccountData = balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
 }
@@ -102,9 +115,25 @@ impl pallet_timestamp::Trait for Runtime {
     type MinimumPeriod = MinimumPeriod;
 }
 
+impl balances::Trait for Runtime {
+    type Balance = u64;
+    type DustRemoval = ();
+    type Event = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = system::Module<Runtime>;
+}
+
+impl GovernanceCurrency for Runtime {
+    type Currency = balances::Module<Self>;
+}
+
 impl Trait for Runtime {
     type Event = ();
-    type MembershipRegistry = registry::TestMembershipRegistryModule;
+    type SlashedDepositHandler = ();
+    type MaxCategoryDepth = MaxCategoryDepth;
+    type ArchivedThreadModerationPeriod = ArchivedThreadModerationPeriod;
+    type MemberId = u64;
+    type MemberOriginValidator = registry::TestMembershipRegistryModule;
     type ThreadId = u64;
     type PostId = u64;
 }
@@ -116,6 +145,14 @@ pub enum OriginType {
     Root,
 }
 
+/// Member id of the forum user with the origin. Mock members share ids with their accounts.
+pub fn forum_user_id(origin: &OriginType) -> RuntimeMemberId {
+    match origin {
+        OriginType::Signed(account_id) => *account_id,
+        OriginType::Root => Default::default(),
+    }
+}
+
 pub fn mock_origin(origin: OriginType) -> mock::Origin {
     match origin {
         OriginType::Signed(account_id) => Origin::signed(account_id),
@@ -128,6 +165,10 @@ pub const NOT_FORUM_SUDO_ORIGIN: OriginType = OriginType::Signed(111);
 
 pub const NOT_MEMBER_ORIGIN: OriginType = OriginType::Signed(222);
 
+pub const MODERATOR_ACCOUNT_ID: <Runtime as system::Trait>::AccountId = 777;
+
+pub const MODERATOR_ORIGIN: OriginType = OriginType::Signed(MODERATOR_ACCOUNT_ID);
+
 pub const INVLAID_CATEGORY_ID: CategoryId = 333;
 
 pub const INVLAID_THREAD_ID: RuntimeThreadId = 444;
@@ -158,6 +199,14 @@ pub fn good_post_text() -> Vec<u8> {
     b"A response in the thread".to_vec()
 }
 
+pub fn good_poll() -> RuntimePollInput {
+    PollInput {
+        description: b"Which option should we choose?".to_vec(),
+        end_block: 10,
+        alternatives: vec![b"First option".to_vec(), b"Second option".to_vec()],
+    }
+}
+
 pub fn good_rationale() -> Vec<u8> {
     b"This post violates our community rules".to_vec()
 }
@@ -216,6 +265,7 @@ pub struct CreateThreadFixture {
     pub category_id: CategoryId,
     pub title: Vec<u8>,
     pub text: Vec<u8>,
+    pub poll: Option<RuntimePollInput>,
     pub result: DispatchResult,
 }
 
@@ -224,9 +274,11 @@ impl CreateThreadFixture {
         assert_eq!(
             TestForumModule::create_thread(
                 mock_origin(self.origin.clone()),
+                forum_user_id(&self.origin),
                 self.category_id,
                 self.title.clone(),
-                self.text.clone()
+                self.text.clone(),
+                self.poll.clone()
             ),
             self.result
         )
@@ -237,6 +289,7 @@ pub struct CreatePostFixture {
     pub origin: OriginType,
     pub thread_id: RuntimeThreadId,
     pub text: Vec<u8>,
+    pub reply_to: Option<RuntimePostId>,
     pub result: DispatchResult,
 }
 
@@ -245,8 +298,10 @@ impl CreatePostFixture {
         assert_eq!(
             TestForumModule::add_post(
                 mock_origin(self.origin.clone()),
+                forum_user_id(&self.origin),
                 self.thread_id,
-                self.text.clone()
+                self.text.clone(),
+                self.reply_to
             ),
             self.result
         )
@@ -255,7 +310,10 @@ impl CreatePostFixture {
 
 pub fn create_forum_member() -> OriginType {
     let member_id = 123;
-    let new_member = registry::Member { id: member_id };
+    let new_member = registry::Member {
+        id: member_id,
+        account_id: member_id,
+    };
     registry::TestMembershipRegistryModule::add_member(&new_member);
     OriginType::Signed(member_id)
 }
@@ -285,6 +343,7 @@ pub fn assert_create_thread(
         category_id,
         title: good_thread_title(),
         text: good_thread_text(),
+        poll: None,
         result: expected_result,
     }
     .call_and_assert();
@@ -299,6 +358,7 @@ pub fn assert_create_post(
         origin: forum_sudo,
         thread_id,
         text: good_thread_text(),
+        reply_to: None,
         result: expected_result,
     }
     .call_and_assert();
@@ -313,6 +373,14 @@ pub fn create_category(
     category_id
 }
 
+pub fn move_category(
+    forum_sudo: OriginType,
+    category_id: CategoryId,
+    new_parent: Option<CategoryId>,
+) -> DispatchResult {
+    TestForumModule::move_category(mock_origin(forum_sudo), category_id, new_parent)
+}
+
 pub fn create_root_category(forum_sudo: OriginType) -> CategoryId {
     create_category(forum_sudo, None)
 }
@@ -329,6 +397,27 @@ pub fn create_root_category_and_thread(
         category_id,
         title: good_thread_title(),
         text: good_thread_text(),
+        poll: None,
+        result: Ok(()),
+    }
+    .call_and_assert();
+
+    (member_origin, category_id, thread_id)
+}
+
+pub fn create_root_category_and_thread_with_poll(
+    forum_sudo: OriginType,
+) -> (OriginType, CategoryId, RuntimeThreadId) {
+    let member_origin = create_forum_member();
+    let category_id = create_root_category(forum_sudo);
+    let thread_id = TestForumModule::next_thread_id();
+
+    CreateThreadFixture {
+        origin: member_origin.clone(),
+        category_id,
+        title: good_thread_title(),
+        text: good_thread_text(),
+        poll: Some(good_poll()),
         result: Ok(()),
     }
     .call_and_assert();
@@ -336,6 +425,90 @@ pub fn create_root_category_and_thread(
     (member_origin, category_id, thread_id)
 }
 
+pub fn vote_on_poll(
+    origin: OriginType,
+    thread_id: RuntimeThreadId,
+    alternative_index: u32,
+) -> DispatchResult {
+    TestForumModule::vote_on_poll(
+        mock_origin(origin.clone()),
+        forum_user_id(&origin),
+        thread_id,
+        alternative_index,
+    )
+}
+
+pub fn edit_post_text(
+    origin: OriginType,
+    post_id: RuntimePostId,
+    new_text: Vec<u8>,
+) -> DispatchResult {
+    TestForumModule::edit_post_text(
+        mock_origin(origin.clone()),
+        forum_user_id(&origin),
+        post_id,
+        new_text,
+    )
+}
+
+pub fn delete_post(origin: OriginType, post_id: RuntimePostId) -> DispatchResult {
+    TestForumModule::delete_post(mock_origin(origin.clone()), forum_user_id(&origin), post_id)
+}
+
+pub fn react_to_post(
+    origin: OriginType,
+    post_id: RuntimePostId,
+    reaction: Option<PostReaction>,
+) -> DispatchResult {
+    TestForumModule::react_to_post(
+        mock_origin(origin.clone()),
+        forum_user_id(&origin),
+        post_id,
+        reaction,
+    )
+}
+
+pub fn archive_thread(origin: OriginType, thread_id: RuntimeThreadId) -> DispatchResult {
+    TestForumModule::archive_thread(
+        mock_origin(origin.clone()),
+        forum_user_id(&origin),
+        thread_id,
+    )
+}
+
+pub fn withdraw_thread_deposit(origin: OriginType, thread_id: RuntimeThreadId) -> DispatchResult {
+    TestForumModule::withdraw_thread_deposit(
+        mock_origin(origin.clone()),
+        forum_user_id(&origin),
+        thread_id,
+    )
+}
+
+pub fn withdraw_post_deposit(origin: OriginType, post_id: RuntimePostId) -> DispatchResult {
+    TestForumModule::withdraw_post_deposit(
+        mock_origin(origin.clone()),
+        forum_user_id(&origin),
+        post_id,
+    )
+}
+
+pub fn ban_forum_user(
+    origin: OriginType,
+    forum_user_id: RuntimeMemberId,
+    scope: BanScope,
+    expires_at: Option<<Runtime as system::Trait>::BlockNumber>,
+) -> DispatchResult {
+    TestForumModule::ban_forum_user(mock_origin(origin), forum_user_id, scope, expires_at)
+}
+
+pub fn unban_forum_user(
+    origin: OriginType,
+    forum_user_id: RuntimeMemberId,
+    scope: BanScope,
+) -> DispatchResult {
+    TestForumModule::unban_forum_user(mock_origin(origin), forum_user_id, scope)
+}
+
 pub fn create_root_category_and_thread_and_post(
     forum_sudo: OriginType,
 ) -> (OriginType, CategoryId, RuntimeThreadId, RuntimePostId) {
@@ -346,6 +519,7 @@ pub fn create_root_category_and_thread_and_post(
         origin: member_origin.clone(),
         thread_id: thread_id.clone(),
         text: good_post_text(),
+        reply_to: None,
         result: Ok(()),
     }
     .call_and_assert();
@@ -369,6 +543,44 @@ pub fn moderate_post(
     TestForumModule::moderate_post(mock_origin(forum_sudo), post_id, rationale)
 }
 
+pub fn set_category_moderator_status(
+    forum_sudo: OriginType,
+    category_id: CategoryId,
+    account_id: <Runtime as system::Trait>::AccountId,
+    is_moderator: bool,
+) -> DispatchResult {
+    TestForumModule::set_category_moderator_status(
+        mock_origin(forum_sudo),
+        category_id,
+        account_id,
+        is_moderator,
+    )
+}
+
+pub fn set_thread_sticky_status(
+    forum_sudo: OriginType,
+    thread_id: RuntimeThreadId,
+    sticky: bool,
+) -> DispatchResult {
+    TestForumModule::set_thread_sticky_status(mock_origin(forum_sudo), thread_id, sticky)
+}
+
+pub fn set_thread_lock_status(
+    forum_sudo: OriginType,
+    thread_id: RuntimeThreadId,
+    locked: bool,
+) -> DispatchResult {
+    TestForumModule::set_thread_lock_status(mock_origin(forum_sudo), thread_id, locked)
+}
+
+pub fn move_thread(
+    forum_sudo: OriginType,
+    thread_id: RuntimeThreadId,
+    new_category_id: CategoryId,
+) -> DispatchResult {
+    TestForumModule::move_thread(mock_origin(forum_sudo), thread_id, new_category_id)
+}
+
 pub fn archive_category(forum_sudo: OriginType, category_id: CategoryId) -> DispatchResult {
     TestForumModule::update_category(mock_origin(forum_sudo), category_id, Some(true), None)
 }
@@ -446,10 +658,39 @@ pub fn default_genesis_config() -> GenesisConfig<Runtime> {
         post_moderation_rationale_constraint: InputValidationLengthConstraint {
             min: 10,
             max_min_diff: 2000,
-        }, // JUST GIVING UP ON ALL THIS FOR NOW BECAUSE ITS TAKING TOO LONG
+        },
+
+        poll_description_constraint: InputValidationLengthConstraint {
+            min: 10,
+            max_min_diff: 200,
+        },
 
-           // Extra genesis fields
-           //initial_forum_sudo: Some(143)
+        poll_alternative_text_constraint: InputValidationLengthConstraint {
+            min: 1,
+            max_min_diff: 100,
+        },
+
+        poll_alternatives_number_constraint: InputValidationLengthConstraint {
+            min: 2,
+            max_min_diff: 3,
+        },
+
+        max_sticky_threads_per_category: 2,
+
+        max_post_text_change_history: 2,
+
+        post_text_change_history_hashes_only: false,
+
+        max_posts_per_rate_limit_period: 20,
+
+        rate_limit_period: 10,
+
+        thread_deposit: 0,
+
+        post_deposit: 0, // JUST GIVING UP ON ALL THIS FOR NOW BECAUSE ITS TAKING TOO LONG
+
+                                            // Extra genesis fields
+                                            //initial_forum_sudo: Some(143)
     }
 }
 
@@ -463,20 +704,25 @@ pub type RuntimeThread = Thread<
     <Runtime as system::Trait>::BlockNumber,
     <Runtime as pallet_timestamp::Trait>::Moment,
     <Runtime as system::Trait>::AccountId,
+    RuntimeMemberId,
     RuntimeThreadId,
 >;
 pub type RuntimePost = Post<
     <Runtime as system::Trait>::BlockNumber,
     <Runtime as pallet_timestamp::Trait>::Moment,
     <Runtime as system::Trait>::AccountId,
+    RuntimeMemberId,
     RuntimeThreadId,
     RuntimePostId,
+    <Runtime as system::Trait>::Hash,
 >;
+pub type RuntimePollInput = PollInput<<Runtime as system::Trait>::BlockNumber>;
 pub type RuntimeBlockchainTimestamp = BlockAndTime<
     <Runtime as system::Trait>::BlockNumber,
     <Runtime as pallet_timestamp::Trait>::Moment,
 >;
 
+pub type RuntimeMemberId = <Runtime as Trait>::MemberId;
 pub type RuntimeThreadId = <Runtime as Trait>::ThreadId;
 pub type RuntimePostId = <Runtime as Trait>::PostId;
 
@@ -509,6 +755,7 @@ pub fn genesis_config(
         post_text_constraint: post_text_constraint.clone(),
         thread_moderation_rationale_constraint: thread_moderation_rationale_constraint.clone(),
         post_moderation_rationale_constraint: post_moderation_rationale_constraint.clone(),
+        ..default_genesis_config()
     }
 }
 
@@ -541,3 +788,5 @@ pub fn build_test_externalities(config: GenesisConfig<Runtime>) -> sp_io::TestEx
 
 /// Export forum module on a test runtime
 pub type TestForumModule = Module<Runtime>;
+
+pub type Balances = balances::Module<Runtime>;
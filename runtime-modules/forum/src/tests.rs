@@ -3,7 +3,7 @@
 use super::*;
 use crate::mock::*;
 
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, StorageMap, StorageValue};
 
 /*
 * NB!: No test checks for event emission!!!!
@@ -67,6 +67,40 @@ fn set_forum_sudo_update() {
     });
 }
 
+#[test]
+fn root_can_set_forum_limits() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_ok!(TestForumModule::set_posting_rate_limit(
+            mock_origin(OriginType::Root),
+            3,
+            50
+        ));
+        assert_ok!(TestForumModule::set_max_sticky_threads_per_category(
+            mock_origin(OriginType::Root),
+            2
+        ));
+        assert_ok!(TestForumModule::set_max_post_text_change_history(
+            mock_origin(OriginType::Root),
+            4
+        ));
+
+        assert_eq!(TestForumModule::max_posts_per_rate_limit_period(), 3);
+        assert_eq!(TestForumModule::rate_limit_period(), 50);
+        assert_eq!(TestForumModule::max_sticky_threads_per_category(), 2);
+        assert_eq!(TestForumModule::max_post_text_change_history(), 4);
+
+        assert_err!(
+            TestForumModule::set_max_post_text_change_history(
+                mock_origin(OriginType::Signed(default_genesis_config().forum_sudo)),
+                1
+            ),
+            "Bad origin"
+        );
+    });
+}
+
 /*
  * create_category
  * ==============================================================================
@@ -294,6 +328,7 @@ fn create_thread_successfully() {
             category_id,
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Ok(()),
         }
         .call_and_assert();
@@ -315,6 +350,7 @@ fn create_thread_title_too_short() {
             category_id,
             title: generate_text(min_len - 1),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_THREAD_TITLE_TOO_SHORT),
         }
         .call_and_assert();
@@ -336,6 +372,7 @@ fn create_thread_title_too_long() {
             category_id,
             title: generate_text(max_len + 1),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_THREAD_TITLE_TOO_LONG),
         }
         .call_and_assert();
@@ -357,6 +394,7 @@ fn create_thread_text_too_short() {
             category_id,
             title: good_thread_title(),
             text: generate_text(min_len - 1),
+            poll: None,
             result: Err(ERROR_POST_TEXT_TOO_SHORT),
         }
         .call_and_assert();
@@ -378,6 +416,7 @@ fn create_thread_text_too_long() {
             category_id,
             title: good_thread_title(),
             text: generate_text(max_len + 1),
+            poll: None,
             result: Err(ERROR_POST_TEXT_TOO_LONG),
         }
         .call_and_assert();
@@ -407,6 +446,7 @@ fn create_post_text_too_short() {
             origin: member_origin,
             thread_id,
             text: generate_text(min_len - 1),
+            reply_to: None,
             result: Err(ERROR_POST_TEXT_TOO_SHORT),
         }
         .call_and_assert();
@@ -426,6 +466,7 @@ fn create_post_text_too_long() {
             origin: member_origin,
             thread_id,
             text: generate_text(max_len + 1),
+            reply_to: None,
             result: Err(ERROR_POST_TEXT_TOO_LONG),
         }
         .call_and_assert();
@@ -609,7 +650,7 @@ fn not_forum_sudo_cannot_undelete_category() {
 }
 
 #[test]
-fn not_forum_sudo_cannot_moderate_thread() {
+fn not_moderator_cannot_moderate_thread() {
     let config = default_genesis_config();
     let origin = OriginType::Signed(config.forum_sudo);
 
@@ -617,13 +658,13 @@ fn not_forum_sudo_cannot_moderate_thread() {
         let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
         assert_eq!(
             moderate_thread(NOT_FORUM_SUDO_ORIGIN, thread_id, good_rationale()),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            Err(ERROR_ORIGIN_NOT_CATEGORY_MODERATOR)
         );
     });
 }
 
 #[test]
-fn not_forum_sudo_cannot_moderate_post() {
+fn not_moderator_cannot_moderate_post() {
     let config = default_genesis_config();
     let origin = OriginType::Signed(config.forum_sudo);
 
@@ -631,7 +672,7 @@ fn not_forum_sudo_cannot_moderate_post() {
         let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin.clone());
         assert_eq!(
             moderate_post(NOT_FORUM_SUDO_ORIGIN, post_id, good_rationale()),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            Err(ERROR_ORIGIN_NOT_CATEGORY_MODERATOR)
         );
     });
 }
@@ -650,6 +691,7 @@ fn not_member_cannot_create_thread() {
             category_id: create_root_category(origin),
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_NOT_FORUM_USER),
         }
         .call_and_assert();
@@ -667,6 +709,7 @@ fn not_member_cannot_create_post() {
             origin: NOT_MEMBER_ORIGIN,
             thread_id,
             text: good_post_text(),
+            reply_to: None,
             result: Err(ERROR_NOT_FORUM_USER),
         }
         .call_and_assert();
@@ -683,6 +726,7 @@ fn not_member_cannot_edit_post() {
         assert_err!(
             TestForumModule::edit_post_text(
                 mock_origin(NOT_MEMBER_ORIGIN),
+                forum_user_id(&NOT_MEMBER_ORIGIN),
                 post_id,
                 good_rationale()
             ),
@@ -691,6 +735,55 @@ fn not_member_cannot_edit_post() {
     });
 }
 
+#[test]
+fn member_cannot_create_thread_on_behalf_of_another_member() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_forum_member();
+        let category_id = create_root_category(origin);
+
+        assert_err!(
+            TestForumModule::create_thread(
+                mock_origin(NOT_MEMBER_ORIGIN),
+                forum_user_id(&member_origin),
+                category_id,
+                good_thread_title(),
+                good_thread_text(),
+                None
+            ),
+            ERROR_NOT_FORUM_USER
+        );
+    });
+}
+
+#[test]
+fn member_cannot_edit_post_of_another_member() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin);
+
+        let other_member_id = 124;
+        registry::TestMembershipRegistryModule::add_member(&registry::Member {
+            id: other_member_id,
+            account_id: other_member_id,
+        });
+
+        assert_err!(
+            TestForumModule::edit_post_text(
+                mock_origin(OriginType::Signed(other_member_id)),
+                other_member_id,
+                post_id,
+                good_post_text()
+            ),
+            ERROR_MEMBER_DOES_NOT_MATCH_POST_AUTHOR
+        );
+    });
+}
+
 // Invalid id passed:
 // -----------------------------------------------------------------------------
 
@@ -718,6 +811,7 @@ fn cannot_create_thread_with_invalid_category_id() {
             category_id: INVLAID_CATEGORY_ID,
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_CATEGORY_DOES_NOT_EXIST),
         }
         .call_and_assert();
@@ -733,6 +827,7 @@ fn cannot_create_post_with_invalid_thread_id() {
             origin: create_forum_member(),
             thread_id: INVLAID_THREAD_ID,
             text: good_post_text(),
+            reply_to: None,
             result: Err(ERROR_THREAD_DOES_NOT_EXIST),
         }
         .call_and_assert();
@@ -970,7 +1065,12 @@ fn cannot_edit_post_in_moderated_thread() {
             create_root_category_and_thread_and_post(forum_sudo.clone());
         assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
         assert_err!(
-            TestForumModule::edit_post_text(mock_origin(member_origin), post_id, good_rationale()),
+            TestForumModule::edit_post_text(
+                mock_origin(member_origin.clone()),
+                forum_user_id(&member_origin),
+                post_id,
+                good_rationale()
+            ),
             ERROR_THREAD_MODERATED
         );
     });
@@ -979,3 +1079,1461 @@ fn cannot_edit_post_in_moderated_thread() {
 // TODO impl
 // #[test]
 // fn cannot_edit_moderated_post() {}
+
+// Polls:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn create_thread_with_poll() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread_with_poll(forum_sudo);
+
+        let poll = TestForumModule::poll_by_thread_id(thread_id).unwrap();
+        assert_eq!(poll.description, good_poll().description);
+        assert_eq!(poll.end_block, good_poll().end_block);
+        assert_eq!(
+            poll.alternatives
+                .iter()
+                .map(|alternative| (alternative.text.clone(), alternative.vote_count))
+                .collect::<Vec<_>>(),
+            good_poll()
+                .alternatives
+                .into_iter()
+                .map(|text| (text, 0))
+                .collect::<Vec<_>>()
+        );
+    });
+}
+
+#[test]
+fn cannot_create_thread_with_invalid_poll() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_forum_member();
+        let category_id = create_root_category(forum_sudo);
+
+        let invalid_polls = vec![
+            (
+                RuntimePollInput {
+                    alternatives: vec![b"Single option".to_vec()],
+                    ..good_poll()
+                },
+                ERROR_POLL_ALTERNATIVES_TOO_FEW,
+            ),
+            (
+                RuntimePollInput {
+                    alternatives: vec![b"Option".to_vec(); 6],
+                    ..good_poll()
+                },
+                ERROR_POLL_ALTERNATIVES_TOO_MANY,
+            ),
+            (
+                RuntimePollInput {
+                    alternatives: vec![b"Option".to_vec(), vec![]],
+                    ..good_poll()
+                },
+                ERROR_POLL_ALTERNATIVE_TEXT_TOO_SHORT,
+            ),
+            (
+                RuntimePollInput {
+                    description: generate_text(5),
+                    ..good_poll()
+                },
+                ERROR_POLL_DESCRIPTION_TOO_SHORT,
+            ),
+            (
+                RuntimePollInput {
+                    end_block: 0,
+                    ..good_poll()
+                },
+                ERROR_POLL_END_BLOCK_NOT_IN_FUTURE,
+            ),
+        ];
+
+        for (poll, error) in invalid_polls {
+            CreateThreadFixture {
+                origin: member_origin.clone(),
+                category_id,
+                title: good_thread_title(),
+                text: good_thread_text(),
+                poll: Some(poll),
+                result: Err(error),
+            }
+            .call_and_assert();
+        }
+    });
+}
+
+#[test]
+fn members_vote_on_poll_once() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread_with_poll(forum_sudo);
+
+        assert_ok!(vote_on_poll(member_origin.clone(), thread_id, 1));
+        assert_err!(
+            vote_on_poll(member_origin.clone(), thread_id, 0),
+            ERROR_ALREADY_VOTED_ON_POLL
+        );
+        assert_err!(
+            vote_on_poll(NOT_MEMBER_ORIGIN, thread_id, 0),
+            ERROR_NOT_FORUM_USER
+        );
+
+        let poll = TestForumModule::poll_by_thread_id(thread_id).unwrap();
+        assert_eq!(poll.alternatives[0].vote_count, 0);
+        assert_eq!(poll.alternatives[1].vote_count, 1);
+        assert_eq!(
+            TestForumModule::poll_vote_by_member_id(thread_id, forum_user_id(&member_origin)),
+            Some(1)
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_on_missing_alternative_or_thread_without_poll() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id) =
+            create_root_category_and_thread_with_poll(forum_sudo);
+
+        assert_err!(
+            vote_on_poll(member_origin.clone(), thread_id, 2),
+            ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST
+        );
+
+        let thread_without_poll_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), category_id, Ok(()));
+        assert_err!(
+            vote_on_poll(member_origin, thread_without_poll_id, 0),
+            ERROR_POLL_DOES_NOT_EXIST
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_on_ended_poll() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread_with_poll(forum_sudo);
+
+        system::Module::<Runtime>::set_block_number(good_poll().end_block);
+
+        assert_err!(vote_on_poll(member_origin, thread_id, 0), ERROR_POLL_ENDED);
+    });
+}
+
+#[test]
+fn cannot_vote_on_poll_in_moderated_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) =
+            create_root_category_and_thread_with_poll(forum_sudo.clone());
+
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+        assert_err!(
+            vote_on_poll(member_origin, thread_id, 0),
+            ERROR_THREAD_MODERATED
+        );
+    });
+}
+
+// Sticky, locked and moved threads:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn forum_sudo_can_pin_threads_up_to_limit() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, first_thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+
+        let mut thread_ids = vec![first_thread_id];
+        for _ in 0..2 {
+            thread_ids.push(TestForumModule::next_thread_id());
+            assert_create_thread(member_origin.clone(), category_id, Ok(()));
+        }
+
+        assert_ok!(set_thread_sticky_status(
+            forum_sudo.clone(),
+            thread_ids[0],
+            true
+        ));
+        assert_ok!(set_thread_sticky_status(
+            forum_sudo.clone(),
+            thread_ids[1],
+            true
+        ));
+        assert_err!(
+            set_thread_sticky_status(forum_sudo.clone(), thread_ids[2], true),
+            ERROR_MAX_STICKY_THREADS_EXCEEDED
+        );
+        assert_err!(
+            set_thread_sticky_status(NOT_FORUM_SUDO_ORIGIN, thread_ids[2], true),
+            ERROR_ORIGIN_NOT_CATEGORY_MODERATOR
+        );
+        assert_eq!(
+            TestForumModule::sticky_thread_ids(category_id),
+            vec![thread_ids[0], thread_ids[1]]
+        );
+
+        assert_ok!(set_thread_sticky_status(
+            forum_sudo.clone(),
+            thread_ids[0],
+            false
+        ));
+        assert_ok!(set_thread_sticky_status(
+            forum_sudo.clone(),
+            thread_ids[2],
+            true
+        ));
+        assert_eq!(
+            TestForumModule::sticky_thread_ids(category_id),
+            vec![thread_ids[1], thread_ids[2]]
+        );
+
+        // Moderated thread is unpinned
+        assert_ok!(moderate_thread(forum_sudo, thread_ids[1], good_rationale()));
+        assert_eq!(
+            TestForumModule::sticky_thread_ids(category_id),
+            vec![thread_ids[2]]
+        );
+    });
+}
+
+#[test]
+fn cannot_add_post_to_locked_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_ok!(set_thread_lock_status(forum_sudo.clone(), thread_id, true));
+        assert!(TestForumModule::locked_threads(thread_id));
+        assert_create_post(member_origin.clone(), thread_id, Err(ERROR_THREAD_LOCKED));
+
+        assert_ok!(set_thread_lock_status(forum_sudo, thread_id, false));
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn forum_sudo_can_move_thread_to_another_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, old_category_id, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let new_category_id = create_root_category(forum_sudo.clone());
+
+        assert_ok!(set_thread_sticky_status(
+            forum_sudo.clone(),
+            thread_id,
+            true
+        ));
+        assert_ok!(move_thread(forum_sudo.clone(), thread_id, new_category_id));
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.category_id, new_category_id);
+        assert_eq!(thread.nr_in_category, 1);
+        assert!(TestForumModule::sticky_thread_ids(old_category_id).is_empty());
+
+        let old_category = TestForumModule::category_by_id(old_category_id);
+        assert_eq!(old_category.num_direct_unmoderated_threads, 0);
+        let new_category = TestForumModule::category_by_id(new_category_id);
+        assert_eq!(new_category.num_direct_unmoderated_threads, 1);
+
+        // Moderated thread moves the moderated thread counters
+        assert_ok!(moderate_thread(
+            forum_sudo.clone(),
+            thread_id,
+            good_rationale()
+        ));
+        assert_ok!(move_thread(forum_sudo.clone(), thread_id, old_category_id));
+
+        // Thread numbers are never reused in a category
+        assert_eq!(TestForumModule::thread_by_id(thread_id).nr_in_category, 2);
+
+        let old_category = TestForumModule::category_by_id(old_category_id);
+        assert_eq!(old_category.num_direct_moderated_threads, 1);
+        let new_category = TestForumModule::category_by_id(new_category_id);
+        assert_eq!(new_category.num_direct_moderated_threads, 0);
+        assert_eq!(new_category.num_direct_unmoderated_threads, 0);
+
+        assert_err!(
+            move_thread(forum_sudo, thread_id, old_category_id),
+            ERROR_THREAD_ALREADY_IN_CATEGORY
+        );
+    });
+}
+
+#[test]
+fn cannot_move_thread_to_archived_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let archived_category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(archive_category(forum_sudo.clone(), archived_category_id));
+
+        assert_err!(
+            move_thread(forum_sudo.clone(), thread_id, archived_category_id),
+            ERROR_ANCESTOR_CATEGORY_IMMUTABLE
+        );
+        assert_err!(
+            move_thread(forum_sudo, thread_id, INVLAID_CATEGORY_ID),
+            ERROR_CATEGORY_DOES_NOT_EXIST
+        );
+    });
+}
+
+// Category moderators:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn category_moderator_can_moderate_in_category_and_subcategories() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, root_category_id, root_thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+        let subcategory_id = create_category(forum_sudo.clone(), Some(root_category_id));
+        let subcategory_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), subcategory_id, Ok(()));
+
+        assert_ok!(set_category_moderator_status(
+            forum_sudo.clone(),
+            root_category_id,
+            MODERATOR_ACCOUNT_ID,
+            true
+        ));
+        assert!(TestForumModule::category_moderators(
+            root_category_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+
+        // Moderator of the ancestor moderates the subcategory
+        assert_ok!(moderate_thread(
+            MODERATOR_ORIGIN,
+            subcategory_thread_id,
+            good_rationale()
+        ));
+        assert_eq!(
+            TestForumModule::thread_by_id(subcategory_thread_id)
+                .moderation
+                .unwrap()
+                .moderator_id,
+            MODERATOR_ACCOUNT_ID
+        );
+
+        // Removed moderator can no longer moderate
+        assert_ok!(set_category_moderator_status(
+            forum_sudo,
+            root_category_id,
+            MODERATOR_ACCOUNT_ID,
+            false
+        ));
+        assert_eq!(
+            moderate_thread(MODERATOR_ORIGIN, root_thread_id, good_rationale()),
+            Err(ERROR_ORIGIN_NOT_CATEGORY_MODERATOR)
+        );
+    });
+}
+
+#[test]
+fn subcategory_moderator_cannot_moderate_in_parent_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, root_category_id, _, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+        let subcategory_id = create_category(forum_sudo.clone(), Some(root_category_id));
+
+        assert_ok!(set_category_moderator_status(
+            forum_sudo,
+            subcategory_id,
+            MODERATOR_ACCOUNT_ID,
+            true
+        ));
+        assert_eq!(
+            moderate_post(MODERATOR_ORIGIN, post_id, good_rationale()),
+            Err(ERROR_ORIGIN_NOT_CATEGORY_MODERATOR)
+        );
+    });
+}
+
+#[test]
+fn only_forum_sudo_can_assign_category_moderators() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+
+        assert_ok!(set_category_moderator_status(
+            forum_sudo.clone(),
+            category_id,
+            MODERATOR_ACCOUNT_ID,
+            true
+        ));
+        assert_eq!(
+            set_category_moderator_status(MODERATOR_ORIGIN, category_id, 1, true),
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+        );
+        assert_eq!(
+            set_category_moderator_status(
+                forum_sudo,
+                INVLAID_CATEGORY_ID,
+                MODERATOR_ACCOUNT_ID,
+                true
+            ),
+            Err(ERROR_CATEGORY_DOES_NOT_EXIST)
+        );
+    });
+}
+
+// Post reactions:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn forum_user_can_change_and_remove_post_reaction() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Like)
+        ));
+        assert_eq!(
+            TestForumModule::post_by_id(post_id).reaction_counts,
+            PostReactionCounts {
+                like: 1,
+                ..Default::default()
+            }
+        );
+
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Funny)
+        ));
+        assert_eq!(
+            TestForumModule::post_by_id(post_id).reaction_counts,
+            PostReactionCounts {
+                funny: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            TestForumModule::post_reaction_by_member_id(post_id, forum_user_id(&member_origin)),
+            Some(PostReaction::Funny)
+        );
+
+        assert_ok!(react_to_post(member_origin.clone(), post_id, None));
+        assert_eq!(
+            TestForumModule::post_by_id(post_id).reaction_counts,
+            PostReactionCounts::default()
+        );
+        assert!(TestForumModule::post_reaction_by_member_id(
+            post_id,
+            forum_user_id(&member_origin)
+        )
+        .is_none());
+    });
+}
+
+#[test]
+fn cannot_react_to_moderated_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+
+        assert_err!(
+            react_to_post(NOT_MEMBER_ORIGIN, post_id, Some(PostReaction::Like)),
+            ERROR_NOT_FORUM_USER
+        );
+
+        assert_ok!(moderate_post(forum_sudo, post_id, good_rationale()));
+        assert_err!(
+            react_to_post(member_origin, post_id, Some(PostReaction::Dislike)),
+            ERROR_POST_MODERATED
+        );
+    });
+}
+
+// Replies:
+// -----------------------------------------------------------------------------
+
+fn assert_create_reply(
+    origin: OriginType,
+    thread_id: RuntimeThreadId,
+    reply_to: RuntimePostId,
+    expected_result: DispatchResult,
+) {
+    CreatePostFixture {
+        origin,
+        thread_id,
+        text: good_post_text(),
+        reply_to: Some(reply_to),
+        result: expected_result,
+    }
+    .call_and_assert();
+}
+
+#[test]
+fn forum_user_can_reply_to_post_in_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo);
+
+        let reply_id = TestForumModule::next_post_id();
+        assert_create_reply(member_origin, thread_id, post_id, Ok(()));
+
+        assert_eq!(
+            TestForumModule::post_by_id(reply_id).reply_to,
+            Some(post_id)
+        );
+        assert_eq!(TestForumModule::post_by_id(post_id).reply_to, None);
+    });
+}
+
+#[test]
+fn cannot_reply_to_invalid_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+
+        assert_create_reply(
+            member_origin.clone(),
+            thread_id,
+            INVLAID_POST_ID,
+            Err(ERROR_POST_DOES_NOT_EXIST),
+        );
+
+        let another_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), category_id, Ok(()));
+        assert_create_reply(
+            member_origin.clone(),
+            another_thread_id,
+            post_id,
+            Err(ERROR_REPLY_TO_POST_NOT_IN_THREAD),
+        );
+
+        assert_ok!(moderate_post(forum_sudo, post_id, good_rationale()));
+        assert_create_reply(
+            member_origin,
+            thread_id,
+            post_id,
+            Err(ERROR_REPLY_TO_POST_MODERATED),
+        );
+    });
+}
+
+// Post edit history and deletion:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn post_text_change_history_keeps_latest_revisions() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+
+        for text in vec![
+            b"first edit".to_vec(),
+            b"second edit".to_vec(),
+            b"third edit".to_vec(),
+        ] {
+            assert_ok!(edit_post_text(member_origin.clone(), post_id, text));
+        }
+
+        let post = TestForumModule::post_by_id(post_id);
+        assert_eq!(post.current_text, b"third edit".to_vec());
+        assert_eq!(post.edit_count, 3);
+        assert_eq!(
+            post.text_change_history
+                .into_iter()
+                .map(|change| change.text)
+                .collect::<Vec<_>>(),
+            vec![
+                PostRevisionText::Text(b"first edit".to_vec()),
+                PostRevisionText::Text(b"second edit".to_vec()),
+            ]
+        );
+    });
+}
+
+#[test]
+fn post_text_change_history_can_keep_hashes_only() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+
+        PostTextChangeHistoryHashesOnly::put(true);
+        assert_ok!(edit_post_text(member_origin, post_id, b"edit".to_vec()));
+
+        let expected_hash = <Runtime as system::Trait>::Hashing::hash(&good_post_text());
+        assert_eq!(
+            TestForumModule::post_by_id(post_id).text_change_history[0].text,
+            PostRevisionText::Hash(expected_hash)
+        );
+    });
+}
+
+#[test]
+fn author_can_delete_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo);
+        assert_ok!(edit_post_text(
+            member_origin.clone(),
+            post_id,
+            b"edit".to_vec()
+        ));
+
+        let thread_before = TestForumModule::thread_by_id(thread_id);
+        let post_before = TestForumModule::post_by_id(post_id);
+
+        assert_ok!(delete_post(member_origin.clone(), post_id));
+
+        let post = TestForumModule::post_by_id(post_id);
+        assert!(post.deleted);
+        assert!(post.current_text.is_empty());
+        assert!(post.text_change_history.is_empty());
+        assert_eq!(post.nr_in_thread, post_before.nr_in_thread);
+        assert_eq!(TestForumModule::thread_by_id(thread_id), thread_before);
+
+        assert_err!(
+            edit_post_text(member_origin.clone(), post_id, b"edit".to_vec()),
+            ERROR_POST_DELETED
+        );
+        assert_err!(delete_post(member_origin, post_id), ERROR_POST_DELETED);
+    });
+}
+
+#[test]
+fn not_author_cannot_delete_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+
+        let other_member_id = 124;
+        registry::TestMembershipRegistryModule::add_member(&registry::Member {
+            id: other_member_id,
+            account_id: other_member_id,
+        });
+
+        assert_err!(
+            delete_post(OriginType::Signed(other_member_id), post_id),
+            ERROR_MEMBER_DOES_NOT_MATCH_POST_AUTHOR
+        );
+    });
+}
+
+// Bans and rate limits:
+// -----------------------------------------------------------------------------
+
+fn assert_create_thread(
+    origin: OriginType,
+    category_id: CategoryId,
+    expected_result: DispatchResult,
+) {
+    CreateThreadFixture {
+        origin,
+        category_id,
+        title: good_thread_title(),
+        text: good_thread_text(),
+        poll: None,
+        result: expected_result,
+    }
+    .call_and_assert();
+}
+
+fn assert_create_post(
+    origin: OriginType,
+    thread_id: RuntimeThreadId,
+    expected_result: DispatchResult,
+) {
+    CreatePostFixture {
+        origin,
+        thread_id,
+        text: good_post_text(),
+        reply_to: None,
+        result: expected_result,
+    }
+    .call_and_assert();
+}
+
+#[test]
+fn forum_sudo_can_ban_and_unban_forum_user_forum_wide() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+        let member_id = forum_user_id(&member_origin);
+
+        assert_ok!(ban_forum_user(
+            forum_sudo.clone(),
+            member_id,
+            BanScope::Forum,
+            None
+        ));
+        assert_create_post(
+            member_origin.clone(),
+            thread_id,
+            Err(ERROR_FORUM_USER_BANNED),
+        );
+        assert_create_thread(
+            member_origin.clone(),
+            category_id,
+            Err(ERROR_FORUM_USER_BANNED),
+        );
+
+        assert_ok!(unban_forum_user(forum_sudo, member_id, BanScope::Forum));
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn category_ban_applies_to_subcategories_only() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_forum_member();
+        let member_id = forum_user_id(&member_origin);
+        let category_id = create_root_category(forum_sudo.clone());
+        let subcategory_id = create_category(forum_sudo.clone(), Some(category_id));
+        let other_category_id = create_root_category(forum_sudo.clone());
+
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), subcategory_id, Ok(()));
+        let other_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), other_category_id, Ok(()));
+
+        assert_ok!(set_category_moderator_status(
+            forum_sudo,
+            category_id,
+            MODERATOR_ACCOUNT_ID,
+            true
+        ));
+        assert_ok!(ban_forum_user(
+            MODERATOR_ORIGIN,
+            member_id,
+            BanScope::Category(category_id),
+            None
+        ));
+
+        assert_create_post(
+            member_origin.clone(),
+            thread_id,
+            Err(ERROR_FORUM_USER_BANNED),
+        );
+        assert_create_post(member_origin, other_thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn only_moderators_can_ban_forum_users() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(set_category_moderator_status(
+            forum_sudo,
+            category_id,
+            MODERATOR_ACCOUNT_ID,
+            true
+        ));
+
+        assert_err!(
+            ban_forum_user(MODERATOR_ORIGIN, 1, BanScope::Forum, None),
+            ERROR_ORIGIN_NOT_FORUM_SUDO
+        );
+        assert_err!(
+            ban_forum_user(
+                NOT_FORUM_SUDO_ORIGIN,
+                1,
+                BanScope::Category(category_id),
+                None
+            ),
+            ERROR_ORIGIN_NOT_CATEGORY_MODERATOR
+        );
+        assert_err!(
+            unban_forum_user(NOT_FORUM_SUDO_ORIGIN, 1, BanScope::Category(category_id)),
+            ERROR_ORIGIN_NOT_CATEGORY_MODERATOR
+        );
+        assert_err!(
+            ban_forum_user(
+                MODERATOR_ORIGIN,
+                1,
+                BanScope::Category(INVLAID_CATEGORY_ID),
+                None
+            ),
+            ERROR_CATEGORY_DOES_NOT_EXIST
+        );
+    });
+}
+
+#[test]
+fn forum_user_ban_expires() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let member_id = forum_user_id(&member_origin);
+        let now = system::Module::<Runtime>::block_number();
+
+        assert_err!(
+            ban_forum_user(forum_sudo.clone(), member_id, BanScope::Forum, Some(now)),
+            ERROR_BAN_EXPIRY_NOT_IN_FUTURE
+        );
+        assert_ok!(ban_forum_user(
+            forum_sudo,
+            member_id,
+            BanScope::Forum,
+            Some(now + 5)
+        ));
+        assert_create_post(
+            member_origin.clone(),
+            thread_id,
+            Err(ERROR_FORUM_USER_BANNED),
+        );
+
+        system::Module::<Runtime>::set_block_number(now + 5);
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn forum_user_posting_is_rate_limited() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let max_posts = config.max_posts_per_rate_limit_period;
+    let period = config.rate_limit_period;
+
+    build_test_externalities(config).execute_with(|| {
+        // Creating the thread counts towards the limit
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+        let now = system::Module::<Runtime>::block_number();
+
+        for _ in 1..max_posts {
+            assert_create_post(member_origin.clone(), thread_id, Ok(()));
+        }
+        assert_create_post(
+            member_origin.clone(),
+            thread_id,
+            Err(ERROR_POSTING_RATE_LIMIT_EXCEEDED),
+        );
+
+        system::Module::<Runtime>::set_block_number(now + period);
+        assert_create_post(member_origin.clone(), thread_id, Ok(()));
+        assert_eq!(
+            TestForumModule::posting_activity_by_member_id(forum_user_id(&member_origin)),
+            PostingActivity {
+                period_started_at: now + period,
+                count: 1,
+            }
+        );
+    });
+}
+
+// Deposits:
+// -----------------------------------------------------------------------------
+
+const THREAD_DEPOSIT: u64 = 10;
+const POST_DEPOSIT: u64 = 5;
+
+fn deposit_genesis_config() -> GenesisConfig<Runtime> {
+    GenesisConfig::<Runtime> {
+        thread_deposit: THREAD_DEPOSIT,
+        post_deposit: POST_DEPOSIT,
+        ..default_genesis_config()
+    }
+}
+
+fn create_funded_forum_member(balance: u64) -> OriginType {
+    let member_origin = create_forum_member();
+    let _ = Balances::make_free_balance_be(&forum_user_id(&member_origin), balance);
+
+    member_origin
+}
+
+#[test]
+fn thread_and_post_deposits_are_reserved() {
+    let config = deposit_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_funded_forum_member(100);
+        let account_id = forum_user_id(&member_origin);
+        let category_id = create_root_category(forum_sudo);
+
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), category_id, Ok(()));
+        let post_id = TestForumModule::next_post_id();
+        assert_create_post(member_origin, thread_id, Ok(()));
+
+        assert_eq!(
+            Balances::reserved_balance(&account_id),
+            THREAD_DEPOSIT + POST_DEPOSIT
+        );
+        assert_eq!(
+            TestForumModule::thread_deposit_by_id(thread_id),
+            Some(Deposit {
+                account_id,
+                amount: THREAD_DEPOSIT,
+            })
+        );
+        assert_eq!(
+            TestForumModule::post_deposit_by_id(thread_id, post_id),
+            Some(Deposit {
+                account_id,
+                amount: POST_DEPOSIT,
+            })
+        );
+    });
+}
+
+#[test]
+fn deposits_require_sufficient_balance() {
+    let config = deposit_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_funded_forum_member(THREAD_DEPOSIT + POST_DEPOSIT - 1);
+        let category_id = create_root_category(forum_sudo);
+
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), category_id, Ok(()));
+        assert_create_post(
+            member_origin.clone(),
+            thread_id,
+            Err(ERROR_INSUFFICIENT_BALANCE_FOR_DEPOSIT),
+        );
+        assert_create_thread(
+            member_origin,
+            category_id,
+            Err(ERROR_INSUFFICIENT_BALANCE_FOR_DEPOSIT),
+        );
+    });
+}
+
+#[test]
+fn deposits_are_refunded_on_post_deletion_and_archived_thread_withdrawal() {
+    let config = deposit_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_funded_forum_member(100);
+        let account_id = forum_user_id(&member_origin);
+        let category_id = create_root_category(forum_sudo);
+
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), category_id, Ok(()));
+        let post_id = TestForumModule::next_post_id();
+        assert_create_post(member_origin.clone(), thread_id, Ok(()));
+
+        assert_ok!(delete_post(member_origin.clone(), post_id));
+        assert_eq!(Balances::reserved_balance(&account_id), THREAD_DEPOSIT);
+        assert!(TestForumModule::post_deposit_by_id(thread_id, post_id).is_none());
+
+        let other_member_id = 124;
+        registry::TestMembershipRegistryModule::add_member(&registry::Member {
+            id: other_member_id,
+            account_id: other_member_id,
+        });
+        assert_err!(
+            archive_thread(OriginType::Signed(other_member_id), thread_id),
+            ERROR_MEMBER_DOES_NOT_MATCH_THREAD_AUTHOR
+        );
+
+        assert_err!(
+            withdraw_thread_deposit(member_origin.clone(), thread_id),
+            ERROR_THREAD_NOT_ARCHIVED
+        );
+
+        assert_ok!(archive_thread(member_origin.clone(), thread_id));
+        assert_eq!(Balances::reserved_balance(&account_id), THREAD_DEPOSIT);
+        assert!(TestForumModule::archived_threads(thread_id).is_some());
+        assert!(!TestForumModule::locked_threads(thread_id));
+
+        assert_create_post(member_origin.clone(), thread_id, Err(ERROR_THREAD_ARCHIVED));
+
+        // Deposit stays reserved for the moderation period of the archived thread
+        assert_err!(
+            withdraw_thread_deposit(member_origin.clone(), thread_id),
+            ERROR_ARCHIVED_THREAD_MODERATION_PERIOD_NOT_OVER
+        );
+
+        let now = system::Module::<Runtime>::block_number();
+        system::Module::<Runtime>::set_block_number(now + ArchivedThreadModerationPeriod::get());
+
+        assert_ok!(withdraw_thread_deposit(member_origin.clone(), thread_id));
+        assert_eq!(Balances::reserved_balance(&account_id), 0);
+        assert_eq!(Balances::free_balance(&account_id), 100);
+
+        assert_err!(
+            withdraw_thread_deposit(member_origin, thread_id),
+            ERROR_THREAD_DEPOSIT_DOES_NOT_EXIST
+        );
+    });
+}
+
+#[test]
+fn archived_thread_deposit_can_be_slashed_during_moderation_period() {
+    let config = deposit_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_funded_forum_member(100);
+        let account_id = forum_user_id(&member_origin);
+        let category_id = create_root_category(forum_sudo.clone());
+
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), category_id, Ok(()));
+
+        assert_ok!(archive_thread(member_origin.clone(), thread_id));
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+        assert_eq!(Balances::reserved_balance(&account_id), 0);
+        assert_eq!(Balances::free_balance(&account_id), 100 - THREAD_DEPOSIT);
+
+        let now = system::Module::<Runtime>::block_number();
+        system::Module::<Runtime>::set_block_number(now + ArchivedThreadModerationPeriod::get());
+
+        assert_err!(
+            withdraw_thread_deposit(member_origin, thread_id),
+            ERROR_THREAD_DEPOSIT_DOES_NOT_EXIST
+        );
+    });
+}
+
+#[test]
+fn deposits_are_slashed_on_moderation() {
+    let config = deposit_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_funded_forum_member(100);
+        let account_id = forum_user_id(&member_origin);
+        let category_id = create_root_category(forum_sudo.clone());
+
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), category_id, Ok(()));
+        let post_id = TestForumModule::next_post_id();
+        assert_create_post(member_origin, thread_id, Ok(()));
+
+        assert_ok!(moderate_post(forum_sudo.clone(), post_id, good_rationale()));
+        assert_eq!(Balances::reserved_balance(&account_id), THREAD_DEPOSIT);
+
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+        assert_eq!(Balances::reserved_balance(&account_id), 0);
+        assert_eq!(
+            Balances::free_balance(&account_id),
+            100 - THREAD_DEPOSIT - POST_DEPOSIT
+        );
+        assert_eq!(
+            Balances::total_issuance(),
+            100 - THREAD_DEPOSIT - POST_DEPOSIT
+        );
+    });
+}
+
+#[test]
+fn post_deposits_are_refunded_on_thread_moderation() {
+    let config = deposit_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_funded_forum_member(100);
+        let account_id = forum_user_id(&member_origin);
+        let category_id = create_root_category(forum_sudo.clone());
+
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), category_id, Ok(()));
+
+        let other_member_id = 124;
+        registry::TestMembershipRegistryModule::add_member(&registry::Member {
+            id: other_member_id,
+            account_id: other_member_id,
+        });
+        let other_member_origin = OriginType::Signed(other_member_id);
+        let _ = Balances::make_free_balance_be(&other_member_id, 100);
+
+        let post_id = TestForumModule::next_post_id();
+        assert_create_post(other_member_origin.clone(), thread_id, Ok(()));
+        assert_eq!(Balances::reserved_balance(&other_member_id), POST_DEPOSIT);
+
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+        assert_eq!(Balances::free_balance(&account_id), 100 - THREAD_DEPOSIT);
+        assert_eq!(Balances::reserved_balance(&other_member_id), 0);
+        assert_eq!(Balances::free_balance(&other_member_id), 100);
+        assert!(TestForumModule::post_deposit_by_id(thread_id, post_id).is_none());
+
+        // Post in the moderated thread can not be deleted
+        assert_err!(
+            delete_post(other_member_origin, post_id),
+            ERROR_THREAD_MODERATED
+        );
+    });
+}
+
+#[test]
+fn deposits_can_be_withdrawn_in_archived_or_deleted_category() {
+    let config = deposit_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_funded_forum_member(100);
+        let account_id = forum_user_id(&member_origin);
+
+        let archived_category_id = create_root_category(forum_sudo.clone());
+        let deleted_category_id = create_root_category(forum_sudo.clone());
+
+        let mut thread_and_post_ids = Vec::new();
+        for category_id in vec![archived_category_id, deleted_category_id] {
+            let thread_id = TestForumModule::next_thread_id();
+            assert_create_thread(member_origin.clone(), category_id, Ok(()));
+            let post_id = TestForumModule::next_post_id();
+            assert_create_post(member_origin.clone(), thread_id, Ok(()));
+
+            thread_and_post_ids.push((thread_id, post_id));
+        }
+
+        let (thread_id, post_id) = thread_and_post_ids[0];
+        assert_err!(
+            withdraw_post_deposit(member_origin.clone(), post_id),
+            ERROR_POST_DEPOSIT_NOT_WITHDRAWABLE
+        );
+        assert_err!(
+            withdraw_thread_deposit(member_origin.clone(), thread_id),
+            ERROR_THREAD_NOT_ARCHIVED
+        );
+
+        assert_ok!(archive_category(forum_sudo.clone(), archived_category_id));
+        assert_ok!(delete_category(forum_sudo, deleted_category_id));
+
+        // Threads and posts in the immutable categories can be neither archived nor deleted
+        assert_err!(
+            archive_thread(member_origin.clone(), thread_id),
+            ERROR_ANCESTOR_CATEGORY_IMMUTABLE
+        );
+        assert_err!(
+            delete_post(member_origin.clone(), post_id),
+            ERROR_ANCESTOR_CATEGORY_IMMUTABLE
+        );
+
+        let other_member_id = 124;
+        registry::TestMembershipRegistryModule::add_member(&registry::Member {
+            id: other_member_id,
+            account_id: other_member_id,
+        });
+        assert_err!(
+            withdraw_post_deposit(OriginType::Signed(other_member_id), post_id),
+            ERROR_MEMBER_DOES_NOT_MATCH_POST_AUTHOR
+        );
+
+        for (thread_id, post_id) in thread_and_post_ids {
+            assert_ok!(withdraw_thread_deposit(member_origin.clone(), thread_id));
+            assert_ok!(withdraw_post_deposit(member_origin.clone(), post_id));
+
+            assert!(TestForumModule::thread_deposit_by_id(thread_id).is_none());
+            assert!(TestForumModule::post_deposit_by_id(thread_id, post_id).is_none());
+
+            assert_err!(
+                withdraw_thread_deposit(member_origin.clone(), thread_id),
+                ERROR_THREAD_DEPOSIT_DOES_NOT_EXIST
+            );
+            assert_err!(
+                withdraw_post_deposit(member_origin.clone(), post_id),
+                ERROR_POST_DEPOSIT_DOES_NOT_EXIST
+            );
+        }
+
+        assert_eq!(Balances::reserved_balance(&account_id), 0);
+        assert_eq!(Balances::free_balance(&account_id), 100);
+    });
+}
+
+// Moving categories:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn forum_sudo_can_move_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let old_parent_id = create_root_category(forum_sudo.clone());
+        let new_parent_id = create_root_category(forum_sudo.clone());
+        let category_id = create_category(forum_sudo.clone(), Some(old_parent_id));
+
+        assert_ok!(move_category(
+            forum_sudo.clone(),
+            category_id,
+            Some(new_parent_id)
+        ));
+
+        assert_eq!(
+            TestForumModule::category_by_id(old_parent_id).num_direct_subcategories,
+            0
+        );
+        assert_eq!(
+            TestForumModule::category_by_id(new_parent_id).num_direct_subcategories,
+            1
+        );
+        assert_eq!(
+            TestForumModule::category_by_id(category_id).position_in_parent_category,
+            Some(ChildPositionInParentCategory {
+                parent_id: new_parent_id,
+                child_nr_in_parent_category: 1,
+            })
+        );
+
+        assert_ok!(move_category(forum_sudo.clone(), category_id, None));
+        assert_eq!(
+            TestForumModule::category_by_id(new_parent_id).num_direct_subcategories,
+            0
+        );
+        assert!(TestForumModule::category_by_id(category_id)
+            .position_in_parent_category
+            .is_none());
+
+        // Subcategory numbers are never reused in a category
+        let subcategory_id = create_category(forum_sudo, Some(new_parent_id));
+        assert_eq!(
+            TestForumModule::category_by_id(subcategory_id).position_in_parent_category,
+            Some(ChildPositionInParentCategory {
+                parent_id: new_parent_id,
+                child_nr_in_parent_category: 2,
+            })
+        );
+    });
+}
+
+#[test]
+fn subcategory_counts_follow_moved_categories() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let old_parent_id = create_root_category(forum_sudo.clone());
+        let new_parent_id = create_root_category(forum_sudo.clone());
+        let category_id = create_category(forum_sudo.clone(), Some(old_parent_id));
+
+        assert_eq!(
+            TestForumModule::subcategory_counts_by_depth(old_parent_id),
+            vec![1]
+        );
+
+        assert_ok!(move_category(
+            forum_sudo.clone(),
+            category_id,
+            Some(new_parent_id)
+        ));
+
+        assert!(TestForumModule::subcategory_counts_by_depth(old_parent_id).is_empty());
+        assert_eq!(
+            TestForumModule::subcategory_counts_by_depth(new_parent_id),
+            vec![1]
+        );
+
+        // Old parent can be moved under the new parent, having no subcategories left
+        assert_ok!(move_category(
+            forum_sudo,
+            old_parent_id,
+            Some(new_parent_id)
+        ));
+        assert_eq!(
+            TestForumModule::subcategory_counts_by_depth(new_parent_id),
+            vec![2]
+        );
+    });
+}
+
+#[test]
+fn category_cannot_be_moved_into_its_subtree() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+        let subcategory_id = create_category(forum_sudo.clone(), Some(category_id));
+
+        assert_err!(
+            move_category(forum_sudo.clone(), category_id, Some(category_id)),
+            ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITS_SUBTREE
+        );
+        assert_err!(
+            move_category(forum_sudo.clone(), category_id, Some(subcategory_id)),
+            ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITS_SUBTREE
+        );
+        assert_err!(
+            move_category(forum_sudo, subcategory_id, Some(category_id)),
+            ERROR_CATEGORY_NOT_BEING_UPDATED
+        );
+    });
+}
+
+#[test]
+fn moved_category_subtree_cannot_exceed_max_depth() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let new_parent_id = create_root_category(forum_sudo.clone());
+        let category_id = create_root_category(forum_sudo.clone());
+        create_category(forum_sudo.clone(), Some(category_id));
+
+        assert_err!(
+            move_category(forum_sudo, category_id, Some(new_parent_id)),
+            ERROR_MAX_VALID_CATEGORY_DEPTH_EXCEEDED
+        );
+    });
+}
+
+#[test]
+fn not_forum_sudo_cannot_move_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let new_parent_id = create_root_category(forum_sudo.clone());
+        let category_id = create_root_category(forum_sudo.clone());
+
+        assert_err!(
+            move_category(NOT_FORUM_SUDO_ORIGIN, category_id, Some(new_parent_id)),
+            ERROR_ORIGIN_NOT_FORUM_SUDO
+        );
+        assert_err!(
+            move_category(forum_sudo, INVLAID_CATEGORY_ID, None),
+            ERROR_CATEGORY_DOES_NOT_EXIST
+        );
+    });
+}
+
+// Migration:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn subcategory_counts_are_initialized_once() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let parent_id = create_root_category(forum_sudo.clone());
+        create_category(forum_sudo.clone(), Some(parent_id));
+        create_category(forum_sudo, Some(parent_id));
+
+        // Fresh chains start with the subcategory counts.
+        assert!(TestForumModule::subcategory_counts_initialized());
+        TestForumModule::initialize_subcategory_counts();
+        assert_eq!(
+            TestForumModule::subcategory_counts_by_depth(parent_id),
+            vec![2]
+        );
+
+        // Chains upgraded from the forum without the counts have none.
+        SubcategoryCountsByDepth::remove(parent_id);
+        SubcategoryCountsInitialized::put(false);
+        TestForumModule::initialize_subcategory_counts();
+
+        assert!(TestForumModule::subcategory_counts_initialized());
+        assert_eq!(
+            TestForumModule::subcategory_counts_by_depth(parent_id),
+            vec![2]
+        );
+    });
+}
+
+#[test]
+fn authors_are_migrated_to_members_once() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo);
+        let author_id = forum_user_id(&member_origin);
+
+        // Fresh chains start with the member based authorship.
+        assert!(TestForumModule::authors_migrated_to_members());
+        TestForumModule::migrate_authors_to_members(|account_id| Some(account_id + 1000));
+        assert_eq!(
+            TestForumModule::thread_by_id(thread_id).author_id,
+            author_id
+        );
+
+        // Mock accounts and member ids share the encoding, so the existing records
+        // can stand for the account based ones.
+        AuthorsMigratedToMembers::put(false);
+        TestForumModule::migrate_authors_to_members(|account_id| Some(account_id + 1000));
+
+        assert!(TestForumModule::authors_migrated_to_members());
+        assert_eq!(
+            TestForumModule::thread_by_id(thread_id).author_id,
+            author_id + 1000
+        );
+        assert_eq!(
+            TestForumModule::post_by_id(post_id).author_id,
+            author_id + 1000
+        );
+
+        // Authors without a membership are attributed to the unknown author.
+        AuthorsMigratedToMembers::put(false);
+        TestForumModule::migrate_authors_to_members(|_| None);
+
+        assert_eq!(
+            TestForumModule::thread_by_id(thread_id).author_id,
+            TestForumModule::unknown_author_id()
+        );
+        assert_eq!(
+            TestForumModule::post_by_id(post_id).author_id,
+            TestForumModule::unknown_author_id()
+        );
+    });
+}
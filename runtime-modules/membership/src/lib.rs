@@ -6,14 +6,15 @@
 #![allow(clippy::redundant_closure_call)]
 
 pub mod genesis;
+mod migration;
 pub(crate) mod mock;
 mod tests;
 
 use codec::{Codec, Decode, Encode};
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, ExistenceRequirement};
 use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::{BaseArithmetic, One};
-use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_runtime::traits::{MaybeSerialize, Member, Zero};
 use sp_std::borrow::ToOwned;
 use sp_std::vec;
 use sp_std::vec::Vec;
@@ -85,11 +86,12 @@ pub type Membership<T> = MembershipObject<
     <T as Trait>::PaidTermId,
     <T as Trait>::SubscriptionId,
     <T as system::Trait>::AccountId,
+    <T as Trait>::MemberId,
 >;
 
 #[derive(Encode, Decode, Default)]
 /// Stored information about a registered user
-pub struct MembershipObject<BlockNumber, Moment, PaidTermId, SubscriptionId, AccountId> {
+pub struct MembershipObject<BlockNumber, Moment, PaidTermId, SubscriptionId, AccountId, MemberId> {
     /// The unique handle chosen by member
     pub handle: Vec<u8>,
 
@@ -106,7 +108,7 @@ pub struct MembershipObject<BlockNumber, Moment, PaidTermId, SubscriptionId, Acc
     pub registered_at_time: Moment,
 
     /// How the member was registered
-    pub entry: EntryMethod<PaidTermId, AccountId>,
+    pub entry: EntryMethod<PaidTermId, AccountId, MemberId>,
 
     /// Whether the member is suspended or not.
     pub suspended: bool,
@@ -134,15 +136,16 @@ struct ValidatedUserInfo {
 }
 
 #[derive(Encode, Decode, Debug, PartialEq)]
-pub enum EntryMethod<PaidTermId, AccountId> {
+pub enum EntryMethod<PaidTermId, AccountId, MemberId> {
     Paid(PaidTermId),
     Screening(AccountId),
     Genesis,
+    Invited(MemberId),
 }
 
 /// Must be default constructible because it indirectly is a value in a storage map.
 /// ***SHOULD NEVER ACTUALLY GET CALLED, IS REQUIRED TO DUE BAD STORAGE MODEL IN SUBSTRATE***
-impl<PaidTermId, AccountId> Default for EntryMethod<PaidTermId, AccountId> {
+impl<PaidTermId, AccountId, MemberId> Default for EntryMethod<PaidTermId, AccountId, MemberId> {
     fn default() -> Self {
         Self::Genesis
     }
@@ -156,6 +159,111 @@ pub struct PaidMembershipTerms<Balance> {
     pub text: Vec<u8>,
 }
 
+/// Time-limited subscription plan members can buy or renew.
+#[derive(Encode, Decode, Eq, PartialEq, Default, Clone, Debug)]
+pub struct SubscriptionPlan<Balance, BlockNumber> {
+    /// Quantity of native tokens which must be provably burned for a period
+    pub price: Balance,
+    /// Number of blocks a subscription lasts
+    pub period: BlockNumber,
+    /// Whether the plan can be bought or renewed
+    pub active: bool,
+}
+
+pub type SubscriptionPlanOf<T> = SubscriptionPlan<BalanceOf<T>, <T as system::Trait>::BlockNumber>;
+
+/// Limits of a screening authority and its usage.
+#[derive(Encode, Decode, Eq, PartialEq, Default, Clone, Debug)]
+pub struct ScreeningQuota<Balance> {
+    /// Maximum number of members the authority may screen
+    pub member_quota: u32,
+    /// Number of members the authority has screened so far
+    pub members_screened: u32,
+    /// Maximum balance the authority may grant to a screened member
+    pub max_initial_balance: Balance,
+}
+
+impl<Balance> ScreeningQuota<Balance> {
+    /// Whether the authority can screen more members
+    pub fn has_members_left(&self) -> bool {
+        self.members_screened < self.member_quota
+    }
+}
+
+pub type ScreeningQuotaOf<T> = ScreeningQuota<BalanceOf<T>>;
+
+/// Verification status of the member profile set by a verifier.
+#[derive(Encode, Decode, Eq, PartialEq, Default, Clone, Debug)]
+pub struct ProfileVerification<AccountId> {
+    /// Whether the profile is verified
+    pub verified: bool,
+    /// Verifier who last updated the status
+    pub verifier: AccountId,
+    /// Reason for the verification status
+    pub rationale: Vec<u8>,
+}
+
+/// External identity claimed by the member, e.g. a GitHub username.
+#[derive(Encode, Decode, Eq, PartialEq, Default, Clone, Debug)]
+pub struct IdentityClaim {
+    /// External service name
+    pub service: Vec<u8>,
+    /// Username on the external service
+    pub username: Vec<u8>,
+    /// Signed proof string published on the external service
+    pub proof: Vec<u8>,
+}
+
+/// Pending offer to transfer the member handle to another member.
+#[derive(Encode, Decode, Eq, PartialEq, Default, Clone, Debug)]
+pub struct HandleTransferOffer<MemberId> {
+    /// Member receiving the handle
+    pub to_member_id: MemberId,
+    /// Handle the offering member gets once the transfer is accepted
+    pub replacement_handle: Vec<u8>,
+}
+
+// Maximum number of identity claims per member
+const MAX_IDENTITY_CLAIMS: usize = 10;
+
+/// Trusted members who can jointly recover the member root account.
+#[derive(Encode, Decode, Eq, PartialEq, Default, Clone, Debug)]
+pub struct RecoveryConfig<MemberId, BlockNumber> {
+    /// Trusted members
+    pub friends: Vec<MemberId>,
+    /// Number of vouching friends required to recover the account
+    pub threshold: u32,
+    /// Number of blocks the root account can cancel the recovery. A recovery not finalized
+    /// within the same number of blocks after the delay expires.
+    pub delay_period: BlockNumber,
+}
+
+/// Recovery of the member root account in progress.
+#[derive(Encode, Decode, Eq, PartialEq, Default, Clone, Debug)]
+pub struct ActiveRecovery<AccountId, MemberId, BlockNumber> {
+    /// Root account to set once the recovery is finalized
+    pub new_root_account: AccountId,
+    /// Block number when the recovery was initiated
+    pub initiated_at: BlockNumber,
+    /// Friends who vouched for the recovery
+    pub vouchers: Vec<MemberId>,
+}
+
+pub type RecoveryConfigOf<T> =
+    RecoveryConfig<<T as Trait>::MemberId, <T as system::Trait>::BlockNumber>;
+
+pub type ActiveRecoveryOf<T> = ActiveRecovery<
+    <T as system::Trait>::AccountId,
+    <T as Trait>::MemberId,
+    <T as system::Trait>::BlockNumber,
+>;
+
+// Maximum number of trusted members in the recovery config
+const MAX_RECOVERY_FRIENDS: usize = 10;
+
+// Minimum number of blocks the root account can cancel the recovery
+const MIN_RECOVERY_DELAY_PERIOD: u32 = 14_400;
+
 decl_storage! {
     trait Store for Module<T: Trait> as Membership {
         /// MemberId to assign to next member that is added to the registry, and is also the
@@ -178,6 +286,18 @@ decl_storage! {
         pub MemberIdByHandle get(fn handles) : map hasher(blake2_128_concat)
             Vec<u8> => T::MemberId;
 
+        /// Whether the `MemberIdByHandle` is keyed by the normalized handles. Set at genesis,
+        /// the chains upgraded from the case-sensitive handles are migrated on runtime upgrade.
+        pub HandlesNormalized get(fn handles_normalized) build(|_| true): bool;
+
+        /// Normalized handles which can't be registered by members
+        pub ReservedHandles get(fn reserved_handles) : map hasher(blake2_128_concat)
+            Vec<u8> => bool;
+
+        /// Pending handle transfer offers by the offering member id
+        pub HandleTransferOfferByMemberId get(fn handle_transfer_offer_by_member_id) :
+            map hasher(blake2_128_concat) T::MemberId => Option<HandleTransferOffer<T::MemberId>>;
+
         /// Next paid membership terms id
         pub NextPaidMembershipTermsId get(fn next_paid_membership_terms_id) :
             T::PaidTermId = T::PaidTermId::from(FIRST_PAID_TERMS_ID);
@@ -204,7 +324,51 @@ decl_storage! {
         /// Is the platform is accepting new members or not
         pub NewMembershipsAllowed get(fn new_memberships_allowed) : bool = true;
 
-        pub ScreeningAuthority get(fn screening_authority) : T::AccountId;
+        /// Screening authorities with their quotas
+        pub ScreeningAuthorities get(fn screening_authorities) : map hasher(blake2_128_concat)
+            T::AccountId => Option<ScreeningQuotaOf<T>>;
+
+        /// Accounts allowed to verify member profiles
+        pub ProfileVerifiers get(fn profile_verifiers) : map hasher(blake2_128_concat)
+            T::AccountId => bool;
+
+        /// Verification status of the member profiles
+        pub ProfileVerificationByMemberId get(fn profile_verification_by_member_id) :
+            map hasher(blake2_128_concat) T::MemberId => Option<ProfileVerification<T::AccountId>>;
+
+        /// External identity claims of the members
+        pub IdentityClaimsByMemberId get(fn identity_claims_by_member_id) :
+            map hasher(blake2_128_concat) T::MemberId => Vec<IdentityClaim>;
+
+        /// Social recovery configs of the members
+        pub RecoveryConfigByMemberId get(fn recovery_config_by_member_id) :
+            map hasher(blake2_128_concat) T::MemberId => Option<RecoveryConfigOf<T>>;
+
+        /// Root account recoveries in progress
+        pub ActiveRecoveryByMemberId get(fn active_recovery_by_member_id) :
+            map hasher(blake2_128_concat) T::MemberId => Option<ActiveRecoveryOf<T>>;
+
+        /// Number of members each member can still invite
+        pub InvitesByMemberId get(fn invites_by_member_id) : map hasher(blake2_128_concat)
+            T::MemberId => u32;
+
+        /// Number of invites granted to the new members. Invited members get none, so invites
+        /// can't multiply without the grants by root.
+        pub InitialInvitationCount get(fn initial_invitation_count) : u32;
+
+        /// Part of the paid membership fee transferred to the referrer instead of being burned
+        pub ReferralCut get(fn referral_cut) : BalanceOf<T>;
+
+        /// Next subscription plan id
+        pub NextSubscriptionId get(fn next_subscription_id) : T::SubscriptionId;
+
+        /// Subscription plans record
+        pub SubscriptionPlanById get(fn subscription_plan_by_id) : map hasher(blake2_128_concat)
+            T::SubscriptionId => SubscriptionPlanOf<T>;
+
+        /// Block at which the subscription of the member expires
+        pub SubscriptionExpiresAt get(fn subscription_expires_at) : map hasher(blake2_128_concat)
+            T::MemberId => Option<T::BlockNumber>;
 
         // User Input Validation parameters - do these really need to be state variables
         // I don't see a need to adjust these in future?
@@ -244,7 +408,10 @@ decl_storage! {
 decl_event! {
     pub enum Event<T> where
       <T as system::Trait>::AccountId,
+      <T as system::Trait>::BlockNumber,
       <T as Trait>::MemberId,
+      <T as Trait>::SubscriptionId,
+      <T as Trait>::PaidTermId,
     {
         MemberRegistered(MemberId, AccountId),
         MemberUpdatedAboutText(MemberId),
@@ -252,6 +419,39 @@ decl_event! {
         MemberUpdatedHandle(MemberId),
         MemberSetRootAccount(MemberId, AccountId),
         MemberSetControllerAccount(MemberId, AccountId),
+        MemberSuspended(MemberId),
+        MemberUnsuspended(MemberId),
+        SubscriptionPlanAdded(SubscriptionId),
+        SubscriptionPlanDeactivated(SubscriptionId),
+        MemberSubscribed(MemberId, SubscriptionId, BlockNumber),
+        MemberInvited(MemberId, MemberId),
+        MemberInvitesUpdated(MemberId, u32),
+        MemberReferred(MemberId, MemberId),
+        ScreeningAuthorityAdded(AccountId),
+        ScreeningAuthorityRemoved(AccountId),
+        ScreeningAuthorityToppedUp(AccountId, u32),
+        ProfileVerifierAdded(AccountId),
+        ProfileVerifierRemoved(AccountId),
+        MemberVerificationUpdated(MemberId, bool, AccountId),
+        MemberVerificationCleared(MemberId),
+        IdentityClaimAdded(MemberId),
+        IdentityClaimRemoved(MemberId),
+        PaidMembershipTermsAdded(PaidTermId),
+        PaidMembershipTermsActivated(PaidTermId),
+        PaidMembershipTermsDeactivated(PaidTermId),
+        PaidMembershipFeeUpdated(PaidTermId),
+        NewMembershipsAllowedUpdated(bool),
+        HandleReserved(Vec<u8>),
+        HandleUnreserved(Vec<u8>),
+        HandleTransferOffered(MemberId, MemberId),
+        HandleTransferCancelled(MemberId),
+        HandleTransferred(MemberId, MemberId),
+        RecoveryConfigSet(MemberId),
+        RecoveryConfigRemoved(MemberId),
+        RecoveryInitiated(MemberId, MemberId, AccountId),
+        RecoveryVouched(MemberId, MemberId),
+        RecoveryCancelled(MemberId),
+        MemberRecovered(MemberId, AccountId),
     }
 }
 
@@ -295,6 +495,310 @@ decl_module! {
             Self::deposit_event(RawEvent::MemberRegistered(member_id, who));
         }
 
+        /// Non-members can buy membership referred by a member. The referral cut of the fee
+        /// is transferred to the referrer root account, the rest is burned.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn buy_membership_with_referrer(
+            origin,
+            paid_terms_id: T::PaidTermId,
+            referrer_id: T::MemberId,
+            handle: Option<Vec<u8>>,
+            avatar_uri: Option<Vec<u8>>,
+            about: Option<Vec<u8>>
+        ) {
+            let who = ensure_signed(origin)?;
+
+            // make sure we are accepting new memberships
+            ensure!(Self::new_memberships_allowed(), "new members not allowed");
+
+            // ensure paid_terms_id is active
+            let terms = Self::ensure_active_terms_id(paid_terms_id)?;
+
+            // ensure enough free balance to cover terms fees
+            ensure!(T::Currency::can_slash(&who, terms.fee), "not enough balance to buy membership");
+
+            let referrer = Self::ensure_membership(referrer_id)?;
+
+            ensure!(!referrer.suspended, "referrer is suspended");
+
+            ensure!(
+                referrer.root_account != who && referrer.controller_account != who,
+                "cannot refer own account"
+            );
+
+            let user_info = Self::check_user_registration_info(handle, avatar_uri, about)?;
+
+            Self::ensure_unique_handle(&user_info.handle)?;
+
+            let referral_cut = Self::referral_cut().min(terms.fee);
+
+            /* Here we are safe to mutate */
+
+            // Transfer is the first mutation, nothing is changed when it fails. Inserting
+            // the member can not fail after the handle uniqueness check.
+            if !referral_cut.is_zero() {
+                T::Currency::transfer(
+                    &who,
+                    &referrer.root_account,
+                    referral_cut,
+                    ExistenceRequirement::AllowDeath
+                )?;
+            }
+
+            let member_id = Self::insert_member(
+                &who,
+                &who,
+                &user_info,
+                EntryMethod::Paid(paid_terms_id),
+                <system::Module<T>>::block_number(),
+                <pallet_timestamp::Module<T>>::now()
+            )?;
+
+            let _ = T::Currency::slash(&who, terms.fee - referral_cut);
+
+            Self::deposit_event(RawEvent::MemberRegistered(member_id, who));
+            Self::deposit_event(RawEvent::MemberReferred(member_id, referrer_id));
+        }
+
+        /// Members can onboard new accounts without tokens using their invites
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn invite_member(
+            origin,
+            member_id: T::MemberId,
+            new_member_account: T::AccountId,
+            handle: Option<Vec<u8>>,
+            avatar_uri: Option<Vec<u8>>,
+            about: Option<Vec<u8>>
+        ) {
+            let who = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == who, "only controller account can invite");
+
+            ensure!(!membership.suspended, "inviting member is suspended");
+
+            let invites = Self::invites_by_member_id(member_id);
+
+            ensure!(invites > 0, "no invites left");
+
+            // make sure we are accepting new memberships
+            ensure!(Self::new_memberships_allowed(), "new members not allowed");
+
+            let user_info = Self::check_user_registration_info(handle, avatar_uri, about)?;
+
+            let new_member_id = Self::insert_member(
+                &new_member_account,
+                &new_member_account,
+                &user_info,
+                EntryMethod::Invited(member_id),
+                <system::Module<T>>::block_number(),
+                <pallet_timestamp::Module<T>>::now()
+            )?;
+
+            <InvitesByMemberId<T>>::insert(member_id, invites - 1);
+
+            Self::deposit_event(RawEvent::MemberRegistered(new_member_id, new_member_account));
+            Self::deposit_event(RawEvent::MemberInvited(new_member_id, member_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_member_invites(origin, member_id: T::MemberId, invites: u32) {
+            ensure_root(origin)?;
+
+            Self::ensure_membership(member_id)?;
+
+            <InvitesByMemberId<T>>::insert(member_id, invites);
+            Self::deposit_event(RawEvent::MemberInvitesUpdated(member_id, invites));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_initial_invitation_count(origin, invites: u32) {
+            ensure_root(origin)?;
+            InitialInvitationCount::put(invites);
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_referral_cut(origin, referral_cut: BalanceOf<T>) {
+            ensure_root(origin)?;
+            <ReferralCut<T>>::put(referral_cut);
+        }
+
+        /// Reserved handles can't be registered, the check is case-insensitive.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn reserve_handle(origin, handle: Vec<u8>) {
+            ensure_root(origin)?;
+
+            let normalized_handle = Self::normalize_handle(&handle);
+
+            ensure!(!Self::reserved_handles(&normalized_handle), "handle already reserved");
+
+            ReservedHandles::insert(&normalized_handle, true);
+            Self::deposit_event(RawEvent::HandleReserved(normalized_handle));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn unreserve_handle(origin, handle: Vec<u8>) {
+            ensure_root(origin)?;
+
+            let normalized_handle = Self::normalize_handle(&handle);
+
+            ensure!(Self::reserved_handles(&normalized_handle), "handle not reserved");
+
+            ReservedHandles::remove(&normalized_handle);
+            Self::deposit_event(RawEvent::HandleUnreserved(normalized_handle));
+        }
+
+        /// Offers the member handle to another member. The offering member gets the replacement
+        /// handle once the transfer is accepted.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn offer_handle_transfer(
+            origin,
+            member_id: T::MemberId,
+            to_member_id: T::MemberId,
+            replacement_handle: Vec<u8>
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, "only controller account can update member info");
+
+            Self::ensure_membership(to_member_id)?;
+
+            ensure!(member_id != to_member_id, "cannot transfer handle to the same member");
+
+            Self::validate_handle(&replacement_handle)?;
+            Self::ensure_unique_handle(&replacement_handle)?;
+
+            let offer = HandleTransferOffer {
+                to_member_id,
+                replacement_handle,
+            };
+
+            <HandleTransferOfferByMemberId<T>>::insert(member_id, offer);
+            Self::deposit_event(RawEvent::HandleTransferOffered(member_id, to_member_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn cancel_handle_transfer(origin, member_id: T::MemberId) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, "only controller account can update member info");
+
+            ensure!(
+                <HandleTransferOfferByMemberId<T>>::contains_key(member_id),
+                "handle transfer offer not found"
+            );
+
+            <HandleTransferOfferByMemberId<T>>::remove(member_id);
+            Self::deposit_event(RawEvent::HandleTransferCancelled(member_id));
+        }
+
+        /// Accepts the handle offered by another member. The previous handle of the accepting
+        /// member is released.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn accept_handle_transfer(origin, member_id: T::MemberId, from_member_id: T::MemberId) {
+            let sender = ensure_signed(origin)?;
+
+            let mut membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, "only controller account can update member info");
+
+            let mut from_membership = Self::ensure_membership(from_member_id)?;
+
+            let offer = Self::handle_transfer_offer_by_member_id(from_member_id)
+                .ok_or("handle transfer offer not found")?;
+
+            ensure!(offer.to_member_id == member_id, "handle transfer offered to another member");
+
+            // the replacement handle can be the handle released by the accepting member
+            let replacement_owner = Self::member_id_by_handle(&offer.replacement_handle);
+            if replacement_owner != Some(member_id) {
+                Self::ensure_unique_handle(&offer.replacement_handle)?;
+            }
+
+            /* Here we are safe to mutate */
+
+            <HandleTransferOfferByMemberId<T>>::remove(from_member_id);
+
+            // pending offer of the accepting member refers to the released handle
+            Self::cancel_pending_handle_transfer(member_id);
+
+            Self::release_handle(member_id, &membership.handle);
+            <MemberIdByHandle<T>>::insert(Self::normalize_handle(&from_membership.handle), member_id);
+            <MemberIdByHandle<T>>::insert(Self::normalize_handle(&offer.replacement_handle), from_member_id);
+
+            membership.handle = from_membership.handle;
+            from_membership.handle = offer.replacement_handle;
+
+            <MembershipById<T>>::insert(member_id, membership);
+            <MembershipById<T>>::insert(from_member_id, from_membership);
+
+            Self::clear_member_verification(member_id);
+            Self::clear_member_verification(from_member_id);
+
+            Self::deposit_event(RawEvent::MemberUpdatedHandle(member_id));
+            Self::deposit_event(RawEvent::MemberUpdatedHandle(from_member_id));
+            Self::deposit_event(RawEvent::HandleTransferred(from_member_id, member_id));
+        }
+
+        /// Adds new active paid membership terms.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn add_paid_membership_terms(origin, fee: BalanceOf<T>, text: Vec<u8>) {
+            ensure_root(origin)?;
+
+            let terms_id = Self::next_paid_membership_terms_id();
+
+            <PaidMembershipTermsById<T>>::insert(terms_id, PaidMembershipTerms { fee, text });
+            <ActivePaidMembershipTerms<T>>::mutate(|ids| ids.push(terms_id));
+            <NextPaidMembershipTermsId<T>>::put(terms_id + One::one());
+
+            Self::deposit_event(RawEvent::PaidMembershipTermsAdded(terms_id));
+        }
+
+        /// Activates or deactivates existing paid membership terms.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_paid_membership_terms_active(origin, terms_id: T::PaidTermId, active: bool) {
+            ensure_root(origin)?;
+
+            Self::ensure_paid_membership_terms_exist(terms_id)?;
+
+            let is_active = Self::active_paid_membership_terms().contains(&terms_id);
+
+            if active && !is_active {
+                <ActivePaidMembershipTerms<T>>::mutate(|ids| ids.push(terms_id));
+                Self::deposit_event(RawEvent::PaidMembershipTermsActivated(terms_id));
+            } else if !active && is_active {
+                <ActivePaidMembershipTerms<T>>::mutate(|ids| ids.retain(|id| *id != terms_id));
+                Self::deposit_event(RawEvent::PaidMembershipTermsDeactivated(terms_id));
+            }
+        }
+
+        /// Updates the fee of existing paid membership terms.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_paid_membership_fee(origin, terms_id: T::PaidTermId, fee: BalanceOf<T>) {
+            ensure_root(origin)?;
+
+            let mut terms = Self::ensure_paid_membership_terms_exist(terms_id)?;
+
+            terms.fee = fee;
+
+            <PaidMembershipTermsById<T>>::insert(terms_id, terms);
+            Self::deposit_event(RawEvent::PaidMembershipFeeUpdated(terms_id));
+        }
+
+        /// Allows or disallows registering new members.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_new_memberships_allowed(origin, allowed: bool) {
+            ensure_root(origin)?;
+
+            NewMembershipsAllowed::put(allowed);
+            Self::deposit_event(RawEvent::NewMembershipsAllowedUpdated(allowed));
+        }
+
         /// Change member's about text
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn change_member_about_text(origin, member_id: T::MemberId, text: Vec<u8>) {
@@ -386,42 +890,292 @@ decl_module! {
         pub fn set_root_account(origin, member_id: T::MemberId, new_root_account: T::AccountId) {
             let sender = ensure_signed(origin)?;
 
-            let mut membership = Self::ensure_membership(member_id)?;
+            let membership = Self::ensure_membership(member_id)?;
 
             ensure!(membership.root_account == sender, "only root account can set new root account");
 
-            // only update if new root account is different than current one
-            if membership.root_account != new_root_account {
-                <MemberIdsByRootAccountId<T>>::mutate(&membership.root_account, |ids| {
-                    ids.retain(|id| *id != member_id);
-                });
+            Self::_set_root_account(member_id, membership, new_root_account);
+        }
 
-                <MemberIdsByRootAccountId<T>>::mutate(&new_root_account, |ids| {
-                    ids.push(member_id);
-                });
+        /// Registers trusted members who can jointly recover the root account after the delay.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_recovery_config(
+            origin,
+            member_id: T::MemberId,
+            friends: Vec<T::MemberId>,
+            threshold: u32,
+            delay_period: T::BlockNumber
+        ) {
+            let sender = ensure_signed(origin)?;
 
-                membership.root_account = new_root_account.clone();
-                <MembershipById<T>>::insert(member_id, membership);
-                Self::deposit_event(RawEvent::MemberSetRootAccount(member_id, new_root_account));
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.root_account == sender, "only root account can set recovery config");
+
+            Self::ensure_no_recovery_in_progress(member_id)?;
+
+            ensure!(
+                delay_period >= T::BlockNumber::from(MIN_RECOVERY_DELAY_PERIOD),
+                "recovery delay period too short"
+            );
+
+            ensure!(!friends.is_empty(), "recovery friends list is empty");
+            ensure!(friends.len() <= MAX_RECOVERY_FRIENDS, "too many recovery friends");
+            ensure!(
+                threshold > 0 && threshold as usize <= friends.len(),
+                "invalid recovery threshold"
+            );
+
+            for (index, friend_id) in friends.iter().enumerate() {
+                ensure!(*friend_id != member_id, "member cannot be its own recovery friend");
+                ensure!(!friends[..index].contains(friend_id), "duplicate recovery friend");
+                Self::ensure_membership(*friend_id)?;
             }
+
+            let config = RecoveryConfig {
+                friends,
+                threshold,
+                delay_period,
+            };
+
+            // expired recovery refers to the previous config
+            <ActiveRecoveryByMemberId<T>>::remove(member_id);
+
+            <RecoveryConfigByMemberId<T>>::insert(member_id, config);
+            Self::deposit_event(RawEvent::RecoveryConfigSet(member_id));
         }
 
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn remove_recovery_config(origin, member_id: T::MemberId) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.root_account == sender, "only root account can set recovery config");
+
+            ensure!(
+                <RecoveryConfigByMemberId<T>>::contains_key(member_id),
+                "recovery config not found"
+            );
+
+            <RecoveryConfigByMemberId<T>>::remove(member_id);
+            <ActiveRecoveryByMemberId<T>>::remove(member_id);
+            Self::deposit_event(RawEvent::RecoveryConfigRemoved(member_id));
+        }
+
+        /// Trusted member starts the recovery of the root account of the lost member. The expired
+        /// recovery is replaced, so a recovery not supported by other friends can't block the
+        /// member recovery.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn initiate_recovery(
+            origin,
+            friend_member_id: T::MemberId,
+            member_id: T::MemberId,
+            new_root_account: T::AccountId
+        ) {
+            Self::ensure_recovery_friend_signed(origin, friend_member_id, member_id)?;
+
+            Self::ensure_no_recovery_in_progress(member_id)?;
+
+            let recovery = ActiveRecovery {
+                new_root_account: new_root_account.clone(),
+                initiated_at: <system::Module<T>>::block_number(),
+                vouchers: vec![friend_member_id],
+            };
+
+            <ActiveRecoveryByMemberId<T>>::insert(member_id, recovery);
+            Self::deposit_event(RawEvent::RecoveryInitiated(member_id, friend_member_id, new_root_account));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn vouch_recovery(origin, friend_member_id: T::MemberId, member_id: T::MemberId) {
+            let config = Self::ensure_recovery_friend_signed(origin, friend_member_id, member_id)?;
+
+            let mut recovery = Self::ensure_active_recovery(member_id)?;
+
+            ensure!(!Self::recovery_expired(&recovery, &config), "recovery expired");
+
+            ensure!(!recovery.vouchers.contains(&friend_member_id), "already vouched for recovery");
+
+            recovery.vouchers.push(friend_member_id);
+
+            <ActiveRecoveryByMemberId<T>>::insert(member_id, recovery);
+            Self::deposit_event(RawEvent::RecoveryVouched(member_id, friend_member_id));
+        }
+
+        /// The current root account can cancel the recovery in progress.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn cancel_recovery(origin, member_id: T::MemberId) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.root_account == sender, "only root account can cancel recovery");
+
+            Self::ensure_active_recovery(member_id)?;
+
+            <ActiveRecoveryByMemberId<T>>::remove(member_id);
+            Self::deposit_event(RawEvent::RecoveryCancelled(member_id));
+        }
+
+        /// Sets the new root account once enough friends vouched and the delay period passed.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn finalize_recovery(origin, member_id: T::MemberId) {
+            ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            let config = Self::recovery_config_by_member_id(member_id)
+                .ok_or("recovery config not found")?;
+
+            let recovery = Self::ensure_active_recovery(member_id)?;
+
+            ensure!(
+                recovery.vouchers.len() >= config.threshold as usize,
+                "not enough recovery vouchers"
+            );
+
+            ensure!(
+                <system::Module<T>>::block_number() >= recovery.initiated_at + config.delay_period,
+                "recovery delay period not passed"
+            );
+
+            ensure!(!Self::recovery_expired(&recovery, &config), "recovery expired");
+
+            /* Here we are safe to mutate */
+
+            <ActiveRecoveryByMemberId<T>>::remove(member_id);
+
+            Self::_set_root_account(member_id, membership, recovery.new_root_account.clone());
+            Self::deposit_event(RawEvent::MemberRecovered(member_id, recovery.new_root_account));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn add_profile_verifier(origin, verifier: T::AccountId) {
+            ensure_root(origin)?;
+
+            ensure!(!Self::profile_verifiers(&verifier), "profile verifier already exists");
+
+            <ProfileVerifiers<T>>::insert(&verifier, true);
+            Self::deposit_event(RawEvent::ProfileVerifierAdded(verifier));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn remove_profile_verifier(origin, verifier: T::AccountId) {
+            ensure_root(origin)?;
+
+            ensure!(Self::profile_verifiers(&verifier), "profile verifier not found");
+
+            <ProfileVerifiers<T>>::remove(&verifier);
+            Self::deposit_event(RawEvent::ProfileVerifierRemoved(verifier));
+        }
+
+        /// Profile verifiers can set or unset the verified status of the member profile.
+        /// The status is cleared when the member changes their handle.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_member_verification(
+            origin,
+            member_id: T::MemberId,
+            verified: bool,
+            rationale: Vec<u8>
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::profile_verifiers(&sender), "not profile verifier");
+
+            Self::ensure_membership(member_id)?;
+
+            let verification = ProfileVerification {
+                verified,
+                verifier: sender.clone(),
+                rationale: Self::validate_text(&rationale),
+            };
+
+            <ProfileVerificationByMemberId<T>>::insert(member_id, verification);
+            Self::deposit_event(RawEvent::MemberVerificationUpdated(member_id, verified, sender));
+        }
+
+        /// Adds the external identity claim or replaces the existing claim for the same service.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn add_identity_claim(
+            origin,
+            member_id: T::MemberId,
+            service: Vec<u8>,
+            username: Vec<u8>,
+            proof: Vec<u8>
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, "only controller account can update member info");
+
+            ensure!(!service.is_empty(), "identity claim service is empty");
+            ensure!(
+                service.len() <= Self::max_handle_length() as usize &&
+                    username.len() <= Self::max_handle_length() as usize,
+                "identity claim too long"
+            );
+            ensure!(proof.len() <= Self::max_about_text_length() as usize, "identity claim proof too long");
+
+            let mut claims = Self::identity_claims_by_member_id(member_id);
+
+            claims.retain(|claim| claim.service != service);
+
+            ensure!(claims.len() < MAX_IDENTITY_CLAIMS, "too many identity claims");
+
+            claims.push(IdentityClaim {
+                service,
+                username,
+                proof,
+            });
+
+            <IdentityClaimsByMemberId<T>>::insert(member_id, claims);
+            Self::deposit_event(RawEvent::IdentityClaimAdded(member_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn remove_identity_claim(origin, member_id: T::MemberId, service: Vec<u8>) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, "only controller account can update member info");
+
+            let mut claims = Self::identity_claims_by_member_id(member_id);
+
+            let claims_count = claims.len();
+            claims.retain(|claim| claim.service != service);
+
+            ensure!(claims.len() < claims_count, "identity claim not found");
+
+            <IdentityClaimsByMemberId<T>>::insert(member_id, claims);
+            Self::deposit_event(RawEvent::IdentityClaimRemoved(member_id));
+        }
+
+        /// Screening authorities can add members within their quota and grant them an
+        /// initial balance up to their limit.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn add_screened_member(
             origin,
             new_member_account: T::AccountId,
             handle: Option<Vec<u8>>,
             avatar_uri: Option<Vec<u8>>,
-            about: Option<Vec<u8>>
+            about: Option<Vec<u8>>,
+            initial_balance: Option<BalanceOf<T>>
         ) {
             // ensure sender is screening authority
             let sender = ensure_signed(origin)?;
 
-            if <ScreeningAuthority<T>>::exists() {
-                ensure!(sender == Self::screening_authority(), "not screener");
-            } else {
-                // no screening authority defined. Cannot accept this request
-                return Err("no screening authority defined".into());
+            let mut quota = Self::screening_authorities(&sender).ok_or("not screener")?;
+
+            ensure!(quota.has_members_left(), "screening quota exhausted");
+
+            if let Some(initial_balance) = initial_balance {
+                ensure!(
+                    initial_balance <= quota.max_initial_balance,
+                    "initial balance exceeds screening limit"
+                );
             }
 
             // make sure we are accepting new memberships
@@ -433,18 +1187,159 @@ decl_module! {
                 &new_member_account,
                 &new_member_account,
                 &user_info,
-                EntryMethod::Screening(sender),
+                EntryMethod::Screening(sender.clone()),
                 <system::Module<T>>::block_number(),
                 <pallet_timestamp::Module<T>>::now()
             )?;
 
+            if let Some(initial_balance) = initial_balance {
+                let _ = T::Currency::deposit_creating(&new_member_account, initial_balance);
+            }
+
+            quota.members_screened += 1;
+            <ScreeningAuthorities<T>>::insert(&sender, quota);
+
             Self::deposit_event(RawEvent::MemberRegistered(member_id, new_member_account));
         }
 
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn set_screening_authority(origin, authority: T::AccountId) {
+        pub fn add_screening_authority(
+            origin,
+            authority: T::AccountId,
+            member_quota: u32,
+            max_initial_balance: BalanceOf<T>
+        ) {
+            ensure_root(origin)?;
+
+            ensure!(
+                !<ScreeningAuthorities<T>>::contains_key(&authority),
+                "screening authority already exists"
+            );
+
+            let quota = ScreeningQuota {
+                member_quota,
+                members_screened: 0,
+                max_initial_balance,
+            };
+
+            <ScreeningAuthorities<T>>::insert(&authority, quota);
+            Self::deposit_event(RawEvent::ScreeningAuthorityAdded(authority));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn remove_screening_authority(origin, authority: T::AccountId) {
+            ensure_root(origin)?;
+
+            Self::ensure_screening_authority(&authority)?;
+
+            <ScreeningAuthorities<T>>::remove(&authority);
+            Self::deposit_event(RawEvent::ScreeningAuthorityRemoved(authority));
+        }
+
+        /// Increases the number of members the screening authority may screen.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn top_up_screening_authority(origin, authority: T::AccountId, additional_members: u32) {
+            ensure_root(origin)?;
+
+            let mut quota = Self::ensure_screening_authority(&authority)?;
+
+            quota.member_quota = quota.member_quota.saturating_add(additional_members);
+
+            <ScreeningAuthorities<T>>::insert(&authority, quota);
+            Self::deposit_event(RawEvent::ScreeningAuthorityToppedUp(authority, additional_members));
+        }
+
+        /// Suspended members can't act as members in the other modules.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn suspend_member(origin, member_id: T::MemberId) {
+            ensure_root(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(!membership.suspended, "member already suspended");
+
+            <MembershipById<T>>::mutate(member_id, |membership| membership.suspended = true);
+            Self::deposit_event(RawEvent::MemberSuspended(member_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn unsuspend_member(origin, member_id: T::MemberId) {
+            ensure_root(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.suspended, "member not suspended");
+
+            <MembershipById<T>>::mutate(member_id, |membership| membership.suspended = false);
+            Self::deposit_event(RawEvent::MemberUnsuspended(member_id));
+        }
+
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn add_subscription_plan(origin, price: BalanceOf<T>, period: T::BlockNumber) {
             ensure_root(origin)?;
-            <ScreeningAuthority<T>>::put(authority);
+
+            ensure!(!period.is_zero(), "subscription period cannot be zero");
+
+            let subscription_id = Self::next_subscription_id();
+
+            <SubscriptionPlanById<T>>::insert(subscription_id, SubscriptionPlan {
+                price,
+                period,
+                active: true,
+            });
+            <NextSubscriptionId<T>>::put(subscription_id + One::one());
+
+            Self::deposit_event(RawEvent::SubscriptionPlanAdded(subscription_id));
+        }
+
+        /// Deactivated plans can't be bought or renewed, running subscriptions last until expiry.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn deactivate_subscription_plan(origin, subscription_id: T::SubscriptionId) {
+            ensure_root(origin)?;
+
+            Self::ensure_active_subscription_plan(subscription_id)?;
+
+            <SubscriptionPlanById<T>>::mutate(subscription_id, |plan| plan.active = false);
+
+            Self::deposit_event(RawEvent::SubscriptionPlanDeactivated(subscription_id));
+        }
+
+        /// Buy the subscription plan for the member, or renew it. Renewing the current plan
+        /// before it expires extends the subscription from its expiry block. Another plan can
+        /// only be bought once the current subscription expires, so no prepaid period is lost.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn buy_subscription(origin, member_id: T::MemberId, subscription_id: T::SubscriptionId) {
+            let who = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == who, "only controller account can buy subscription");
+
+            let plan = Self::ensure_active_subscription_plan(subscription_id)?;
+
+            let period_start = if Self::has_active_subscription(&member_id) {
+                ensure!(
+                    membership.subscription == Some(subscription_id),
+                    "member has an active subscription to another plan"
+                );
+
+                // renewal extends the running subscription
+                Self::subscription_expires_at(member_id).unwrap_or_default()
+            } else {
+                <system::Module<T>>::block_number()
+            };
+
+            // ensure enough free balance to cover subscription price
+            ensure!(T::Currency::can_slash(&who, plan.price), "not enough balance to buy subscription");
+
+            let expires_at = period_start + plan.period;
+
+            let _ = T::Currency::slash(&who, plan.price);
+
+            <MembershipById<T>>::mutate(member_id, |membership| membership.subscription = Some(subscription_id));
+            <SubscriptionExpiresAt<T>>::insert(member_id, expires_at);
+
+            Self::deposit_event(RawEvent::MemberSubscribed(member_id, subscription_id, expires_at));
         }
     }
 }
@@ -498,12 +1393,73 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    fn ensure_screening_authority(
+        authority: &T::AccountId,
+    ) -> Result<ScreeningQuotaOf<T>, &'static str> {
+        Self::screening_authorities(authority).ok_or("screening authority not found")
+    }
+
+    /// Returns true if the member profile is verified
+    pub fn is_member_verified(member_id: &T::MemberId) -> bool {
+        Self::profile_verification_by_member_id(member_id)
+            .map(|verification| verification.verified)
+            .unwrap_or(false)
+    }
+
+    /// Returns true if the member exists and is suspended
+    pub fn is_member_suspended(member_id: &T::MemberId) -> bool {
+        <MembershipById<T>>::contains_key(member_id) && Self::membership(member_id).suspended
+    }
+
+    /// Returns true if the member has a subscription which has not expired yet
+    pub fn has_active_subscription(member_id: &T::MemberId) -> bool {
+        Self::subscription_expires_at(member_id).map_or(false, |expires_at| {
+            expires_at > <system::Module<T>>::block_number()
+        })
+    }
+
+    fn ensure_active_subscription_plan(
+        subscription_id: T::SubscriptionId,
+    ) -> Result<SubscriptionPlanOf<T>, &'static str> {
+        ensure!(
+            <SubscriptionPlanById<T>>::contains_key(subscription_id),
+            "subscription plan not found"
+        );
+
+        let plan = Self::subscription_plan_by_id(subscription_id);
+
+        ensure!(plan.active, "subscription plan not active");
+
+        Ok(plan)
+    }
+
     /// Returns true if account is either a member's root or controller account
     pub fn is_member_account(who: &T::AccountId) -> bool {
         <MemberIdsByRootAccountId<T>>::contains_key(who)
             || <MemberIdsByControllerAccountId<T>>::contains_key(who)
     }
 
+    /// Returns the first member id controlled by the account, falling back to the first
+    /// member id rooted at the account.
+    pub fn member_id_by_account(who: &T::AccountId) -> Option<T::MemberId> {
+        let controlled_member_id = <MemberIdsByControllerAccountId<T>>::get(who)
+            .first()
+            .copied();
+
+        controlled_member_id.or_else(|| <MemberIdsByRootAccountId<T>>::get(who).first().copied())
+    }
+
+    fn ensure_paid_membership_terms_exist(
+        terms_id: T::PaidTermId,
+    ) -> Result<PaidMembershipTerms<BalanceOf<T>>, &'static str> {
+        ensure!(
+            <PaidMembershipTermsById<T>>::contains_key(terms_id),
+            "paid membership term id does not exist"
+        );
+
+        Ok(Self::paid_membership_terms_by_id(terms_id))
+    }
+
     fn ensure_active_terms_id(
         terms_id: T::PaidTermId,
     ) -> Result<PaidMembershipTerms<BalanceOf<T>>, &'static str> {
@@ -520,12 +1476,33 @@ impl<T: Trait> Module<T> {
         }
     }
 
-    #[allow(clippy::ptr_arg)] // cannot change to the "&[u8]" suggested by clippy
-    fn ensure_unique_handle(handle: &Vec<u8>) -> DispatchResult {
+    /// Handles are unique regardless of the letter case
+    pub fn normalize_handle(handle: &[u8]) -> Vec<u8> {
+        handle.to_ascii_lowercase()
+    }
+
+    /// Returns the member id owning the handle, the lookup is case-insensitive
+    pub fn member_id_by_handle(handle: &[u8]) -> Option<T::MemberId> {
+        let normalized_handle = Self::normalize_handle(handle);
+
+        if <MemberIdByHandle<T>>::contains_key(&normalized_handle) {
+            Some(Self::handles(normalized_handle))
+        } else {
+            None
+        }
+    }
+
+    fn ensure_unique_handle(handle: &[u8]) -> DispatchResult {
+        let normalized_handle = Self::normalize_handle(handle);
+
         ensure!(
-            !<MemberIdByHandle<T>>::contains_key(handle),
+            !<MemberIdByHandle<T>>::contains_key(&normalized_handle),
             "handle already registered"
         );
+        ensure!(
+            !Self::reserved_handles(&normalized_handle),
+            "handle reserved"
+        );
         Ok(())
     }
 
@@ -580,7 +1557,7 @@ impl<T: Trait> Module<T> {
         root_account: &T::AccountId,
         controller_account: &T::AccountId,
         user_info: &ValidatedUserInfo,
-        entry_method: EntryMethod<T::PaidTermId, T::AccountId>,
+        entry_method: EntryMethod<T::PaidTermId, T::AccountId, T::MemberId>,
         registered_at_block: T::BlockNumber,
         registered_at_time: T::Moment,
     ) -> Result<T::MemberId, &'static str> {
@@ -588,6 +1565,8 @@ impl<T: Trait> Module<T> {
 
         let new_member_id = Self::members_created();
 
+        let invited = matches!(entry_method, EntryMethod::Invited(_));
+
         let membership: Membership<T> = MembershipObject {
             handle: user_info.handle.clone(),
             avatar_uri: user_info.avatar_uri.clone(),
@@ -609,7 +1588,12 @@ impl<T: Trait> Module<T> {
         });
 
         <MembershipById<T>>::insert(new_member_id, membership);
-        <MemberIdByHandle<T>>::insert(user_info.handle.clone(), new_member_id);
+        <MemberIdByHandle<T>>::insert(Self::normalize_handle(&user_info.handle), new_member_id);
+
+        let initial_invitation_count = Self::initial_invitation_count();
+        if initial_invitation_count > 0 && !invited {
+            <InvitesByMemberId<T>>::insert(new_member_id, initial_invitation_count);
+        }
 
         <NextMemberId<T>>::put(new_member_id + One::one());
         Ok(new_member_id)
@@ -636,15 +1620,122 @@ impl<T: Trait> Module<T> {
     fn _change_member_handle(id: T::MemberId, handle: Vec<u8>) -> DispatchResult {
         let mut membership = Self::ensure_membership(id)?;
         Self::validate_handle(&handle)?;
-        Self::ensure_unique_handle(&handle)?;
-        <MemberIdByHandle<T>>::remove(&membership.handle);
-        <MemberIdByHandle<T>>::insert(handle.clone(), id);
+
+        // members can change the letter case of their own handle
+        if Self::member_id_by_handle(&handle) != Some(id) {
+            Self::ensure_unique_handle(&handle)?;
+        }
+
+        Self::release_handle(id, &membership.handle);
+        <MemberIdByHandle<T>>::insert(Self::normalize_handle(&handle), id);
         membership.handle = handle;
         Self::deposit_event(RawEvent::MemberUpdatedHandle(id));
         <MembershipById<T>>::insert(id, membership);
+
+        // pending offer refers to the previous handle
+        Self::cancel_pending_handle_transfer(id);
+
+        Self::clear_member_verification(id);
+        Ok(())
+    }
+
+    // Removes the handle from the index only if it is registered by the member.
+    fn release_handle(member_id: T::MemberId, handle: &[u8]) {
+        if Self::member_id_by_handle(handle) == Some(member_id) {
+            <MemberIdByHandle<T>>::remove(Self::normalize_handle(handle));
+        }
+    }
+
+    fn cancel_pending_handle_transfer(member_id: T::MemberId) {
+        if <HandleTransferOfferByMemberId<T>>::contains_key(member_id) {
+            <HandleTransferOfferByMemberId<T>>::remove(member_id);
+            Self::deposit_event(RawEvent::HandleTransferCancelled(member_id));
+        }
+    }
+
+    fn _set_root_account(
+        member_id: T::MemberId,
+        mut membership: Membership<T>,
+        new_root_account: T::AccountId,
+    ) {
+        // only update if new root account is different than current one
+        if membership.root_account != new_root_account {
+            <MemberIdsByRootAccountId<T>>::mutate(&membership.root_account, |ids| {
+                ids.retain(|id| *id != member_id);
+            });
+
+            <MemberIdsByRootAccountId<T>>::mutate(&new_root_account, |ids| {
+                ids.push(member_id);
+            });
+
+            membership.root_account = new_root_account.clone();
+            <MembershipById<T>>::insert(member_id, membership);
+            Self::deposit_event(RawEvent::MemberSetRootAccount(member_id, new_root_account));
+        }
+    }
+
+    // ensures the origin is signed by the controller account of the trusted member
+    fn ensure_recovery_friend_signed(
+        origin: T::Origin,
+        friend_member_id: T::MemberId,
+        member_id: T::MemberId,
+    ) -> Result<RecoveryConfigOf<T>, &'static str> {
+        let sender = ensure_signed(origin)?;
+
+        let friend_membership = Self::ensure_membership(friend_member_id)?;
+
+        ensure!(
+            friend_membership.controller_account == sender,
+            "only controller account can vouch for recovery"
+        );
+
+        ensure!(!friend_membership.suspended, "recovery friend is suspended");
+
+        Self::ensure_membership(member_id)?;
+
+        let config =
+            Self::recovery_config_by_member_id(member_id).ok_or("recovery config not found")?;
+
+        ensure!(
+            config.friends.contains(&friend_member_id),
+            "not a recovery friend"
+        );
+
+        Ok(config)
+    }
+
+    fn ensure_active_recovery(member_id: T::MemberId) -> Result<ActiveRecoveryOf<T>, &'static str> {
+        Self::active_recovery_by_member_id(member_id).ok_or("recovery not initiated")
+    }
+
+    // expired recovery doesn't prevent starting a new one
+    fn ensure_no_recovery_in_progress(member_id: T::MemberId) -> DispatchResult {
+        if let Some(recovery) = Self::active_recovery_by_member_id(member_id) {
+            if let Some(config) = Self::recovery_config_by_member_id(member_id) {
+                ensure!(
+                    Self::recovery_expired(&recovery, &config),
+                    "recovery in progress"
+                );
+            }
+        }
+
         Ok(())
     }
 
+    // recovery not finalized within the delay period after the delay expires
+    fn recovery_expired(recovery: &ActiveRecoveryOf<T>, config: &RecoveryConfigOf<T>) -> bool {
+        <system::Module<T>>::block_number()
+            >= recovery.initiated_at + config.delay_period + config.delay_period
+    }
+
+    // verification refers to the previous handle
+    fn clear_member_verification(id: T::MemberId) {
+        if <ProfileVerificationByMemberId<T>>::contains_key(id) {
+            <ProfileVerificationByMemberId<T>>::remove(id);
+            Self::deposit_event(RawEvent::MemberVerificationCleared(id));
+        }
+    }
+
     pub fn ensure_member_controller_account_signed(
         origin: T::Origin,
         member_id: &T::MemberId,
@@ -697,3 +1788,17 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 }
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API to query the subscriptions of the members.
+    pub trait MembershipSubscriptionApi<MemberId, BlockNumber> where
+        MemberId: codec::Codec,
+        BlockNumber: codec::Codec,
+    {
+        /// Returns true if the member has a subscription which has not expired yet.
+        fn has_active_subscription(member_id: MemberId) -> bool;
+
+        /// Returns the block at which the subscription of the member expires, if any.
+        fn subscription_expires_at(member_id: MemberId) -> Option<BlockNumber>;
+    }
+}
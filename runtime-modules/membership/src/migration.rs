@@ -0,0 +1,109 @@
+use crate::*;
+use frame_support::storage::migration::take_storage_value;
+use frame_support::storage::IterableStorageMap;
+use frame_support::{StorageMap, StorageValue};
+use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_map::BTreeMap;
+
+impl<T: Trait> Module<T> {
+    /// Re-keys the existing handles with their normalized form to enforce the case-insensitive
+    /// uniqueness. Handles differing only in the letter case are resolved to the oldest member,
+    /// the newer members get their handle suffixed with the member id.
+    /// Does nothing if the handles were already normalized. Should be called on the runtime upgrade.
+    pub fn migrate_handles_to_normalized() {
+        if Self::handles_normalized() {
+            return;
+        }
+
+        let handles: Vec<(Vec<u8>, T::MemberId)> = <MemberIdByHandle<T>>::drain().collect();
+
+        let mut oldest_members: BTreeMap<Vec<u8>, T::MemberId> = BTreeMap::new();
+
+        for (handle, member_id) in handles.iter() {
+            let oldest_member = oldest_members
+                .entry(Self::normalize_handle(handle))
+                .or_insert(*member_id);
+
+            if *member_id < *oldest_member {
+                *oldest_member = *member_id;
+            }
+        }
+
+        for (normalized_handle, member_id) in oldest_members.iter() {
+            <MemberIdByHandle<T>>::insert(normalized_handle, member_id);
+        }
+
+        for (handle, member_id) in handles {
+            if oldest_members.get(&Self::normalize_handle(&handle)) != Some(&member_id) {
+                Self::rename_member_handle(member_id, handle);
+            }
+        }
+
+        HandlesNormalized::put(true);
+    }
+
+    // Registers the handle suffixed with the member id, extended until it is unique.
+    // The handle is truncated to keep the result within the maximum handle length.
+    fn rename_member_handle(member_id: T::MemberId, handle: Vec<u8>) {
+        let mut id: u64 = member_id.saturated_into();
+        let mut suffix = Vec::new();
+        loop {
+            suffix.push(b'0' + (id % 10) as u8);
+            id /= 10;
+            if id == 0 {
+                break;
+            }
+        }
+        suffix.push(b'-');
+        suffix.reverse();
+
+        let max_handle_length = Self::max_handle_length() as usize;
+
+        let mut num_underscores = 0;
+        let new_handle = loop {
+            let base_length = max_handle_length
+                .saturating_sub(suffix.len() + num_underscores)
+                .min(handle.len());
+
+            let mut new_handle = handle[..base_length].to_vec();
+            new_handle.extend_from_slice(&suffix);
+            new_handle.resize(new_handle.len() + num_underscores, b'_');
+
+            if !<MemberIdByHandle<T>>::contains_key(Self::normalize_handle(&new_handle)) {
+                break new_handle;
+            }
+
+            num_underscores += 1;
+        };
+
+        <MemberIdByHandle<T>>::insert(Self::normalize_handle(&new_handle), member_id);
+
+        if <MembershipById<T>>::contains_key(member_id) {
+            <MembershipById<T>>::mutate(member_id, |membership| membership.handle = new_handle);
+            Self::deposit_event(RawEvent::MemberUpdatedHandle(member_id));
+        }
+    }
+
+    /// Moves the single screening authority of the previous runtime into the screening
+    /// authorities with the given member quota and no initial balance allowance.
+    /// Does nothing if there is no legacy screening authority. Should be called on the runtime upgrade.
+    pub fn migrate_screening_authority(member_quota: u32) {
+        let authority =
+            match take_storage_value::<T::AccountId>(b"Membership", b"ScreeningAuthority", &[]) {
+                Some(authority) => authority,
+                None => return,
+            };
+
+        if <ScreeningAuthorities<T>>::contains_key(&authority) {
+            return;
+        }
+
+        let quota = ScreeningQuota {
+            member_quota,
+            members_screened: 0,
+            max_initial_balance: Zero::zero(),
+        };
+
+        <ScreeningAuthorities<T>>::insert(authority, quota);
+    }
+}
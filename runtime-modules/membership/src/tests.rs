@@ -250,7 +250,12 @@ fn add_screened_member() {
         .build()
         .execute_with(|| {
             let screening_authority = 5;
-            <crate::ScreeningAuthority<Test>>::put(&screening_authority);
+            assert_ok!(Members::add_screening_authority(
+                system::RawOrigin::Root.into(),
+                screening_authority,
+                1,
+                0
+            ));
 
             let next_member_id = Members::members_created();
 
@@ -260,7 +265,8 @@ fn add_screened_member() {
                 ALICE_ACCOUNT_ID,
                 info.handle,
                 info.avatar_uri,
-                info.about
+                info.about,
+                None
             ));
 
             let profile = get_membership_by_id(next_member_id);
@@ -275,6 +281,106 @@ fn add_screened_member() {
         });
 }
 
+#[test]
+fn screening_authority_quota_and_initial_balance_limits() {
+    const MAX_INITIAL_BALANCE: u64 = 100;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(genesis::GenesisConfigBuilder::default().build())
+        .build()
+        .execute_with(|| {
+            let screening_authority = 5;
+            let alice_info = get_alice_info();
+            let bob_info = get_bob_info();
+
+            assert_dispatch_error_message(
+                Members::add_screened_member(
+                    Origin::signed(screening_authority),
+                    ALICE_ACCOUNT_ID,
+                    alice_info.handle.clone(),
+                    alice_info.avatar_uri.clone(),
+                    alice_info.about.clone(),
+                    None,
+                )
+                .map_err(|err| err.into()),
+                "not screener",
+            );
+
+            assert_ok!(Members::add_screening_authority(
+                system::RawOrigin::Root.into(),
+                screening_authority,
+                1,
+                MAX_INITIAL_BALANCE
+            ));
+
+            assert_dispatch_error_message(
+                Members::add_screened_member(
+                    Origin::signed(screening_authority),
+                    ALICE_ACCOUNT_ID,
+                    alice_info.handle.clone(),
+                    alice_info.avatar_uri.clone(),
+                    alice_info.about.clone(),
+                    Some(MAX_INITIAL_BALANCE + 1),
+                )
+                .map_err(|err| err.into()),
+                "initial balance exceeds screening limit",
+            );
+
+            assert_ok!(Members::add_screened_member(
+                Origin::signed(screening_authority),
+                ALICE_ACCOUNT_ID,
+                alice_info.handle,
+                alice_info.avatar_uri,
+                alice_info.about,
+                Some(MAX_INITIAL_BALANCE)
+            ));
+            assert_eq!(
+                Balances::free_balance(&ALICE_ACCOUNT_ID),
+                MAX_INITIAL_BALANCE
+            );
+            assert_eq!(
+                Members::screening_authorities(screening_authority)
+                    .unwrap()
+                    .members_screened,
+                1
+            );
+
+            assert_dispatch_error_message(
+                Members::add_screened_member(
+                    Origin::signed(screening_authority),
+                    2,
+                    bob_info.handle.clone(),
+                    bob_info.avatar_uri.clone(),
+                    bob_info.about.clone(),
+                    None,
+                )
+                .map_err(|err| err.into()),
+                "screening quota exhausted",
+            );
+
+            assert_ok!(Members::top_up_screening_authority(
+                system::RawOrigin::Root.into(),
+                screening_authority,
+                1
+            ));
+
+            assert_ok!(Members::add_screened_member(
+                Origin::signed(screening_authority),
+                2,
+                bob_info.handle,
+                bob_info.avatar_uri,
+                bob_info.about,
+                None
+            ));
+
+            assert_ok!(Members::remove_screening_authority(
+                system::RawOrigin::Root.into(),
+                screening_authority
+            ));
+            assert!(Members::screening_authorities(screening_authority).is_none());
+        });
+}
+
 #[test]
 fn set_controller_key() {
     let initial_members = [(0, ALICE_ACCOUNT_ID)];
@@ -337,3 +443,1113 @@ fn set_root_account() {
             assert!(<crate::MemberIdsByRootAccountId<Test>>::get(&ALICE_ACCOUNT_ID).is_empty());
         });
 }
+
+#[test]
+fn suspend_and_unsuspend_member() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+
+            assert!(Members::suspend_member(Origin::signed(ALICE_ACCOUNT_ID), member_id).is_err());
+
+            assert_ok!(Members::suspend_member(
+                system::RawOrigin::Root.into(),
+                member_id
+            ));
+            assert!(get_membership_by_id(member_id).suspended);
+            assert!(Members::is_member_suspended(&member_id));
+
+            assert_dispatch_error_message(
+                Members::suspend_member(system::RawOrigin::Root.into(), member_id)
+                    .map_err(|err| err.into()),
+                "member already suspended",
+            );
+
+            assert_ok!(Members::unsuspend_member(
+                system::RawOrigin::Root.into(),
+                member_id
+            ));
+            assert!(!Members::is_member_suspended(&member_id));
+
+            assert_dispatch_error_message(
+                Members::unsuspend_member(system::RawOrigin::Root.into(), member_id)
+                    .map_err(|err| err.into()),
+                "member not suspended",
+            );
+        });
+}
+
+#[test]
+fn buy_and_renew_subscription() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+    const PRICE: u64 = 10;
+    const PERIOD: u64 = 100;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let subscription_id = Members::next_subscription_id();
+            System::set_block_number(1);
+
+            assert!(Members::add_subscription_plan(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                PRICE,
+                PERIOD
+            )
+            .is_err());
+            assert_ok!(Members::add_subscription_plan(
+                system::RawOrigin::Root.into(),
+                PRICE,
+                PERIOD
+            ));
+
+            assert_dispatch_error_message(
+                Members::buy_subscription(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    member_id,
+                    subscription_id,
+                )
+                .map_err(|err| err.into()),
+                "not enough balance to buy subscription",
+            );
+
+            set_alice_free_balance(2 * PRICE);
+
+            assert_ok!(Members::buy_subscription(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                subscription_id
+            ));
+            assert_eq!(
+                get_membership_by_id(member_id).subscription,
+                Some(subscription_id)
+            );
+            assert_eq!(
+                Members::subscription_expires_at(member_id),
+                Some(1 + PERIOD)
+            );
+            assert!(Members::has_active_subscription(&member_id));
+            assert_eq!(Balances::free_balance(&ALICE_ACCOUNT_ID), PRICE);
+
+            // renewing before expiry extends the subscription
+            assert_ok!(Members::buy_subscription(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                subscription_id
+            ));
+            assert_eq!(
+                Members::subscription_expires_at(member_id),
+                Some(1 + 2 * PERIOD)
+            );
+
+            System::set_block_number(1 + 2 * PERIOD);
+            assert!(!Members::has_active_subscription(&member_id));
+        });
+}
+
+#[test]
+fn another_subscription_plan_cannot_be_bought_before_expiry() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+    const PERIOD: u64 = 100;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let subscription_id = Members::next_subscription_id();
+            let other_subscription_id = subscription_id + 1;
+            System::set_block_number(1);
+
+            assert_ok!(Members::add_subscription_plan(
+                system::RawOrigin::Root.into(),
+                0,
+                PERIOD
+            ));
+            assert_ok!(Members::add_subscription_plan(
+                system::RawOrigin::Root.into(),
+                0,
+                2 * PERIOD
+            ));
+            assert_ok!(Members::buy_subscription(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                subscription_id
+            ));
+
+            assert_dispatch_error_message(
+                Members::buy_subscription(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    member_id,
+                    other_subscription_id,
+                )
+                .map_err(|err| err.into()),
+                "member has an active subscription to another plan",
+            );
+
+            System::set_block_number(1 + PERIOD);
+            assert_ok!(Members::buy_subscription(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                other_subscription_id
+            ));
+            assert_eq!(
+                get_membership_by_id(member_id).subscription,
+                Some(other_subscription_id)
+            );
+            assert_eq!(
+                Members::subscription_expires_at(member_id),
+                Some(1 + 3 * PERIOD)
+            );
+        });
+}
+
+#[test]
+fn deactivated_subscription_plan_cannot_be_bought() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let subscription_id = Members::next_subscription_id();
+
+            assert_ok!(Members::add_subscription_plan(
+                system::RawOrigin::Root.into(),
+                0,
+                100
+            ));
+            assert_ok!(Members::deactivate_subscription_plan(
+                system::RawOrigin::Root.into(),
+                subscription_id
+            ));
+
+            assert_dispatch_error_message(
+                Members::buy_subscription(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    member_id,
+                    subscription_id,
+                )
+                .map_err(|err| err.into()),
+                "subscription plan not active",
+            );
+            assert!(!Members::has_active_subscription(&member_id));
+        });
+}
+
+#[test]
+fn invite_member() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+    const BOB_ACCOUNT_ID: u64 = 2;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let inviting_member_id = 0;
+            let info = get_bob_info();
+
+            assert_dispatch_error_message(
+                Members::invite_member(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    inviting_member_id,
+                    BOB_ACCOUNT_ID,
+                    info.handle.clone(),
+                    info.avatar_uri.clone(),
+                    info.about.clone(),
+                )
+                .map_err(|err| err.into()),
+                "no invites left",
+            );
+
+            assert_ok!(Members::set_member_invites(
+                system::RawOrigin::Root.into(),
+                inviting_member_id,
+                1
+            ));
+
+            let next_member_id = Members::members_created();
+
+            assert_ok!(Members::invite_member(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                inviting_member_id,
+                BOB_ACCOUNT_ID,
+                info.handle,
+                info.avatar_uri,
+                info.about,
+            ));
+
+            let profile = get_membership_by_id(next_member_id);
+
+            assert_eq!(Some(profile.handle), get_bob_info().handle);
+            assert_eq!(profile.root_account, BOB_ACCOUNT_ID);
+            assert_eq!(profile.controller_account, BOB_ACCOUNT_ID);
+            assert_eq!(
+                profile.entry,
+                crate::EntryMethod::Invited(inviting_member_id)
+            );
+            assert_eq!(Members::invites_by_member_id(inviting_member_id), 0);
+        });
+}
+
+#[test]
+fn invited_member_gets_no_initial_invites() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+    const BOB_ACCOUNT_ID: u64 = 2;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let inviting_member_id = 0;
+            let info = get_bob_info();
+
+            assert_ok!(Members::set_initial_invitation_count(
+                system::RawOrigin::Root.into(),
+                2
+            ));
+            assert_ok!(Members::set_member_invites(
+                system::RawOrigin::Root.into(),
+                inviting_member_id,
+                1
+            ));
+
+            let invited_member_id = Members::members_created();
+
+            assert_ok!(Members::invite_member(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                inviting_member_id,
+                BOB_ACCOUNT_ID,
+                info.handle,
+                info.avatar_uri,
+                info.about,
+            ));
+
+            assert_eq!(Members::invites_by_member_id(invited_member_id), 0);
+        });
+}
+
+#[test]
+fn buy_membership_with_referrer() {
+    const DEFAULT_FEE: u64 = 500;
+    const REFERRAL_CUT: u64 = 200;
+    const REFERRER_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, REFERRER_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .default_paid_membership_fee(DEFAULT_FEE)
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let referrer_id = 0;
+            let info = get_alice_info();
+
+            assert_ok!(Members::set_referral_cut(
+                system::RawOrigin::Root.into(),
+                REFERRAL_CUT
+            ));
+
+            set_alice_free_balance(DEFAULT_FEE);
+
+            let next_member_id = Members::members_created();
+
+            assert_ok!(Members::buy_membership_with_referrer(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                DEFAULT_PAID_TERM_ID as u32,
+                referrer_id,
+                info.handle,
+                info.avatar_uri,
+                info.about,
+            ));
+
+            let profile = get_membership_by_id(next_member_id);
+
+            assert_eq!(Some(profile.handle), get_alice_info().handle);
+            assert_eq!(Balances::free_balance(&ALICE_ACCOUNT_ID), 0);
+            assert_eq!(Balances::free_balance(&REFERRER_ACCOUNT_ID), REFERRAL_CUT);
+        });
+}
+
+#[test]
+fn buy_membership_with_referrer_fails_for_own_account() {
+    const DEFAULT_FEE: u64 = 500;
+    const CONTROLLER_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, ALICE_ACCOUNT_ID), (1, CONTROLLER_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .default_paid_membership_fee(DEFAULT_FEE)
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let referrer_id = 0;
+            let info = get_alice_info();
+
+            assert_ok!(Members::set_referral_cut(
+                system::RawOrigin::Root.into(),
+                DEFAULT_FEE
+            ));
+
+            set_alice_free_balance(DEFAULT_FEE);
+
+            // Referred by the member with the same root account
+            assert_dispatch_error_message(
+                Members::buy_membership_with_referrer(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    DEFAULT_PAID_TERM_ID as u32,
+                    referrer_id,
+                    info.handle.clone(),
+                    info.avatar_uri.clone(),
+                    info.about.clone(),
+                )
+                .map_err(|err| err.into()),
+                "cannot refer own account",
+            );
+
+            // Referred by the member with the same controller account
+            assert_ok!(Members::set_controller_account(
+                Origin::signed(CONTROLLER_ACCOUNT_ID),
+                1,
+                ALICE_ACCOUNT_ID
+            ));
+
+            assert_dispatch_error_message(
+                Members::buy_membership_with_referrer(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    DEFAULT_PAID_TERM_ID as u32,
+                    1,
+                    info.handle,
+                    info.avatar_uri,
+                    info.about,
+                )
+                .map_err(|err| err.into()),
+                "cannot refer own account",
+            );
+
+            assert_eq!(Balances::free_balance(&ALICE_ACCOUNT_ID), DEFAULT_FEE);
+            assert_eq!(Members::members_created(), 2);
+        });
+}
+
+#[test]
+fn member_verification_is_cleared_on_handle_change() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+    const VERIFIER_ACCOUNT_ID: u64 = 5;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let rationale = b"known contributor".to_vec();
+
+            assert_dispatch_error_message(
+                Members::set_member_verification(
+                    Origin::signed(VERIFIER_ACCOUNT_ID),
+                    member_id,
+                    true,
+                    rationale.clone(),
+                )
+                .map_err(|err| err.into()),
+                "not profile verifier",
+            );
+
+            assert_ok!(Members::add_profile_verifier(
+                system::RawOrigin::Root.into(),
+                VERIFIER_ACCOUNT_ID
+            ));
+
+            assert_ok!(Members::set_member_verification(
+                Origin::signed(VERIFIER_ACCOUNT_ID),
+                member_id,
+                true,
+                rationale.clone()
+            ));
+            assert!(Members::is_member_verified(&member_id));
+            assert_eq!(
+                Members::profile_verification_by_member_id(member_id)
+                    .unwrap()
+                    .rationale,
+                rationale
+            );
+
+            assert_ok!(Members::update_membership(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                get_bob_info().handle,
+                None,
+                None
+            ));
+            assert!(!Members::is_member_verified(&member_id));
+            assert!(Members::profile_verification_by_member_id(member_id).is_none());
+        });
+}
+
+#[test]
+fn add_and_remove_identity_claims() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let service = b"github".to_vec();
+
+            assert_ok!(Members::add_identity_claim(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                service.clone(),
+                b"alice".to_vec(),
+                b"proof".to_vec()
+            ));
+
+            // claim for the same service is replaced
+            assert_ok!(Members::add_identity_claim(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                service.clone(),
+                b"alice2".to_vec(),
+                b"proof2".to_vec()
+            ));
+
+            let claims = Members::identity_claims_by_member_id(member_id);
+            assert_eq!(claims.len(), 1);
+            assert_eq!(claims[0].username, b"alice2".to_vec());
+
+            assert_ok!(Members::remove_identity_claim(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                service.clone()
+            ));
+            assert!(Members::identity_claims_by_member_id(member_id).is_empty());
+
+            assert_dispatch_error_message(
+                Members::remove_identity_claim(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    member_id,
+                    service,
+                )
+                .map_err(|err| err.into()),
+                "identity claim not found",
+            );
+        });
+}
+
+#[test]
+fn paid_membership_terms_lifecycle() {
+    const DEFAULT_FEE: u64 = 500;
+    const NEW_FEE: u64 = 700;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .default_paid_membership_fee(DEFAULT_FEE)
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let terms_id = Members::next_paid_membership_terms_id();
+
+            assert!(Members::add_paid_membership_terms(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                NEW_FEE,
+                b"terms".to_vec()
+            )
+            .is_err());
+
+            assert_ok!(Members::add_paid_membership_terms(
+                system::RawOrigin::Root.into(),
+                DEFAULT_FEE,
+                b"terms".to_vec()
+            ));
+            assert!(Members::active_paid_membership_terms().contains(&terms_id));
+
+            assert_ok!(Members::set_paid_membership_fee(
+                system::RawOrigin::Root.into(),
+                terms_id,
+                NEW_FEE
+            ));
+            assert_eq!(Members::paid_membership_terms_by_id(terms_id).fee, NEW_FEE);
+
+            assert_ok!(Members::set_paid_membership_terms_active(
+                system::RawOrigin::Root.into(),
+                terms_id,
+                false
+            ));
+            assert!(!Members::active_paid_membership_terms().contains(&terms_id));
+
+            assert_dispatch_error_message(
+                Members::set_paid_membership_terms_active(
+                    system::RawOrigin::Root.into(),
+                    terms_id + 1,
+                    true,
+                )
+                .map_err(|err| err.into()),
+                "paid membership term id does not exist",
+            );
+
+            assert_ok!(Members::set_new_memberships_allowed(
+                system::RawOrigin::Root.into(),
+                false
+            ));
+            assert!(!Members::new_memberships_allowed());
+        });
+}
+
+#[test]
+fn reserved_and_case_insensitive_handles() {
+    const DEFAULT_FEE: u64 = 500;
+    let initial_members = [(0, 2)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .default_paid_membership_fee(DEFAULT_FEE)
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            set_alice_free_balance(DEFAULT_FEE);
+
+            assert_ok!(Members::reserve_handle(
+                system::RawOrigin::Root.into(),
+                b"ALICE".to_vec()
+            ));
+            assert!(Members::reserved_handles(b"alice".to_vec()));
+
+            assert_dispatch_error_message(buy_default_membership_as_alice(), "handle reserved");
+
+            assert_ok!(Members::unreserve_handle(
+                system::RawOrigin::Root.into(),
+                b"alice".to_vec()
+            ));
+
+            assert_ok!(buy_default_membership_as_alice());
+            assert_eq!(Members::member_id_by_handle(b"AlIcE"), Some(1));
+
+            // handle differing only in the letter case is taken
+            assert_dispatch_error_message(
+                Members::update_membership(
+                    Origin::signed(2),
+                    0,
+                    Some(b"Alice".to_vec()),
+                    None,
+                    None,
+                )
+                .map_err(|err| err.into()),
+                "handle already registered",
+            );
+
+            // the owner can change the letter case and the old handle is released
+            assert_ok!(Members::update_membership(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                1,
+                Some(b"Alice".to_vec()),
+                None,
+                None
+            ));
+            assert_eq!(get_membership_by_id(1).handle, b"Alice".to_vec());
+            assert_eq!(Members::member_id_by_handle(b"alice"), Some(1));
+
+            assert_ok!(Members::update_membership(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                1,
+                get_bob_info().handle,
+                None,
+                None
+            ));
+            assert_eq!(Members::member_id_by_handle(b"alice"), None);
+        });
+}
+
+#[test]
+fn handle_transfer() {
+    const BOB_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, ALICE_ACCOUNT_ID), (1, BOB_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let alice_member_id = 0;
+            let bob_member_id = 1;
+            let transferred_handle = get_membership_by_id(alice_member_id).handle;
+            let bob_old_handle = get_membership_by_id(bob_member_id).handle;
+            let replacement_handle = b"alice-old".to_vec();
+
+            assert_dispatch_error_message(
+                Members::accept_handle_transfer(
+                    Origin::signed(BOB_ACCOUNT_ID),
+                    bob_member_id,
+                    alice_member_id,
+                )
+                .map_err(|err| err.into()),
+                "handle transfer offer not found",
+            );
+
+            assert_ok!(Members::offer_handle_transfer(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                alice_member_id,
+                bob_member_id,
+                replacement_handle.clone()
+            ));
+
+            // offer of the accepting member is cancelled with the transfer
+            assert_ok!(Members::offer_handle_transfer(
+                Origin::signed(BOB_ACCOUNT_ID),
+                bob_member_id,
+                alice_member_id,
+                b"bob-old".to_vec()
+            ));
+
+            assert_ok!(Members::accept_handle_transfer(
+                Origin::signed(BOB_ACCOUNT_ID),
+                bob_member_id,
+                alice_member_id
+            ));
+
+            assert_eq!(
+                get_membership_by_id(bob_member_id).handle,
+                transferred_handle
+            );
+            assert_eq!(
+                get_membership_by_id(alice_member_id).handle,
+                replacement_handle
+            );
+            assert_eq!(
+                Members::member_id_by_handle(&transferred_handle),
+                Some(bob_member_id)
+            );
+            assert_eq!(
+                Members::member_id_by_handle(&replacement_handle),
+                Some(alice_member_id)
+            );
+            assert_eq!(Members::member_id_by_handle(&bob_old_handle), None);
+            assert!(Members::handle_transfer_offer_by_member_id(alice_member_id).is_none());
+            assert!(Members::handle_transfer_offer_by_member_id(bob_member_id).is_none());
+        });
+}
+
+#[test]
+fn handle_registered_by_another_member_is_not_released() {
+    const BOB_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, ALICE_ACCOUNT_ID), (1, BOB_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let alice_member_id = 0;
+            let bob_member_id = 1;
+            let alice_handle = get_membership_by_id(alice_member_id).handle;
+
+            // unindexed handle colliding with the handle of another member
+            <crate::MembershipById<Test>>::mutate(bob_member_id, |membership| {
+                membership.handle = alice_handle.clone()
+            });
+
+            assert_ok!(Members::change_member_handle(
+                Origin::signed(BOB_ACCOUNT_ID),
+                bob_member_id,
+                b"bob-new".to_vec()
+            ));
+
+            assert_eq!(
+                Members::member_id_by_handle(&alice_handle),
+                Some(alice_member_id)
+            );
+            assert_eq!(
+                Members::member_id_by_handle(b"bob-new"),
+                Some(bob_member_id)
+            );
+        });
+}
+
+#[test]
+fn handles_are_migrated_to_normalized_once() {
+    const BOB_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, ALICE_ACCOUNT_ID), (1, BOB_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            assert!(Members::handles_normalized());
+
+            <crate::MemberIdByHandle<Test>>::insert(b"Alice".to_vec(), 1);
+            <crate::MemberIdByHandle<Test>>::insert(b"ALICE".to_vec(), 0);
+            <crate::MembershipById<Test>>::mutate(1, |membership| {
+                membership.handle = b"Alice".to_vec()
+            });
+            <crate::MembershipById<Test>>::mutate(0, |membership| {
+                membership.handle = b"ALICE".to_vec()
+            });
+
+            // nothing happens on the normalized chains
+            Members::migrate_handles_to_normalized();
+            assert!(<crate::MemberIdByHandle<Test>>::contains_key(
+                b"Alice".to_vec()
+            ));
+
+            crate::HandlesNormalized::put(false);
+            Members::migrate_handles_to_normalized();
+
+            assert!(Members::handles_normalized());
+            assert!(!<crate::MemberIdByHandle<Test>>::contains_key(
+                b"Alice".to_vec()
+            ));
+            assert_eq!(Members::member_id_by_handle(b"alice"), Some(0));
+            assert_eq!(get_membership_by_id(0).handle, b"ALICE".to_vec());
+
+            // the newer member is renamed instead of being left unindexed
+            assert_eq!(get_membership_by_id(1).handle, b"Alice-1".to_vec());
+            assert_eq!(Members::member_id_by_handle(b"alice-1"), Some(1));
+        });
+}
+
+#[test]
+fn migrated_handles_are_renamed_within_max_handle_length() {
+    let initial_members = [(0, 1), (1, 2), (2, 3)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            crate::MaxHandleLength::put(8);
+
+            for (member_id, handle) in [(0, b"ABCDEFGH"), (1, b"abcdefgh"), (2, b"abcdef-1")].iter()
+            {
+                <crate::MemberIdByHandle<Test>>::insert(handle.to_vec(), member_id);
+                <crate::MembershipById<Test>>::mutate(member_id, |membership| {
+                    membership.handle = handle.to_vec()
+                });
+            }
+
+            crate::HandlesNormalized::put(false);
+            Members::migrate_handles_to_normalized();
+
+            assert_eq!(Members::member_id_by_handle(b"abcdefgh"), Some(0));
+            assert_eq!(Members::member_id_by_handle(b"abcdef-1"), Some(2));
+
+            // the base handle is truncated to fit the member id and the extension
+            assert_eq!(get_membership_by_id(1).handle, b"abcde-1_".to_vec());
+            assert_eq!(Members::member_id_by_handle(b"abcde-1_"), Some(1));
+        });
+}
+
+#[test]
+fn legacy_screening_authority_is_migrated_with_quota() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            let screening_authority: u64 = 5;
+
+            // nothing happens without the legacy screening authority
+            Members::migrate_screening_authority(10);
+            assert!(Members::screening_authorities(&screening_authority).is_none());
+
+            storage::migration::put_storage_value(
+                b"Membership",
+                b"ScreeningAuthority",
+                &[],
+                screening_authority,
+            );
+            Members::migrate_screening_authority(10);
+
+            assert_eq!(
+                Members::screening_authorities(&screening_authority),
+                Some(crate::ScreeningQuota {
+                    member_quota: 10,
+                    members_screened: 0,
+                    max_initial_balance: 0,
+                })
+            );
+            assert!(storage::migration::get_storage_value::<u64>(
+                b"Membership",
+                b"ScreeningAuthority",
+                &[]
+            )
+            .is_none());
+        });
+}
+
+#[test]
+fn social_recovery_of_root_account() {
+    const BOB_ACCOUNT_ID: u64 = 2;
+    const CHARLIE_ACCOUNT_ID: u64 = 3;
+    const NEW_ROOT_ACCOUNT_ID: u64 = 4;
+    const DELAY: u64 = crate::MIN_RECOVERY_DELAY_PERIOD as u64;
+    let initial_members = [
+        (0, ALICE_ACCOUNT_ID),
+        (1, BOB_ACCOUNT_ID),
+        (2, CHARLIE_ACCOUNT_ID),
+    ];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let alice_member_id = 0;
+            let bob_member_id = 1;
+            let charlie_member_id = 2;
+
+            System::set_block_number(1);
+
+            assert_dispatch_error_message(
+                Members::set_recovery_config(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    alice_member_id,
+                    vec![bob_member_id, charlie_member_id],
+                    3,
+                    DELAY,
+                )
+                .map_err(|err| err.into()),
+                "invalid recovery threshold",
+            );
+
+            assert_dispatch_error_message(
+                Members::set_recovery_config(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    alice_member_id,
+                    vec![bob_member_id, alice_member_id],
+                    1,
+                    DELAY,
+                )
+                .map_err(|err| err.into()),
+                "member cannot be its own recovery friend",
+            );
+
+            assert_dispatch_error_message(
+                Members::set_recovery_config(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    alice_member_id,
+                    vec![bob_member_id, charlie_member_id],
+                    2,
+                    DELAY - 1,
+                )
+                .map_err(|err| err.into()),
+                "recovery delay period too short",
+            );
+
+            assert_ok!(Members::set_recovery_config(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                alice_member_id,
+                vec![bob_member_id, charlie_member_id],
+                2,
+                DELAY,
+            ));
+
+            assert_ok!(Members::initiate_recovery(
+                Origin::signed(BOB_ACCOUNT_ID),
+                bob_member_id,
+                alice_member_id,
+                NEW_ROOT_ACCOUNT_ID,
+            ));
+
+            // original root account can cancel during the delay
+            assert_ok!(Members::cancel_recovery(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                alice_member_id
+            ));
+            assert!(Members::active_recovery_by_member_id(alice_member_id).is_none());
+
+            assert_ok!(Members::initiate_recovery(
+                Origin::signed(BOB_ACCOUNT_ID),
+                bob_member_id,
+                alice_member_id,
+                NEW_ROOT_ACCOUNT_ID,
+            ));
+
+            assert_dispatch_error_message(
+                Members::vouch_recovery(
+                    Origin::signed(BOB_ACCOUNT_ID),
+                    bob_member_id,
+                    alice_member_id,
+                )
+                .map_err(|err| err.into()),
+                "already vouched for recovery",
+            );
+
+            assert_dispatch_error_message(
+                Members::finalize_recovery(Origin::signed(BOB_ACCOUNT_ID), alice_member_id)
+                    .map_err(|err| err.into()),
+                "not enough recovery vouchers",
+            );
+
+            assert_ok!(Members::vouch_recovery(
+                Origin::signed(CHARLIE_ACCOUNT_ID),
+                charlie_member_id,
+                alice_member_id,
+            ));
+
+            assert_dispatch_error_message(
+                Members::finalize_recovery(Origin::signed(BOB_ACCOUNT_ID), alice_member_id)
+                    .map_err(|err| err.into()),
+                "recovery delay period not passed",
+            );
+
+            System::set_block_number(1 + DELAY);
+
+            assert_ok!(Members::finalize_recovery(
+                Origin::signed(BOB_ACCOUNT_ID),
+                alice_member_id
+            ));
+
+            assert_eq!(
+                get_membership_by_id(alice_member_id).root_account,
+                NEW_ROOT_ACCOUNT_ID
+            );
+            assert_eq!(
+                <crate::MemberIdsByRootAccountId<Test>>::get(NEW_ROOT_ACCOUNT_ID),
+                vec![alice_member_id]
+            );
+            assert!(<crate::MemberIdsByRootAccountId<Test>>::get(ALICE_ACCOUNT_ID).is_empty());
+            assert!(Members::active_recovery_by_member_id(alice_member_id).is_none());
+        });
+}
+
+#[test]
+fn expired_recovery_is_replaced() {
+    const BOB_ACCOUNT_ID: u64 = 2;
+    const CHARLIE_ACCOUNT_ID: u64 = 3;
+    const NEW_ROOT_ACCOUNT_ID: u64 = 4;
+    const MALICIOUS_ROOT_ACCOUNT_ID: u64 = 5;
+    const DELAY: u64 = crate::MIN_RECOVERY_DELAY_PERIOD as u64;
+    let initial_members = [
+        (0, ALICE_ACCOUNT_ID),
+        (1, BOB_ACCOUNT_ID),
+        (2, CHARLIE_ACCOUNT_ID),
+    ];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let alice_member_id = 0;
+            let bob_member_id = 1;
+            let charlie_member_id = 2;
+
+            System::set_block_number(1);
+
+            assert_ok!(Members::set_recovery_config(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                alice_member_id,
+                vec![bob_member_id, charlie_member_id],
+                2,
+                DELAY,
+            ));
+
+            assert_ok!(Members::initiate_recovery(
+                Origin::signed(BOB_ACCOUNT_ID),
+                bob_member_id,
+                alice_member_id,
+                MALICIOUS_ROOT_ACCOUNT_ID,
+            ));
+
+            assert_dispatch_error_message(
+                Members::initiate_recovery(
+                    Origin::signed(CHARLIE_ACCOUNT_ID),
+                    charlie_member_id,
+                    alice_member_id,
+                    NEW_ROOT_ACCOUNT_ID,
+                )
+                .map_err(|err| err.into()),
+                "recovery in progress",
+            );
+
+            System::set_block_number(1 + 2 * DELAY);
+
+            assert_dispatch_error_message(
+                Members::vouch_recovery(
+                    Origin::signed(CHARLIE_ACCOUNT_ID),
+                    charlie_member_id,
+                    alice_member_id,
+                )
+                .map_err(|err| err.into()),
+                "recovery expired",
+            );
+
+            assert_ok!(Members::initiate_recovery(
+                Origin::signed(CHARLIE_ACCOUNT_ID),
+                charlie_member_id,
+                alice_member_id,
+                NEW_ROOT_ACCOUNT_ID,
+            ));
+
+            let recovery = Members::active_recovery_by_member_id(alice_member_id).unwrap();
+            assert_eq!(recovery.new_root_account, NEW_ROOT_ACCOUNT_ID);
+            assert_eq!(recovery.vouchers, vec![charlie_member_id]);
+        });
+}
@@ -31,6 +31,12 @@
 //! - [create_set_working_group_leader_reward_proposal](./struct.Module.html#method.create_set_working_group_leader_reward_proposal)
 //! - [create_terminate_working_group_leader_role_proposal](./struct.Module.html#method.create_terminate_working_group_leader_role_proposal)
 //!
+//! ### Membership proposals
+//! - [create_add_paid_membership_terms_proposal](./struct.Module.html#method.create_add_paid_membership_terms_proposal)
+//! - [create_set_paid_membership_terms_active_proposal](./struct.Module.html#method.create_set_paid_membership_terms_active_proposal)
+//! - [create_set_paid_membership_fee_proposal](./struct.Module.html#method.create_set_paid_membership_fee_proposal)
+//! - [create_set_new_memberships_allowed_proposal](./struct.Module.html#method.create_set_new_memberships_allowed_proposal)
+//!
 //! ### Proposal implementations of this module
 //! - execute_text_proposal - prints the proposal to the log
 //! - execute_runtime_upgrade_proposal - sets the runtime code
@@ -118,6 +124,12 @@ const ELECTION_PARAMETERS_ANNOUNCING_PERIOD_MAX_VALUE: u32 = 43200;
 const ELECTION_PARAMETERS_MIN_COUNCIL_STAKE_MIN_VALUE: u32 = 1;
 // min_council_stake max value for the 'set election parameters' proposal
 const ELECTION_PARAMETERS_MIN_COUNCIL_STAKE_MAX_VALUE: u32 = 100_000_u32;
+// Max text length for the 'add paid membership terms' proposal
+const PAID_MEMBERSHIP_TERMS_TEXT_MAX_LENGTH: u32 = 5_000;
+// Min fee for the paid membership terms proposals
+const PAID_MEMBERSHIP_FEE_MIN_VALUE: u32 = 1;
+// Max fee for the paid membership terms proposals
+const PAID_MEMBERSHIP_FEE_MAX_VALUE: u32 = 1_000_000_u32;
 
 // Data container struct to fix linter warning 'too many arguments for the function' for the
 // create_proposal() function.
@@ -182,6 +194,8 @@ pub type NegativeImbalance<T> =
 
 type MemberId<T> = <T as membership::Trait>::MemberId;
 
+type PaidTermId<T> = <T as membership::Trait>::PaidTermId;
+
 decl_error! {
     /// Codex module predefined errors
     pub enum Error for Module<T: Trait> {
@@ -244,6 +258,18 @@ decl_error! {
 
         /// Invalid 'decrease stake proposal' parameter - cannot decrease by zero balance.
         DecreasingStakeIsZero,
+
+        /// Provided text for the paid membership terms is empty
+        PaidMembershipTermsTextIsEmpty,
+
+        /// The size of the provided text for the paid membership terms exceeded the limit
+        PaidMembershipTermsTextSizeExceeded,
+
+        /// Invalid fee for the paid membership terms
+        InvalidPaidMembershipFee,
+
+        /// Paid membership terms with the provided id don't exist
+        PaidMembershipTermsNotFound,
     }
 }
 
@@ -350,6 +376,38 @@ decl_storage! {
         /// Grace period for the 'terminate working group leader role' proposal
         pub TerminateWorkingGroupLeaderRoleProposalGracePeriod get(fn terminate_working_group_leader_role_proposal_grace_period)
             config(): T::BlockNumber;
+
+        /// Voting period for the 'add paid membership terms' proposal
+        pub AddPaidMembershipTermsProposalVotingPeriod get(fn add_paid_membership_terms_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'add paid membership terms' proposal
+        pub AddPaidMembershipTermsProposalGracePeriod get(fn add_paid_membership_terms_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'set paid membership terms active' proposal
+        pub SetPaidMembershipTermsActiveProposalVotingPeriod get(fn set_paid_membership_terms_active_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set paid membership terms active' proposal
+        pub SetPaidMembershipTermsActiveProposalGracePeriod get(fn set_paid_membership_terms_active_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'set paid membership fee' proposal
+        pub SetPaidMembershipFeeProposalVotingPeriod get(fn set_paid_membership_fee_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set paid membership fee' proposal
+        pub SetPaidMembershipFeeProposalGracePeriod get(fn set_paid_membership_fee_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'set new memberships allowed' proposal
+        pub SetNewMembershipsAllowedProposalVotingPeriod get(fn set_new_memberships_allowed_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set new memberships allowed' proposal
+        pub SetNewMembershipsAllowedProposalGracePeriod get(fn set_new_memberships_allowed_proposal_grace_period)
+            config(): T::BlockNumber;
     }
 }
 
@@ -775,6 +833,125 @@ decl_module! {
             Self::create_proposal(params)?;
         }
 
+        /// Create 'add paid membership terms' proposal type.
+        /// This proposal uses `add_paid_membership_terms()` extrinsic from the `membership`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_add_paid_membership_terms_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            fee: BalanceOfGovernanceCurrency<T>,
+            text: Vec<u8>,
+        ) {
+            ensure!(!text.is_empty(), Error::<T>::PaidMembershipTermsTextIsEmpty);
+            ensure!(text.len() as u32 <= PAID_MEMBERSHIP_TERMS_TEXT_MAX_LENGTH,
+                Error::<T>::PaidMembershipTermsTextSizeExceeded);
+
+            Self::ensure_paid_membership_fee_valid(fee)?;
+
+            let proposal_details = ProposalDetails::AddPaidMembershipTerms(fee, text);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::add_paid_membership_terms_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'set paid membership terms active' proposal type.
+        /// This proposal uses `set_paid_membership_terms_active()` extrinsic from the `membership`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_paid_membership_terms_active_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            paid_terms_id: PaidTermId<T>,
+            active: bool,
+        ) {
+            Self::ensure_paid_membership_terms_exist(paid_terms_id)?;
+
+            let proposal_details = ProposalDetails::SetPaidMembershipTermsActive(paid_terms_id, active);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::set_paid_membership_terms_active_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'set paid membership fee' proposal type.
+        /// This proposal uses `set_paid_membership_fee()` extrinsic from the `membership`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_paid_membership_fee_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            paid_terms_id: PaidTermId<T>,
+            fee: BalanceOfGovernanceCurrency<T>,
+        ) {
+            Self::ensure_paid_membership_terms_exist(paid_terms_id)?;
+
+            Self::ensure_paid_membership_fee_valid(fee)?;
+
+            let proposal_details = ProposalDetails::SetPaidMembershipFee(paid_terms_id, fee);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::set_paid_membership_fee_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'set new memberships allowed' proposal type.
+        /// This proposal uses `set_new_memberships_allowed()` extrinsic from the `membership`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_new_memberships_allowed_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            allowed: bool,
+        ) {
+            let proposal_details = ProposalDetails::SetNewMembershipsAllowed(allowed);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::set_new_memberships_allowed_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
 
 // *************** Extrinsic to execute
 
@@ -953,6 +1130,31 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    // validates fee for the paid membership terms proposals
+    fn ensure_paid_membership_fee_valid(fee: BalanceOfGovernanceCurrency<T>) -> DispatchResult {
+        ensure!(
+            fee >= <BalanceOfGovernanceCurrency<T>>::from(PAID_MEMBERSHIP_FEE_MIN_VALUE),
+            Error::<T>::InvalidPaidMembershipFee
+        );
+
+        ensure!(
+            fee <= <BalanceOfGovernanceCurrency<T>>::from(PAID_MEMBERSHIP_FEE_MAX_VALUE),
+            Error::<T>::InvalidPaidMembershipFee
+        );
+
+        Ok(())
+    }
+
+    // validates paid membership terms id for the paid membership terms proposals
+    fn ensure_paid_membership_terms_exist(paid_terms_id: PaidTermId<T>) -> DispatchResult {
+        ensure!(
+            <membership::PaidMembershipTermsById<T>>::contains_key(paid_terms_id),
+            Error::<T>::PaidMembershipTermsNotFound
+        );
+
+        Ok(())
+    }
+
     /// Sets config values for the proposals.
     /// Should be called on the migration to the new runtime version.
     pub fn set_config_values(p: ProposalsConfigParameters) {
@@ -1034,5 +1236,45 @@ impl<T: Trait> Module<T> {
         <TerminateWorkingGroupLeaderRoleProposalGracePeriod<T>>::put(T::BlockNumber::from(
             p.terminate_working_group_leader_role_proposal_grace_period,
         ));
+        Self::set_membership_terms_proposal_periods(p);
+    }
+
+    /// Sets the voting and grace periods of the paid membership terms proposals on the chains
+    /// started without them. Does nothing if the periods were already set.
+    /// Should be called on the migration to the new runtime version.
+    pub fn migrate_membership_terms_proposal_periods(p: ProposalsConfigParameters) {
+        if !Self::add_paid_membership_terms_proposal_voting_period().is_zero() {
+            return;
+        }
+
+        Self::set_membership_terms_proposal_periods(p);
+    }
+
+    // sets the voting and grace periods of the paid membership terms proposals
+    fn set_membership_terms_proposal_periods(p: ProposalsConfigParameters) {
+        <AddPaidMembershipTermsProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.add_paid_membership_terms_proposal_voting_period,
+        ));
+        <AddPaidMembershipTermsProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.add_paid_membership_terms_proposal_grace_period,
+        ));
+        <SetPaidMembershipTermsActiveProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_paid_membership_terms_active_proposal_voting_period,
+        ));
+        <SetPaidMembershipTermsActiveProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_paid_membership_terms_active_proposal_grace_period,
+        ));
+        <SetPaidMembershipFeeProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_paid_membership_fee_proposal_voting_period,
+        ));
+        <SetPaidMembershipFeeProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_paid_membership_fee_proposal_grace_period,
+        ));
+        <SetNewMembershipsAllowedProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_new_memberships_allowed_proposal_voting_period,
+        ));
+        <SetNewMembershipsAllowedProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_new_memberships_allowed_proposal_grace_period,
+        ));
     }
 }
@@ -27,6 +27,7 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     crate::BalanceOf<T>,
     working_group::WorkerId<T>,
     crate::MemberId<T>,
+    crate::PaidTermId<T>,
 >;
 
 /// Proposal details provide voters the information required for the perceived voting.
@@ -42,6 +43,7 @@ pub enum ProposalDetails<
     StakeBalance,
     WorkerId,
     MemberId,
+    PaidTermId,
 > {
     /// The text of the `text` proposal
     Text(Vec<u8>),
@@ -103,6 +105,18 @@ pub enum ProposalDetails<
 
     /// Fire the working group leader with possible slashing.
     TerminateWorkingGroupLeaderRole(TerminateRoleParameters<WorkerId>),
+
+    /// Fee and text for the `add paid membership terms` proposal
+    AddPaidMembershipTerms(CurrencyBalance, Vec<u8>),
+
+    /// Activate or deactivate paid membership terms.
+    SetPaidMembershipTermsActive(PaidTermId, bool),
+
+    /// Set the fee of the paid membership terms.
+    SetPaidMembershipFee(PaidTermId, CurrencyBalance),
+
+    /// Allow or disallow new memberships.
+    SetNewMembershipsAllowed(bool),
 }
 
 impl<
@@ -115,6 +129,7 @@ impl<
         StakeBalance,
         WorkerId,
         MemberId,
+        PaidTermId,
     > Default
     for ProposalDetails<
         MintedBalance,
@@ -126,6 +141,7 @@ impl<
         StakeBalance,
         WorkerId,
         MemberId,
+        PaidTermId,
     >
 {
     fn default() -> Self {
@@ -309,6 +325,30 @@ pub struct ProposalsConfigParameters {
 
     /// 'Terminate working group leader role' proposal grace period
     pub terminate_working_group_leader_role_proposal_grace_period: u32,
+
+    /// 'Add paid membership terms' proposal voting period
+    pub add_paid_membership_terms_proposal_voting_period: u32,
+
+    /// 'Add paid membership terms' proposal grace period
+    pub add_paid_membership_terms_proposal_grace_period: u32,
+
+    /// 'Set paid membership terms active' proposal voting period
+    pub set_paid_membership_terms_active_proposal_voting_period: u32,
+
+    /// 'Set paid membership terms active' proposal grace period
+    pub set_paid_membership_terms_active_proposal_grace_period: u32,
+
+    /// 'Set paid membership fee' proposal voting period
+    pub set_paid_membership_fee_proposal_voting_period: u32,
+
+    /// 'Set paid membership fee' proposal grace period
+    pub set_paid_membership_fee_proposal_grace_period: u32,
+
+    /// 'Set new memberships allowed' proposal voting period
+    pub set_new_memberships_allowed_proposal_voting_period: u32,
+
+    /// 'Set new memberships allowed' proposal grace period
+    pub set_new_memberships_allowed_proposal_grace_period: u32,
 }
 
 impl Default for ProposalsConfigParameters {
@@ -342,6 +382,14 @@ impl Default for ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0u32,
             terminate_working_group_leader_role_proposal_voting_period: 72200u32,
             terminate_working_group_leader_role_proposal_grace_period: 0u32,
+            add_paid_membership_terms_proposal_voting_period: 43200u32,
+            add_paid_membership_terms_proposal_grace_period: 14400u32,
+            set_paid_membership_terms_active_proposal_voting_period: 43200u32,
+            set_paid_membership_terms_active_proposal_grace_period: 0u32,
+            set_paid_membership_fee_proposal_voting_period: 43200u32,
+            set_paid_membership_fee_proposal_grace_period: 14400u32,
+            set_new_memberships_allowed_proposal_voting_period: 43200u32,
+            set_new_memberships_allowed_proposal_grace_period: 0u32,
         }
     }
 }
@@ -380,6 +428,14 @@ impl ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0,
             terminate_working_group_leader_role_proposal_voting_period: voting_period,
             terminate_working_group_leader_role_proposal_grace_period: 0,
+            add_paid_membership_terms_proposal_voting_period: voting_period,
+            add_paid_membership_terms_proposal_grace_period: grace_period,
+            set_paid_membership_terms_active_proposal_voting_period: voting_period,
+            set_paid_membership_terms_active_proposal_grace_period: 0,
+            set_paid_membership_fee_proposal_voting_period: voting_period,
+            set_paid_membership_fee_proposal_grace_period: grace_period,
+            set_new_memberships_allowed_proposal_voting_period: voting_period,
+            set_new_memberships_allowed_proposal_grace_period: 0,
         }
     }
 }
@@ -182,3 +182,59 @@ pub(crate) fn terminate_working_group_leader_role_proposal<T: crate::Trait>(
         required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
     }
 }
+
+// Proposal parameters for the 'Add paid membership terms' proposal
+pub(crate) fn add_paid_membership_terms_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::add_paid_membership_terms_proposal_voting_period(),
+        grace_period: <Module<T>>::add_paid_membership_terms_proposal_grace_period(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+    }
+}
+
+// Proposal parameters for the 'Set paid membership terms active' proposal
+pub(crate) fn set_paid_membership_terms_active_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_paid_membership_terms_active_proposal_voting_period(),
+        grace_period: <Module<T>>::set_paid_membership_terms_active_proposal_grace_period(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+    }
+}
+
+// Proposal parameters for the 'Set paid membership fee' proposal
+pub(crate) fn set_paid_membership_fee_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_paid_membership_fee_proposal_voting_period(),
+        grace_period: <Module<T>>::set_paid_membership_fee_proposal_grace_period(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
+    }
+}
+
+// Proposal parameters for the 'Set new memberships allowed' proposal
+pub(crate) fn set_new_memberships_allowed_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_new_memberships_allowed_proposal_voting_period(),
+        grace_period: <Module<T>>::set_new_memberships_allowed_proposal_grace_period(),
+        approval_quorum_percentage: 66,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+    }
+}
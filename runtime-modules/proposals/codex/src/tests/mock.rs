@@ -167,11 +167,13 @@ parameter_types! {
 impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Test {
     type Event = ();
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WorkerExitHandler = ();
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = ();
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WorkerExitHandler = ();
 }
 
 impl recurring_rewards::Trait for Test {
@@ -42,7 +42,7 @@ where
     invalid_stake_call: InvalidStakeCall,
     successful_call: SuccessfulCall,
     proposal_parameters: ProposalParameters<u64, u64>,
-    proposal_details: ProposalDetails<u64, u64, u64, u64, u64, u64, u64, u64, u64>,
+    proposal_details: ProposalDetails<u64, u64, u64, u64, u64, u64, u64, u64, u64, u64>,
 }
 
 impl<InsufficientRightsCall, EmptyStakeCall, InvalidStakeCall, SuccessfulCall>
@@ -761,6 +761,75 @@ fn set_default_proposal_parameters_succeeded() {
             <TerminateWorkingGroupLeaderRoleProposalGracePeriod<Test>>::get(),
             p.terminate_working_group_leader_role_proposal_grace_period as u64
         );
+        assert_eq!(
+            <AddPaidMembershipTermsProposalVotingPeriod<Test>>::get(),
+            p.add_paid_membership_terms_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <AddPaidMembershipTermsProposalGracePeriod<Test>>::get(),
+            p.add_paid_membership_terms_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetPaidMembershipTermsActiveProposalVotingPeriod<Test>>::get(),
+            p.set_paid_membership_terms_active_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetPaidMembershipTermsActiveProposalGracePeriod<Test>>::get(),
+            p.set_paid_membership_terms_active_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetPaidMembershipFeeProposalVotingPeriod<Test>>::get(),
+            p.set_paid_membership_fee_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetPaidMembershipFeeProposalGracePeriod<Test>>::get(),
+            p.set_paid_membership_fee_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetNewMembershipsAllowedProposalVotingPeriod<Test>>::get(),
+            p.set_new_memberships_allowed_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetNewMembershipsAllowedProposalGracePeriod<Test>>::get(),
+            p.set_new_memberships_allowed_proposal_grace_period as u64
+        );
+    });
+}
+
+#[test]
+fn membership_terms_proposal_periods_are_migrated_once() {
+    initial_test_ext().execute_with(|| {
+        let p = ProposalsConfigParameters::default();
+
+        // nothing is set
+        assert_eq!(<AddPaidMembershipTermsProposalVotingPeriod<Test>>::get(), 0);
+
+        ProposalCodex::migrate_membership_terms_proposal_periods(p);
+
+        assert_eq!(
+            <AddPaidMembershipTermsProposalVotingPeriod<Test>>::get(),
+            p.add_paid_membership_terms_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetPaidMembershipFeeProposalGracePeriod<Test>>::get(),
+            p.set_paid_membership_fee_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetNewMembershipsAllowedProposalVotingPeriod<Test>>::get(),
+            p.set_new_memberships_allowed_proposal_voting_period as u64
+        );
+        // other proposal periods are not touched
+        assert_eq!(<TextProposalVotingPeriod<Test>>::get(), 0);
+
+        // already set periods are kept
+        ProposalCodex::migrate_membership_terms_proposal_periods(
+            ProposalsConfigParameters::with_grace_and_voting_periods(1, 1),
+        );
+
+        assert_eq!(
+            <AddPaidMembershipTermsProposalVotingPeriod<Test>>::get(),
+            p.add_paid_membership_terms_proposal_voting_period as u64
+        );
     });
 }
 
@@ -1445,3 +1514,269 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
         proposal_fixture.check_all();
     });
 }
+
+#[test]
+fn create_add_paid_membership_terms_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_add_paid_membership_terms_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    100,
+                    b"terms".to_vec(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_add_paid_membership_terms_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    100,
+                    b"terms".to_vec(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_add_paid_membership_terms_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    100,
+                    b"terms".to_vec(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_add_paid_membership_terms_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    100,
+                    b"terms".to_vec(),
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::add_paid_membership_terms_proposal::<Test>(),
+            proposal_details: ProposalDetails::AddPaidMembershipTerms(100, b"terms".to_vec()),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_add_paid_membership_terms_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalCodex::create_add_paid_membership_terms_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                100,
+                Vec::new(),
+            ),
+            Err(Error::<Test>::PaidMembershipTermsTextIsEmpty.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::create_add_paid_membership_terms_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                100,
+                vec![b'a'; (crate::PAID_MEMBERSHIP_TERMS_TEXT_MAX_LENGTH + 1) as usize],
+            ),
+            Err(Error::<Test>::PaidMembershipTermsTextSizeExceeded.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::create_add_paid_membership_terms_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                0,
+                b"terms".to_vec(),
+            ),
+            Err(Error::<Test>::InvalidPaidMembershipFee.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::create_add_paid_membership_terms_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                (crate::PAID_MEMBERSHIP_FEE_MAX_VALUE + 1) as u64,
+                b"terms".to_vec(),
+            ),
+            Err(Error::<Test>::InvalidPaidMembershipFee.into())
+        );
+    });
+}
+
+#[test]
+fn create_set_paid_membership_fee_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let paid_terms_id = 0;
+        <membership::PaidMembershipTermsById<Test>>::insert(
+            paid_terms_id,
+            membership::PaidMembershipTerms::default(),
+        );
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_paid_membership_fee_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    paid_terms_id,
+                    100,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_paid_membership_fee_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    paid_terms_id,
+                    100,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_paid_membership_fee_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    paid_terms_id,
+                    100,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_paid_membership_fee_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    paid_terms_id,
+                    100,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_paid_membership_fee_proposal::<Test>(),
+            proposal_details: ProposalDetails::SetPaidMembershipFee(paid_terms_id, 100),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_paid_membership_terms_proposals_fail_with_invalid_terms_id() {
+    initial_test_ext().execute_with(|| {
+        let paid_terms_id = 10;
+
+        assert_eq!(
+            ProposalCodex::create_set_paid_membership_fee_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                paid_terms_id,
+                100,
+            ),
+            Err(Error::<Test>::PaidMembershipTermsNotFound.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::create_set_paid_membership_terms_active_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                paid_terms_id,
+                false,
+            ),
+            Err(Error::<Test>::PaidMembershipTermsNotFound.into())
+        );
+    });
+}
+
+#[test]
+fn create_set_new_memberships_allowed_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_new_memberships_allowed_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    false,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_new_memberships_allowed_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    false,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_new_memberships_allowed_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    false,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_new_memberships_allowed_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    false,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_new_memberships_allowed_proposal::<Test>(),
+            proposal_details: ProposalDetails::SetNewMembershipsAllowed(false),
+        };
+        proposal_fixture.check_all();
+    });
+}
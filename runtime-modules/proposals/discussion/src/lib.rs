@@ -9,7 +9,8 @@
 //! posts.
 //!
 //! ## Supported extrinsics
-//! - [add_post](./struct.Module.html#method.add_post) - adds a post to an existing discussion thread
+//! - [add_post](./struct.Module.html#method.add_post) - adds a post to an existing discussion thread,
+//! optionally as a reply to an earlier post of the thread
 //! - [update_post](./struct.Module.html#method.update_post) - updates existing post
 //!
 //! ## Public API methods
@@ -75,6 +76,13 @@ decl_event!(
 
         /// Emits on post update.
         PostUpdated(PostId, MemberId),
+
+        /// Emits on reply creation.
+        /// Params:
+        /// - reply post id
+        /// - replied post id
+        /// - replied post author id
+        PostRepliedTo(PostId, PostId, MemberId),
     }
 );
 
@@ -141,6 +149,9 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Replied post doesn't exist in the thread
+        ReplyToPostDoesntExist,
     }
 }
 
@@ -162,6 +173,12 @@ decl_storage! {
         /// Count of all posts that have been created.
         pub PostCount get(fn post_count): u64;
 
+        /// Map thread id and post id to the id of the post it replies to. Kept apart from
+        /// the `DiscussionPost` to preserve the encoding of the existing posts.
+        pub PostReplyTo get(fn post_reply_to):
+            double_map hasher(blake2_128_concat) T::ThreadId, hasher(blake2_128_concat) T::PostId =>
+                Option<T::PostId>;
+
         /// Last author thread counter (part of the antispam mechanism)
         pub LastThreadAuthorCounter get(fn last_thread_author_counter):
             Option<ThreadCounter<MemberId<T>>>;
@@ -189,13 +206,15 @@ decl_module! {
         /// Exports max thread by same author in a row number limit const.
         const MaxThreadInARowNumber: u32 = T::MaxThreadInARowNumber::get();
 
-        /// Adds a post with author origin check.
+        /// Adds a post with author origin check. Optional `reply_to` post should belong
+        /// to the same thread.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn add_post(
             origin,
             post_author_id: MemberId<T>,
             thread_id : T::ThreadId,
-            text : Vec<u8>
+            text : Vec<u8>,
+            reply_to: Option<T::PostId>
         ) {
             T::PostAuthorOriginValidator::ensure_actor_origin(
                 origin,
@@ -209,6 +228,20 @@ decl_module! {
                 Error::<T>::PostIsTooLong
             );
 
+            let replied_post = if let Some(replied_post_id) = reply_to {
+                ensure!(
+                    <PostThreadIdByPostId<T>>::contains_key(thread_id, replied_post_id),
+                    Error::<T>::ReplyToPostDoesntExist
+                );
+
+                let replied_post_author_id =
+                    <PostThreadIdByPostId<T>>::get(thread_id, replied_post_id).author_id;
+
+                Some((replied_post_id, replied_post_author_id))
+            } else {
+                None
+            };
+
             // mutation
 
             let next_post_count_value = Self::post_count() + 1;
@@ -227,6 +260,13 @@ decl_module! {
             <PostThreadIdByPostId<T>>::insert(thread_id, post_id, new_post);
             PostCount::put(next_post_count_value);
             Self::deposit_event(RawEvent::PostCreated(post_id, post_author_id));
+
+            if let Some((replied_post_id, replied_post_author_id)) = replied_post {
+                <PostReplyTo<T>>::insert(thread_id, post_id, replied_post_id);
+                Self::deposit_event(
+                    RawEvent::PostRepliedTo(post_id, replied_post_id, replied_post_author_id)
+                );
+            }
        }
 
         /// Updates a post with author origin check. Update attempts number is limited.
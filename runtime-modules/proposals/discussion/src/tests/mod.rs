@@ -98,6 +98,7 @@ struct PostFixture {
     pub thread_id: u64,
     pub post_id: Option<u64>,
     pub author_id: u64,
+    pub reply_to: Option<u64>,
 }
 
 impl PostFixture {
@@ -108,6 +109,7 @@ impl PostFixture {
             thread_id,
             origin: RawOrigin::Signed(1),
             post_id: None,
+            reply_to: None,
         }
     }
 
@@ -123,6 +125,13 @@ impl PostFixture {
         PostFixture { author_id, ..self }
     }
 
+    fn with_reply_to(self, reply_to: u64) -> Self {
+        PostFixture {
+            reply_to: Some(reply_to),
+            ..self
+        }
+    }
+
     fn change_thread_id(self, thread_id: u64) -> Self {
         PostFixture { thread_id, ..self }
     }
@@ -140,6 +149,7 @@ impl PostFixture {
             self.author_id,
             self.thread_id,
             self.text.clone(),
+            self.reply_to,
         );
 
         assert_eq!(add_post_result, result);
@@ -425,3 +435,50 @@ fn discussion_thread_and_post_counters_are_valid() {
         assert_eq!(Discussions::post_count(), 1);
     });
 }
+
+#[test]
+fn add_reply_post_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut reply_fixture = PostFixture::default_for_thread(thread_id).with_reply_to(post_id);
+        let reply_id = reply_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        assert_eq!(Discussions::post_reply_to(thread_id, reply_id), Some(post_id));
+        assert_eq!(Discussions::post_reply_to(thread_id, post_id), None);
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1),
+            RawEvent::PostCreated(2, 1),
+            RawEvent::PostRepliedTo(2, 1, 1),
+        ]);
+    });
+}
+
+#[test]
+fn add_reply_post_call_fails_for_post_from_another_thread() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+        let another_thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(2))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(another_thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        let mut reply_fixture = PostFixture::default_for_thread(thread_id).with_reply_to(post_id);
+        reply_fixture.add_post_and_assert(Err(Error::<Test>::ReplyToPostDoesntExist.into()));
+    });
+}
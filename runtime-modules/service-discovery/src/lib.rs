@@ -6,19 +6,30 @@
 //! ## Comments
 //!
 //! Service discovery module uses working group module to authorize actions. It is generally used by
-//! the Colossus service.
+//! the Colossus service. Storage provider records are authorized by the storage working group,
+//! distributor records by the distribution working group. Their worker ids can overlap, so the
+//! records are kept in separate maps.
 //!
 //! ## Supported extrinsics
 //!
 //! - [set_ipns_id](./struct.Module.html#method.set_ipns_id) - Creates the ServiceProviderRecord to save an IPNS identity for the storage provider.
+//! - [set_service_provider_record](./struct.Module.html#method.set_service_provider_record) - Creates the ServiceProviderRecord with the service type and endpoints.
 //! - [unset_ipns_id](./struct.Module.html#method.unset_ipns_id) - Deletes the ServiceProviderRecord with the IPNS identity for the storage provider.
+//! - [unset_distributor_record](./struct.Module.html#method.unset_distributor_record) - Deletes the ServiceProviderRecord of the distributor.
 //! - [set_default_lifetime](./struct.Module.html#method.set_default_lifetime) - Sets default lifetime for storage providers accounts info.
 //! - [set_bootstrap_endpoints](./struct.Module.html#method.set_bootstrap_endpoints) - Sets bootstrap endpoints for the Colossus.
 //!
+//! ## Records cleanup
+//!
+//! Expired records are pruned on block initialization in bounded batches. Records of the storage
+//! providers and distributors leaving their working groups are removed by the working group exit
+//! handlers.
+//!
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod migration;
 mod mock;
 mod tests;
 
@@ -26,9 +37,21 @@ use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-use frame_support::{decl_event, decl_module, decl_storage, ensure};
+use frame_support::storage::IterableStorageMap;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
+use sp_runtime::traits::One;
+use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
 use system::ensure_root;
+
+mod validation;
+
+pub use validation::{
+    is_valid_ipns_identity, is_valid_url, HTTP_SCHEMES, MULTIADDR_PREFIXES, WEBSOCKET_SCHEMES,
+};
+
 /*
   Although there is support for ed25519 keys as the IPNS identity key and we could potentially
   reuse the same key for the role account and ipns (and make this discovery module obselete)
@@ -41,6 +64,9 @@ use system::ensure_root;
   https://github.com/multiformats/rust-multihash
   https://github.com/multiformats/multicodec/
   https://github.com/multiformats/multihash/
+
+  The identity is validated as a base58 encoded multihash with the supported hash function code
+  and a matching digest length.
 */
 /// base58 encoded IPNS identity multihash codec
 pub type IPNSIdentity = Vec<u8>;
@@ -57,9 +83,53 @@ pub(crate) type StorageWorkingGroup<T> = working_group::Module<T, StorageWorking
 /// Storage provider is a worker from the  working_group module.
 pub type StorageProviderId<T> = working_group::WorkerId<T>;
 
+// The distribution working group instance alias.
+pub(crate) type DistributionWorkingGroupInstance = working_group::Instance4;
+
+// Alias for distribution working group.
+pub(crate) type DistributionWorkingGroup<T> =
+    working_group::Module<T, DistributionWorkingGroupInstance>;
+
+/// Distributor is a worker from the distribution working group.
+pub type DistributorId<T> = working_group::WorkerId<T>;
+
+/// Service provider is a storage provider or a distributor, depending on the service type.
+pub type ServiceProviderId<T> = working_group::WorkerId<T>;
+
 pub(crate) const MINIMUM_LIFETIME: u32 = 600; // 1hr assuming 6s block times
 pub(crate) const DEFAULT_LIFETIME: u32 = MINIMUM_LIFETIME * 24; // 24hr
 
+/// Type of the service provided by the service provider.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceType {
+    /// Stores the content.
+    Storage,
+
+    /// Serves the content to the users.
+    Distributor,
+}
+
+impl Default for ServiceType {
+    fn default() -> Self {
+        ServiceType::Storage
+    }
+}
+
+/// Endpoint of the service provider.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+pub enum ServiceEndpoint {
+    /// HTTP(S) url.
+    Http(Url),
+
+    /// WebSocket url.
+    WebSocket(Url),
+
+    /// libp2p multiaddress in the text form (eg.: /ip4/127.0.0.1/tcp/4001).
+    Libp2p(Vec<u8>),
+}
+
 /// Defines the expiration date for the storage provider.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
@@ -68,12 +138,49 @@ pub struct ServiceProviderRecord<BlockNumber> {
     pub identity: IPNSIdentity,
     /// Block at which information expires.
     pub expires_at: BlockNumber,
+    /// Type of the provided service.
+    pub service_type: ServiceType,
+    /// Endpoints of the service.
+    pub endpoints: Vec<ServiceEndpoint>,
 }
 
 /// The _Service discovery_ main _Trait_.
-pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstance> {
+pub trait Trait:
+    system::Trait
+    + working_group::Trait<StorageWorkingGroupInstance>
+    + working_group::Trait<DistributionWorkingGroupInstance>
+{
     /// _Service discovery_ event type.
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Max number of endpoints per service provider record.
+    type MaxEndpointsPerRecord: Get<u32>;
+
+    /// Max length of the endpoint url or multiaddress.
+    type MaxEndpointLength: Get<u32>;
+
+    /// Max number of expired records pruned per block.
+    type MaxRecordsPrunedPerBlock: Get<u32>;
+}
+
+decl_error! {
+    /// _Service discovery_ module predefined errors
+    pub enum Error for Module<T: Trait> {
+        /// Default lifetime must be greater than or equal to the minimum lifetime.
+        LifetimeTooShort,
+
+        /// IPNS identity is not a base58 encoded multihash.
+        InvalidIpnsIdentity,
+
+        /// Too many endpoints in the service provider record.
+        TooManyEndpoints,
+
+        /// Endpoint exceeds the max length.
+        EndpointTooLong,
+
+        /// Endpoint url or multiaddress is invalid.
+        InvalidEndpoint,
+    }
 }
 
 decl_storage! {
@@ -85,16 +192,30 @@ decl_storage! {
         pub AccountInfoByStorageProviderId get(fn account_info_by_storage_provider_id):
             map hasher(blake2_128_concat) StorageProviderId<T> => ServiceProviderRecord<T::BlockNumber>;
 
+        /// Mapping of the distributor id to its ServiceProviderRecord
+        pub DistributorRecordByDistributorId get(fn distributor_record_by_distributor_id):
+            map hasher(blake2_128_concat) DistributorId<T> => ServiceProviderRecord<T::BlockNumber>;
+
         /// Lifetime of an ServiceProviderRecord record in AccountInfoByAccountId map
         pub DefaultLifetime get(fn default_lifetime) config():
             T::BlockNumber = T::BlockNumber::from(DEFAULT_LIFETIME);
+
+        /// Service types and service provider ids by the first block their records are expired at.
+        /// Used to prune the expired records.
+        pub ExpiringRecordsByBlock get(fn expiring_records_by_block):
+            map hasher(blake2_128_concat) T::BlockNumber => Vec<(ServiceType, ServiceProviderId<T>)>;
+
+        /// Whether the storage provider records contain the service type and the endpoints. Set at
+        /// genesis, the chains upgraded from the identity only records are migrated on runtime upgrade.
+        pub RecordsMigratedToServiceTypes get(fn records_migrated_to_service_types) build(|_| true): bool;
     }
 }
 
 decl_event! {
     /// _Service discovery_ events
     pub enum Event<T> where
-        StorageProviderId = StorageProviderId<T>
+        StorageProviderId = StorageProviderId<T>,
+        DistributorId = DistributorId<T>
        {
         /// Emits on updating of the account info.
         /// Params:
@@ -106,6 +227,17 @@ decl_event! {
         /// Params:
         /// - Id of the storage provider.
         AccountInfoRemoved(StorageProviderId),
+
+        /// Emits on updating of the distributor record.
+        /// Params:
+        /// - Id of the distributor.
+        /// - Id of the IPNS.
+        DistributorRecordUpdated(DistributorId, IPNSIdentity),
+
+        /// Emits on removing of the distributor record.
+        /// Params:
+        /// - Id of the distributor.
+        DistributorRecordRemoved(DistributorId),
     }
 }
 
@@ -115,7 +247,26 @@ decl_module! {
         /// Default deposit_event() handler
         fn deposit_event() = default;
 
+        /// Predefined errors
+        type Error = Error<T>;
+
+        /// Max number of endpoints per service provider record.
+        const MaxEndpointsPerRecord: u32 = T::MaxEndpointsPerRecord::get();
+
+        /// Max length of the endpoint url or multiaddress.
+        const MaxEndpointLength: u32 = T::MaxEndpointLength::get();
+
+        /// Max number of expired records pruned per block.
+        const MaxRecordsPrunedPerBlock: u32 = T::MaxRecordsPrunedPerBlock::get();
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::prune_expired_records(now);
+
+            10_000_000 //TODO: adjust weight
+        }
+
         /// Creates the ServiceProviderRecord to save an IPNS identity for the storage provider.
+        /// Service type and endpoints of the existing record are preserved.
         /// Requires signed storage provider credentials.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn set_ipns_id(
@@ -125,18 +276,44 @@ decl_module! {
         ) {
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
 
-            // TODO: ensure id is a valid base58 encoded IPNS identity
+            ensure!(is_valid_ipns_identity(&id), Error::<T>::InvalidIpnsIdentity);
 
             //
             // == MUTATION SAFE ==
             //
 
-            <AccountInfoByStorageProviderId<T>>::insert(storage_provider_id, ServiceProviderRecord {
-                identity: id.clone(),
-                expires_at: <system::Module<T>>::block_number() + Self::default_lifetime(),
-            });
+            let endpoints =
+                if <AccountInfoByStorageProviderId<T>>::contains_key(storage_provider_id) {
+                    Self::account_info_by_storage_provider_id(storage_provider_id).endpoints
+                } else {
+                    Vec::new()
+                };
 
-            Self::deposit_event(RawEvent::AccountInfoUpdated(storage_provider_id, id));
+            Self::insert_record(ServiceType::Storage, storage_provider_id, id, endpoints);
+        }
+
+        /// Creates the ServiceProviderRecord with the IPNS identity, service type and endpoints
+        /// for the service provider. Requires signed credentials of the storage provider for the
+        /// storage records and of the distributor for the distributor records.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_service_provider_record(
+            origin,
+            service_provider_id: ServiceProviderId<T>,
+            id: IPNSIdentity,
+            service_type: ServiceType,
+            endpoints: Vec<ServiceEndpoint>,
+        ) {
+            Self::ensure_service_provider_signed(origin, service_type, &service_provider_id)?;
+
+            ensure!(is_valid_ipns_identity(&id), Error::<T>::InvalidIpnsIdentity);
+
+            Self::ensure_endpoints_are_valid(&endpoints)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::insert_record(service_type, service_provider_id, id, endpoints);
         }
 
         /// Deletes the ServiceProviderRecord with the IPNS identity for the storage provider.
@@ -147,10 +324,18 @@ decl_module! {
 
             // == MUTATION SAFE ==
 
-            if <AccountInfoByStorageProviderId<T>>::contains_key(storage_provider_id) {
-                <AccountInfoByStorageProviderId<T>>::remove(storage_provider_id);
-                Self::deposit_event(RawEvent::AccountInfoRemoved(storage_provider_id));
-            }
+            Self::remove_record(ServiceType::Storage, &storage_provider_id);
+        }
+
+        /// Deletes the ServiceProviderRecord of the distributor.
+        /// Requires signed distributor credentials.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn unset_distributor_record(origin, distributor_id: DistributorId<T>) {
+            <DistributionWorkingGroup<T>>::ensure_worker_signed(origin, &distributor_id)?;
+
+            // == MUTATION SAFE ==
+
+            Self::remove_record(ServiceType::Distributor, &distributor_id);
         }
 
         // Privileged methods
@@ -159,8 +344,7 @@ decl_module! {
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn set_default_lifetime(origin, lifetime: T::BlockNumber) {
             ensure_root(origin)?;
-            ensure!(lifetime >= T::BlockNumber::from(MINIMUM_LIFETIME),
-                "discovery: default lifetime must be gte minimum lifetime");
+            ensure!(lifetime >= T::BlockNumber::from(MINIMUM_LIFETIME), Error::<T>::LifetimeTooShort);
 
             // == MUTATION SAFE ==
 
@@ -168,10 +352,25 @@ decl_module! {
         }
 
         /// Sets bootstrap endpoints for the Colossus. Requires root privileges.
+        /// Endpoints must be valid HTTP(S) or WebSocket urls.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn set_bootstrap_endpoints(origin, endpoints: Vec<Url>) {
             ensure_root(origin)?;
 
+            ensure!(
+                endpoints.len() <= T::MaxEndpointsPerRecord::get() as usize,
+                Error::<T>::TooManyEndpoints
+            );
+
+            for url in endpoints.iter() {
+                Self::ensure_endpoint_length_is_valid(url)?;
+
+                ensure!(
+                    is_valid_url(url, HTTP_SCHEMES) || is_valid_url(url, WEBSOCKET_SCHEMES),
+                    Error::<T>::InvalidEndpoint
+                );
+            }
+
             // == MUTATION SAFE ==
 
             BootstrapEndpoints::put(endpoints);
@@ -182,8 +381,216 @@ decl_module! {
 impl<T: Trait> Module<T> {
     /// Verifies that account info for the storage provider is still valid.
     pub fn is_account_info_expired(storage_provider_id: &StorageProviderId<T>) -> bool {
-        !<AccountInfoByStorageProviderId<T>>::contains_key(storage_provider_id)
-            || <system::Module<T>>::block_number()
-                > <AccountInfoByStorageProviderId<T>>::get(storage_provider_id).expires_at
+        Self::is_record_expired(ServiceType::Storage, storage_provider_id)
+    }
+
+    /// Verifies that the service provider record of the provided service type is still valid.
+    pub fn is_record_expired(
+        service_type: ServiceType,
+        service_provider_id: &ServiceProviderId<T>,
+    ) -> bool {
+        Self::get_record(service_type, service_provider_id)
+            .map(|record| <system::Module<T>>::block_number() > record.expires_at)
+            .unwrap_or(true)
+    }
+
+    /// Returns all not expired service provider records of the provided service type.
+    pub fn live_service_providers(
+        service_type: ServiceType,
+    ) -> Vec<(ServiceProviderId<T>, ServiceProviderRecord<T::BlockNumber>)> {
+        let now = <system::Module<T>>::block_number();
+
+        let records: Vec<_> = match service_type {
+            ServiceType::Storage => <AccountInfoByStorageProviderId<T>>::iter().collect(),
+            ServiceType::Distributor => <DistributorRecordByDistributorId<T>>::iter().collect(),
+        };
+
+        records
+            .into_iter()
+            .filter(|(_, record)| record.expires_at >= now)
+            .collect()
+    }
+
+    // Ensures the origin is signed by the worker of the working group providing the service type.
+    fn ensure_service_provider_signed(
+        origin: T::Origin,
+        service_type: ServiceType,
+        service_provider_id: &ServiceProviderId<T>,
+    ) -> Result<(), DispatchError> {
+        match service_type {
+            ServiceType::Storage => {
+                <StorageWorkingGroup<T>>::ensure_worker_signed(origin, service_provider_id)?;
+            }
+            ServiceType::Distributor => {
+                <DistributionWorkingGroup<T>>::ensure_worker_signed(origin, service_provider_id)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Returns the service provider record of the provided service type if it exists.
+    fn get_record(
+        service_type: ServiceType,
+        service_provider_id: &ServiceProviderId<T>,
+    ) -> Option<ServiceProviderRecord<T::BlockNumber>> {
+        match service_type {
+            ServiceType::Storage => {
+                if <AccountInfoByStorageProviderId<T>>::contains_key(service_provider_id) {
+                    Some(Self::account_info_by_storage_provider_id(
+                        service_provider_id,
+                    ))
+                } else {
+                    None
+                }
+            }
+            ServiceType::Distributor => {
+                if <DistributorRecordByDistributorId<T>>::contains_key(service_provider_id) {
+                    Some(Self::distributor_record_by_distributor_id(
+                        service_provider_id,
+                    ))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    // Saves the service provider record with the default lifetime.
+    fn insert_record(
+        service_type: ServiceType,
+        service_provider_id: ServiceProviderId<T>,
+        identity: IPNSIdentity,
+        endpoints: Vec<ServiceEndpoint>,
+    ) {
+        let expires_at = <system::Module<T>>::block_number() + Self::default_lifetime();
+
+        let record = ServiceProviderRecord {
+            identity: identity.clone(),
+            expires_at,
+            service_type,
+            endpoints,
+        };
+
+        match service_type {
+            ServiceType::Storage => {
+                <AccountInfoByStorageProviderId<T>>::insert(service_provider_id, record);
+
+                Self::deposit_event(RawEvent::AccountInfoUpdated(service_provider_id, identity));
+            }
+            ServiceType::Distributor => {
+                <DistributorRecordByDistributorId<T>>::insert(service_provider_id, record);
+
+                Self::deposit_event(RawEvent::DistributorRecordUpdated(
+                    service_provider_id,
+                    identity,
+                ));
+            }
+        }
+
+        <ExpiringRecordsByBlock<T>>::mutate(expires_at + One::one(), |ids| {
+            ids.push((service_type, service_provider_id))
+        });
+    }
+
+    // Removes the service provider record if it exists.
+    fn remove_record(service_type: ServiceType, service_provider_id: &ServiceProviderId<T>) {
+        match service_type {
+            ServiceType::Storage => {
+                if <AccountInfoByStorageProviderId<T>>::contains_key(service_provider_id) {
+                    <AccountInfoByStorageProviderId<T>>::remove(service_provider_id);
+                    Self::deposit_event(RawEvent::AccountInfoRemoved(*service_provider_id));
+                }
+            }
+            ServiceType::Distributor => {
+                if <DistributorRecordByDistributorId<T>>::contains_key(service_provider_id) {
+                    <DistributorRecordByDistributorId<T>>::remove(service_provider_id);
+                    Self::deposit_event(RawEvent::DistributorRecordRemoved(*service_provider_id));
+                }
+            }
+        }
+    }
+
+    // Removes the records expiring at the provided block. The records above the per block
+    // limit are postponed to the next block. Refreshed records are kept.
+    fn prune_expired_records(now: T::BlockNumber) {
+        let mut expiring_records = <ExpiringRecordsByBlock<T>>::take(now);
+
+        let limit = T::MaxRecordsPrunedPerBlock::get() as usize;
+        if expiring_records.len() > limit {
+            let postponed_records = expiring_records.split_off(limit);
+
+            <ExpiringRecordsByBlock<T>>::mutate(now + One::one(), |records| {
+                records.extend(postponed_records)
+            });
+        }
+
+        for (service_type, service_provider_id) in expiring_records {
+            if Self::is_record_expired(service_type, &service_provider_id) {
+                Self::remove_record(service_type, &service_provider_id);
+            }
+        }
+    }
+
+    fn ensure_endpoint_length_is_valid(endpoint: &[u8]) -> Result<(), Error<T>> {
+        ensure!(
+            endpoint.len() <= T::MaxEndpointLength::get() as usize,
+            Error::<T>::EndpointTooLong
+        );
+
+        Ok(())
+    }
+
+    fn ensure_endpoints_are_valid(endpoints: &[ServiceEndpoint]) -> Result<(), Error<T>> {
+        ensure!(
+            endpoints.len() <= T::MaxEndpointsPerRecord::get() as usize,
+            Error::<T>::TooManyEndpoints
+        );
+
+        for endpoint in endpoints {
+            let is_valid = match endpoint {
+                ServiceEndpoint::Http(url) => {
+                    Self::ensure_endpoint_length_is_valid(url)?;
+                    is_valid_url(url, HTTP_SCHEMES)
+                }
+                ServiceEndpoint::WebSocket(url) => {
+                    Self::ensure_endpoint_length_is_valid(url)?;
+                    is_valid_url(url, WEBSOCKET_SCHEMES)
+                }
+                ServiceEndpoint::Libp2p(multiaddr) => {
+                    Self::ensure_endpoint_length_is_valid(multiaddr)?;
+                    is_valid_url(multiaddr, MULTIADDR_PREFIXES)
+                }
+            };
+
+            ensure!(is_valid, Error::<T>::InvalidEndpoint);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Trait> working_group::WorkerExitHandler<T, StorageWorkingGroupInstance> for Module<T> {
+    fn worker_exited(storage_provider_id: &StorageProviderId<T>) {
+        Self::remove_record(ServiceType::Storage, storage_provider_id);
+    }
+}
+
+impl<T: Trait> working_group::WorkerExitHandler<T, DistributionWorkingGroupInstance> for Module<T> {
+    fn worker_exited(distributor_id: &DistributorId<T>) {
+        Self::remove_record(ServiceType::Distributor, distributor_id);
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API to resolve the live service providers.
+    pub trait ServiceDiscoveryApi<ServiceProviderId, BlockNumber> where
+        ServiceProviderId: codec::Codec,
+        BlockNumber: codec::Codec,
+    {
+        /// Returns all not expired service provider records of the provided service type.
+        fn live_service_providers(
+            service_type: ServiceType,
+        ) -> Vec<(ServiceProviderId, ServiceProviderRecord<BlockNumber>)>;
     }
 }
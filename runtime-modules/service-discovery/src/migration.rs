@@ -0,0 +1,45 @@
+use crate::*;
+use frame_support::storage::IterableStorageMap;
+use frame_support::StorageValue;
+
+// Service provider record in the format preceding the service types and endpoints.
+#[derive(Decode)]
+struct LegacyServiceProviderRecord<BlockNumber> {
+    identity: IPNSIdentity,
+    expires_at: BlockNumber,
+}
+
+impl<T: Trait> Module<T> {
+    /// Converts the existing storage provider records to the layout with the service type and
+    /// the endpoints. The migrated records provide the storage service and have no endpoints.
+    /// The records are indexed by their expiration blocks to be pruned, the already expired ones
+    /// are pruned in the next block.
+    /// Does nothing if the records were already migrated. Should be called on the runtime upgrade.
+    pub fn migrate_records_to_service_types() {
+        if Self::records_migrated_to_service_types() {
+            return;
+        }
+
+        <AccountInfoByStorageProviderId<T>>::translate(
+            |_, record: LegacyServiceProviderRecord<T::BlockNumber>| {
+                Some(ServiceProviderRecord {
+                    identity: record.identity,
+                    expires_at: record.expires_at,
+                    service_type: ServiceType::Storage,
+                    endpoints: Vec::new(),
+                })
+            },
+        );
+
+        let next_block = <system::Module<T>>::block_number() + One::one();
+        for (storage_provider_id, record) in <AccountInfoByStorageProviderId<T>>::iter() {
+            let prune_at = (record.expires_at + One::one()).max(next_block);
+
+            <ExpiringRecordsByBlock<T>>::mutate(prune_at, |records| {
+                records.push((ServiceType::Storage, storage_provider_id))
+            });
+        }
+
+        RecordsMigratedToServiceTypes::put(true);
+    }
+}
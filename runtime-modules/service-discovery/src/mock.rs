@@ -2,6 +2,7 @@
 
 pub use crate::*;
 
+use frame_support::traits::{OnFinalize, OnInitialize};
 use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
 use sp_core::H256;
 use sp_runtime::{
@@ -13,8 +14,11 @@ use sp_runtime::{
 // The storage working group instance alias.
 pub type StorageWorkingGroupInstance = working_group::Instance2;
 
+// The distribution working group instance alias.
+pub type DistributionWorkingGroupInstance = working_group::Instance4;
+
 mod working_group_mod {
-    pub use super::StorageWorkingGroupInstance;
+    pub use super::{DistributionWorkingGroupInstance, StorageWorkingGroupInstance};
     pub use working_group::Event;
     pub use working_group::Trait;
 }
@@ -37,6 +41,7 @@ impl_outer_event! {
         balances<T>,
         membership_mod<T>,
         working_group_mod StorageWorkingGroupInstance <T>,
+        working_group_mod DistributionWorkingGroupInstance <T>,
         system<T>,
     }
 }
@@ -81,8 +86,17 @@ impl system::Trait for Test {
     type OnKilledAccount = ();
 }
 
+parameter_types! {
+    pub const MaxEndpointsPerRecord: u32 = 3;
+    pub const MaxEndpointLength: u32 = 64;
+    pub const MaxRecordsPrunedPerBlock: u32 = 2;
+}
+
 impl Trait for Test {
     type Event = MetaEvent;
+    type MaxEndpointsPerRecord = MaxEndpointsPerRecord;
+    type MaxEndpointLength = MaxEndpointLength;
+    type MaxRecordsPrunedPerBlock = MaxRecordsPrunedPerBlock;
 }
 
 impl hiring::Trait for Test {
@@ -138,6 +152,13 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WorkerExitHandler = Discovery;
+}
+
+impl working_group::Trait<DistributionWorkingGroupInstance> for Test {
+    type Event = MetaEvent;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WorkerExitHandler = Discovery;
 }
 
 impl pallet_timestamp::Trait for Test {
@@ -158,9 +179,15 @@ pub type Balances = balances::Module<Test>;
 pub type System = system::Module<Test>;
 pub type Discovery = Module<Test>;
 
+// Valid base58 encoded sha2-256 multihash.
+pub(crate) const TEST_IPNS_IDENTITY: &str = "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N";
+
 pub(crate) fn hire_storage_provider() -> (u64, u64) {
-    let storage_provider_id = 1;
-    let role_account_id = 1;
+    hire_storage_provider_with_id(1)
+}
+
+pub(crate) fn hire_storage_provider_with_id(storage_provider_id: u64) -> (u64, u64) {
+    let role_account_id = storage_provider_id;
 
     let storage_provider = working_group::Worker {
         member_id: 1,
@@ -176,3 +203,31 @@ pub(crate) fn hire_storage_provider() -> (u64, u64) {
 
     (role_account_id, storage_provider_id)
 }
+
+pub(crate) fn hire_distributor_with_id(distributor_id: u64, role_account_id: u64) -> (u64, u64) {
+    let distributor = working_group::Worker {
+        member_id: 1,
+        role_account_id,
+        reward_relationship: None,
+        role_stake_profile: None,
+    };
+
+    <working_group::WorkerById<Test, DistributionWorkingGroupInstance>>::insert(
+        distributor_id,
+        distributor,
+    );
+
+    (role_account_id, distributor_id)
+}
+
+// Recommendation from Parity on testing on_finalize
+// https://substrate.dev/docs/en/next/development/module/tests
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        <System as OnFinalize<u64>>::on_finalize(System::block_number());
+        <Discovery as OnFinalize<u64>>::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+        <System as OnInitialize<u64>>::on_initialize(System::block_number());
+        <Discovery as OnInitialize<u64>>::on_initialize(System::block_number());
+    }
+}
@@ -2,6 +2,8 @@
 
 use super::mock::*;
 
+use frame_support::storage::unhashed;
+use frame_support::{StorageMap, StorageValue};
 use system::{EventRecord, Phase, RawOrigin};
 
 #[test]
@@ -12,7 +14,7 @@ fn set_ipns_id() {
 
         let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
 
-        let identity = "alice".as_bytes().to_vec();
+        let identity = TEST_IPNS_IDENTITY.as_bytes().to_vec();
         let ttl = <Test as system::Trait>::BlockNumber::from(DEFAULT_LIFETIME);
         assert!(Discovery::set_ipns_id(
             Origin::signed(storage_provider_account_id),
@@ -29,7 +31,9 @@ fn set_ipns_id() {
             account_info,
             ServiceProviderRecord {
                 identity: identity.clone(),
-                expires_at: current_block_number + ttl
+                expires_at: current_block_number + ttl,
+                service_type: ServiceType::Storage,
+                endpoints: Vec::new(),
             }
         );
 
@@ -73,6 +77,7 @@ fn unset_ipns_id() {
             ServiceProviderRecord {
                 expires_at: 1000,
                 identity: "alice".as_bytes().to_vec(),
+                ..Default::default()
             },
         );
 
@@ -123,6 +128,7 @@ fn is_account_info_expired() {
             ServiceProviderRecord {
                 expires_at,
                 identity: id.clone(),
+                ..Default::default()
             },
         );
 
@@ -163,7 +169,7 @@ fn set_default_lifetime() {
 #[test]
 fn set_bootstrap_endpoints() {
     initial_test_ext().execute_with(|| {
-        let endpoints = vec!["endpoint1".as_bytes().to_vec()];
+        let endpoints = vec!["https://endpoint1".as_bytes().to_vec()];
         // privileged method should fail if not from root origin
         assert!(
             Discovery::set_bootstrap_endpoints(Origin::signed(1), endpoints.clone()).is_err(),
@@ -176,3 +182,435 @@ fn set_bootstrap_endpoints() {
         assert_eq!(Discovery::bootstrap_endpoints(), endpoints, "");
     });
 }
+
+#[test]
+fn set_bootstrap_endpoints_fails_with_invalid_url() {
+    initial_test_ext().execute_with(|| {
+        let endpoints = vec!["endpoint1".as_bytes().to_vec()];
+        assert_eq!(
+            Discovery::set_bootstrap_endpoints(RawOrigin::Root.into(), endpoints),
+            Err(Error::<Test>::InvalidEndpoint.into())
+        );
+    });
+}
+
+#[test]
+fn set_ipns_id_fails_with_invalid_identity() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        assert_eq!(
+            Discovery::set_ipns_id(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                "alice".as_bytes().to_vec(),
+            ),
+            Err(Error::<Test>::InvalidIpnsIdentity.into())
+        );
+    });
+}
+
+#[test]
+fn set_service_provider_record() {
+    initial_test_ext().execute_with(|| {
+        let current_block_number = 1000;
+        System::set_block_number(current_block_number);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let identity = TEST_IPNS_IDENTITY.as_bytes().to_vec();
+        let endpoints = vec![
+            ServiceEndpoint::Http(b"https://storage.joystream.org".to_vec()),
+            ServiceEndpoint::WebSocket(b"wss://storage.joystream.org".to_vec()),
+            ServiceEndpoint::Libp2p(b"/ip4/127.0.0.1/tcp/4001".to_vec()),
+        ];
+
+        assert_eq!(
+            Discovery::set_service_provider_record(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                identity.clone(),
+                ServiceType::Storage,
+                endpoints.clone(),
+            ),
+            Ok(())
+        );
+
+        let record = Discovery::account_info_by_storage_provider_id(&storage_provider_id);
+        assert_eq!(record.service_type, ServiceType::Storage);
+        assert_eq!(record.endpoints, endpoints);
+
+        // Refreshing the IPNS id preserves the endpoints.
+        assert_eq!(
+            Discovery::set_ipns_id(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                identity.clone(),
+            ),
+            Ok(())
+        );
+
+        let record = Discovery::account_info_by_storage_provider_id(&storage_provider_id);
+        assert_eq!(record.service_type, ServiceType::Storage);
+        assert_eq!(record.endpoints, endpoints);
+
+        // Storage provider is not a distributor.
+        assert!(Discovery::set_service_provider_record(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            identity.clone(),
+            ServiceType::Distributor,
+            endpoints.clone(),
+        )
+        .is_err());
+        assert!(!<DistributorRecordByDistributorId<Test>>::contains_key(
+            storage_provider_id
+        ));
+    });
+}
+
+#[test]
+fn set_distributor_record() {
+    initial_test_ext().execute_with(|| {
+        let current_block_number = 1000;
+        System::set_block_number(current_block_number);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        // Distributor with the same worker id as the storage provider.
+        let (distributor_account_id, distributor_id) =
+            hire_distributor_with_id(storage_provider_id, 10);
+
+        let identity = TEST_IPNS_IDENTITY.as_bytes().to_vec();
+        let endpoints = vec![ServiceEndpoint::Http(
+            b"https://distributor.joystream.org".to_vec(),
+        )];
+
+        assert_eq!(
+            Discovery::set_ipns_id(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                identity.clone(),
+            ),
+            Ok(())
+        );
+
+        // Distributor records are authorized by the distribution working group.
+        assert!(Discovery::set_service_provider_record(
+            Origin::signed(storage_provider_account_id),
+            distributor_id,
+            identity.clone(),
+            ServiceType::Distributor,
+            endpoints.clone(),
+        )
+        .is_err());
+
+        assert_eq!(
+            Discovery::set_service_provider_record(
+                Origin::signed(distributor_account_id),
+                distributor_id,
+                identity.clone(),
+                ServiceType::Distributor,
+                endpoints.clone(),
+            ),
+            Ok(())
+        );
+
+        let record = Discovery::distributor_record_by_distributor_id(&distributor_id);
+        assert_eq!(record.service_type, ServiceType::Distributor);
+        assert_eq!(record.endpoints, endpoints);
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::Initialization,
+                event: MetaEvent::discovery(RawEvent::DistributorRecordUpdated(
+                    distributor_id,
+                    identity
+                )),
+                topics: vec![]
+            }
+        );
+
+        // The storage provider record with the same id is kept.
+        let record = Discovery::account_info_by_storage_provider_id(&storage_provider_id);
+        assert_eq!(record.service_type, ServiceType::Storage);
+
+        assert_eq!(
+            Discovery::unset_distributor_record(
+                Origin::signed(distributor_account_id),
+                distributor_id
+            ),
+            Ok(())
+        );
+        assert!(!<DistributorRecordByDistributorId<Test>>::contains_key(
+            distributor_id
+        ));
+        assert!(<AccountInfoByStorageProviderId<Test>>::contains_key(
+            storage_provider_id
+        ));
+    });
+}
+
+#[test]
+fn set_service_provider_record_fails_with_invalid_endpoints() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+        let identity = TEST_IPNS_IDENTITY.as_bytes().to_vec();
+
+        let set_record = |endpoints: Vec<ServiceEndpoint>| {
+            Discovery::set_service_provider_record(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                identity.clone(),
+                ServiceType::Storage,
+                endpoints,
+            )
+        };
+
+        assert_eq!(
+            set_record(vec![ServiceEndpoint::Http(b"wss://storage".to_vec())]),
+            Err(Error::<Test>::InvalidEndpoint.into())
+        );
+
+        assert_eq!(
+            set_record(vec![ServiceEndpoint::Libp2p(b"ip4/127.0.0.1".to_vec())]),
+            Err(Error::<Test>::InvalidEndpoint.into())
+        );
+
+        let too_long_url = [b"https://".to_vec(), vec![b'a'; 64]].concat();
+        assert_eq!(
+            set_record(vec![ServiceEndpoint::Http(too_long_url)]),
+            Err(Error::<Test>::EndpointTooLong.into())
+        );
+
+        assert_eq!(
+            set_record(vec![ServiceEndpoint::Http(b"https://storage".to_vec()); 4]),
+            Err(Error::<Test>::TooManyEndpoints.into())
+        );
+    });
+}
+
+#[test]
+fn live_service_providers() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(100);
+
+        let storage_record = ServiceProviderRecord {
+            identity: TEST_IPNS_IDENTITY.as_bytes().to_vec(),
+            expires_at: 1000,
+            service_type: ServiceType::Storage,
+            endpoints: Vec::new(),
+        };
+        let distributor_record = ServiceProviderRecord {
+            service_type: ServiceType::Distributor,
+            ..storage_record.clone()
+        };
+        let expired_record = ServiceProviderRecord {
+            expires_at: 10,
+            ..storage_record.clone()
+        };
+
+        <AccountInfoByStorageProviderId<Test>>::insert(1, storage_record.clone());
+        <DistributorRecordByDistributorId<Test>>::insert(2, distributor_record.clone());
+        <AccountInfoByStorageProviderId<Test>>::insert(3, expired_record);
+
+        assert_eq!(
+            Discovery::live_service_providers(ServiceType::Storage),
+            vec![(1, storage_record)]
+        );
+        assert_eq!(
+            Discovery::live_service_providers(ServiceType::Distributor),
+            vec![(2, distributor_record)]
+        );
+    });
+}
+
+#[test]
+fn expired_records_are_pruned_in_batches() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let storage_provider_ids = vec![1, 2, 3];
+        for storage_provider_id in storage_provider_ids.iter() {
+            let (account_id, _) = hire_storage_provider_with_id(*storage_provider_id);
+
+            assert_eq!(
+                Discovery::set_ipns_id(
+                    Origin::signed(account_id),
+                    *storage_provider_id,
+                    TEST_IPNS_IDENTITY.as_bytes().to_vec(),
+                ),
+                Ok(())
+            );
+        }
+
+        // Refresh the last record, it should survive the pruning.
+        let refresh_block = 100;
+        run_to_block(refresh_block);
+        assert_eq!(
+            Discovery::set_ipns_id(Origin::signed(3), 3, TEST_IPNS_IDENTITY.as_bytes().to_vec(),),
+            Ok(())
+        );
+
+        let expires_at = 1 + Discovery::default_lifetime();
+        run_to_block(expires_at);
+        for storage_provider_id in storage_provider_ids.iter() {
+            assert!(<AccountInfoByStorageProviderId<Test>>::contains_key(
+                storage_provider_id
+            ));
+        }
+
+        // Only two records are checked per block in the mock, the third one is postponed.
+        run_to_block(expires_at + 1);
+        assert_eq!(
+            Discovery::expiring_records_by_block(expires_at + 2),
+            vec![(ServiceType::Storage, 3)]
+        );
+
+        assert!(!<AccountInfoByStorageProviderId<Test>>::contains_key(1));
+        assert!(!<AccountInfoByStorageProviderId<Test>>::contains_key(2));
+        assert!(<AccountInfoByStorageProviderId<Test>>::contains_key(3));
+
+        assert!(System::events()
+            .iter()
+            .any(|record| record.event == MetaEvent::discovery(RawEvent::AccountInfoRemoved(1))));
+        assert!(System::events()
+            .iter()
+            .any(|record| record.event == MetaEvent::discovery(RawEvent::AccountInfoRemoved(2))));
+
+        // The refreshed record is pruned after its own expiration.
+        run_to_block(refresh_block + Discovery::default_lifetime() + 1);
+        assert!(!<AccountInfoByStorageProviderId<Test>>::contains_key(3));
+    });
+}
+
+#[test]
+fn record_is_removed_when_storage_provider_leaves() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        assert_eq!(
+            Discovery::set_ipns_id(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                TEST_IPNS_IDENTITY.as_bytes().to_vec(),
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            working_group::Module::<Test, StorageWorkingGroupInstance>::leave_role(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                b"rationale".to_vec(),
+            ),
+            Ok(())
+        );
+
+        assert!(!<AccountInfoByStorageProviderId<Test>>::contains_key(
+            storage_provider_id
+        ));
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::discovery(RawEvent::AccountInfoRemoved(storage_provider_id))));
+    });
+}
+
+#[test]
+fn record_is_removed_when_distributor_leaves() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (distributor_account_id, distributor_id) = hire_distributor_with_id(1, 10);
+
+        assert_eq!(
+            Discovery::set_service_provider_record(
+                Origin::signed(distributor_account_id),
+                distributor_id,
+                TEST_IPNS_IDENTITY.as_bytes().to_vec(),
+                ServiceType::Distributor,
+                Vec::new(),
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            working_group::Module::<Test, DistributionWorkingGroupInstance>::leave_role(
+                Origin::signed(distributor_account_id),
+                distributor_id,
+                b"rationale".to_vec(),
+            ),
+            Ok(())
+        );
+
+        assert!(!<DistributorRecordByDistributorId<Test>>::contains_key(
+            distributor_id
+        ));
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::discovery(RawEvent::DistributorRecordRemoved(distributor_id))));
+    });
+}
+
+#[test]
+fn records_migration_works() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(100);
+
+        let storage_provider_id = 1u64;
+        let expired_storage_provider_id = 2u64;
+        let identity = TEST_IPNS_IDENTITY.as_bytes().to_vec();
+
+        // Records in the layout without the service type and the endpoints.
+        unhashed::put(
+            &<AccountInfoByStorageProviderId<Test>>::hashed_key_for(storage_provider_id),
+            &(identity.clone(), 1000u64),
+        );
+        unhashed::put(
+            &<AccountInfoByStorageProviderId<Test>>::hashed_key_for(expired_storage_provider_id),
+            &(identity.clone(), 10u64),
+        );
+
+        // nothing happens on the migrated chains
+        Discovery::migrate_records_to_service_types();
+        assert_eq!(
+            unhashed::get::<(Vec<u8>, u64)>(
+                &<AccountInfoByStorageProviderId<Test>>::hashed_key_for(storage_provider_id)
+            ),
+            Some((identity.clone(), 1000))
+        );
+
+        RecordsMigratedToServiceTypes::put(false);
+        Discovery::migrate_records_to_service_types();
+
+        assert!(Discovery::records_migrated_to_service_types());
+        assert_eq!(
+            Discovery::account_info_by_storage_provider_id(storage_provider_id),
+            ServiceProviderRecord {
+                identity,
+                expires_at: 1000,
+                service_type: ServiceType::Storage,
+                endpoints: Vec::new(),
+            }
+        );
+
+        // The migrated records are pruned after the expiration.
+        assert_eq!(
+            Discovery::expiring_records_by_block(1001),
+            vec![(ServiceType::Storage, storage_provider_id)]
+        );
+        assert_eq!(
+            Discovery::expiring_records_by_block(101),
+            vec![(ServiceType::Storage, expired_storage_provider_id)]
+        );
+
+        run_to_block(101);
+        assert!(!<AccountInfoByStorageProviderId<Test>>::contains_key(
+            expired_storage_provider_id
+        ));
+        assert!(<AccountInfoByStorageProviderId<Test>>::contains_key(
+            storage_provider_id
+        ));
+    });
+}
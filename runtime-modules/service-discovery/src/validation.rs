@@ -0,0 +1,111 @@
+//! Validation of the service provider records: IPNS identities and endpoints.
+
+use sp_std::vec::Vec;
+
+// Bitcoin base58 alphabet used by IPFS.
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+// Supported multihash function codes: identity, sha2-256, sha2-512, blake2b-256.
+const SUPPORTED_MULTIHASH_CODES: [u64; 4] = [0x00, 0x12, 0x13, 0xb220];
+
+// Max digest length of the supported multihashes (identity hash of the inlined public key).
+const MAX_DIGEST_LENGTH: u64 = 64;
+
+// Max length of the base58 encoded IPNS identity.
+const MAX_IPNS_IDENTITY_LENGTH: usize = 128;
+
+/// Allowed HTTP url schemes.
+pub const HTTP_SCHEMES: &[&[u8]] = &[b"http://", b"https://"];
+
+/// Allowed WebSocket url schemes.
+pub const WEBSOCKET_SCHEMES: &[&[u8]] = &[b"ws://", b"wss://"];
+
+/// Text form of the multiaddress starts with the protocol separator.
+pub const MULTIADDR_PREFIXES: &[&[u8]] = &[b"/"];
+
+/// Verifies that the identity is a base58 encoded multihash with the supported hash function.
+pub fn is_valid_ipns_identity(identity: &[u8]) -> bool {
+    if identity.is_empty() || identity.len() > MAX_IPNS_IDENTITY_LENGTH {
+        return false;
+    }
+
+    let bytes = match decode_base58(identity) {
+        Some(bytes) => bytes,
+        None => return false,
+    };
+
+    let (code, code_length) = match decode_varint(&bytes) {
+        Some(result) => result,
+        None => return false,
+    };
+
+    let (digest_length, digest_length_length) = match decode_varint(&bytes[code_length..]) {
+        Some(result) => result,
+        None => return false,
+    };
+
+    SUPPORTED_MULTIHASH_CODES.contains(&code)
+        && digest_length <= MAX_DIGEST_LENGTH
+        && bytes.len() as u64 == (code_length + digest_length_length) as u64 + digest_length
+}
+
+/// Verifies that the url starts with one of the allowed prefixes, has a non-empty remainder
+/// and contains only printable ASCII characters.
+pub fn is_valid_url(url: &[u8], allowed_prefixes: &[&[u8]]) -> bool {
+    let prefix_length = match allowed_prefixes
+        .iter()
+        .find(|prefix| url.starts_with(prefix))
+    {
+        Some(prefix) => prefix.len(),
+        None => return false,
+    };
+
+    url.len() > prefix_length && url.iter().all(|c| c.is_ascii_graphic())
+}
+
+// Decodes the base58 string. Returns None on invalid characters.
+fn decode_base58(input: &[u8]) -> Option<Vec<u8>> {
+    // Little-endian big number.
+    let mut bytes: Vec<u8> = Vec::new();
+
+    for c in input {
+        let mut carry = BASE58_ALPHABET.iter().position(|a| a == c)? as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    // Leading '1' characters encode the leading zero bytes.
+    let leading_zeros = input
+        .iter()
+        .take_while(|c| **c == BASE58_ALPHABET[0])
+        .count();
+    bytes.extend(sp_std::iter::repeat(0).take(leading_zeros));
+
+    bytes.reverse();
+
+    Some(bytes)
+}
+
+// Decodes the unsigned varint. Returns the value and the number of the consumed bytes.
+fn decode_varint(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+
+    for (index, byte) in bytes.iter().enumerate().take(9) {
+        value |= ((byte & 0x7f) as u64) << (7 * index);
+
+        if byte & 0x80 == 0 {
+            return Some((value, index + 1));
+        }
+    }
+
+    None
+}
@@ -12,7 +12,7 @@
 //! - [add_content](./struct.Module.html#method.add_content) - Adds the content to the system.
 //!
 //! ### Private extrinsics
-//! - accept_content - Storage provider accepts a content.
+//! - accept_content - Storage provider accepts a content and commits to its hash.
 //! - reject_content - Storage provider rejects a content.
 //! - remove_known_content_id - Removes the content id from the list of known content ids. Requires root privileges.
 //! - set_known_content_id - Sets the content id from the list of known content ids. Requires root privileges.
@@ -25,7 +25,7 @@ use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResult;
 use frame_support::traits::Get;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
-use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_runtime::traits::{Hash, MaybeSerialize, Member};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::vec::Vec;
 use system::ensure_root;
@@ -85,7 +85,13 @@ decl_error! {
         RequireRootOrigin,
 
         /// DataObject Injection Failed. Too Many DataObjects.
-        DataObjectsInjectionExceededLimit
+        DataObjectsInjectionExceededLimit,
+
+        /// Content hash provided by the liaison doesn't match the data object content hash.
+        ContentHashMismatch,
+
+        /// Chunk manifest must have a non-zero chunk size.
+        InvalidChunkManifest,
     }
 }
 
@@ -109,6 +115,40 @@ impl Default for LiaisonJudgement {
     }
 }
 
+/// Prefix of the Merkle tree leaves, hashed together with the chunk data.
+pub const CHUNK_LEAF_PREFIX: u8 = 0;
+
+/// Prefix of the Merkle tree inner nodes, hashed together with the (left, right) pair.
+pub const CHUNK_NODE_PREFIX: u8 = 1;
+
+/// Describes the content split into fixed-size chunks and the Merkle root of the chunk hashes.
+/// Leaves are the hashes of the (CHUNK_LEAF_PREFIX, chunk) pairs, inner nodes are the hashes
+/// of the (CHUNK_NODE_PREFIX, left, right) triples. A node without a sibling on its level is
+/// paired with itself, so every leaf is at the depth of ceil(log2(chunks_count)).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Debug)]
+pub struct ChunkManifest<Hash> {
+    /// Merkle root of the content chunks.
+    pub merkle_root: Hash,
+
+    /// Chunk size in bytes. The last chunk can be smaller.
+    pub chunk_size: u64,
+}
+
+impl<Hash> ChunkManifest<Hash> {
+    /// Returns the number of chunks for the content of the provided size.
+    pub fn chunks_count(&self, content_size: u64) -> u64 {
+        if self.chunk_size == 0 {
+            return 0;
+        }
+
+        content_size / self.chunk_size + (content_size % self.chunk_size != 0) as u64
+    }
+}
+
+/// Alias for the chunk manifest.
+pub type ContentChunkManifest<T> = ChunkManifest<<T as system::Trait>::Hash>;
+
 /// Alias for DataObjectInternal
 pub type DataObject<T> = DataObjectInternal<
     MemberId<T>,
@@ -116,12 +156,20 @@ pub type DataObject<T> = DataObjectInternal<
     <T as pallet_timestamp::Trait>::Moment,
     <T as data_object_type_registry::Trait>::DataObjectTypeId,
     StorageProviderId<T>,
+    <T as system::Trait>::Hash,
 >;
 
 /// Manages content ids, type and storage provider decision about it.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, PartialEq, Debug)]
-pub struct DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, StorageProviderId> {
+pub struct DataObjectInternal<
+    MemberId,
+    BlockNumber,
+    Moment,
+    DataObjectTypeId,
+    StorageProviderId,
+    Hash,
+> {
     /// Content owner.
     pub owner: MemberId,
 
@@ -142,6 +190,12 @@ pub struct DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, S
 
     /// IPFS content id.
     pub ipfs_content_id: Vec<u8>,
+
+    /// Hash of the whole content (blake2-256 in the Joystream runtime).
+    pub content_hash: Hash,
+
+    /// Optional chunked representation of the content used for the possession proofs.
+    pub chunk_manifest: Option<ChunkManifest<Hash>>,
 }
 
 /// A map collection of unique DataObjects keyed by the ContentId
@@ -155,6 +209,10 @@ decl_storage! {
         /// Maps data objects by their content id.
         pub DataObjectByContentId get(fn data_object_by_content_id) config():
             map hasher(blake2_128_concat) T::ContentId => Option<DataObject<T>>;
+
+        /// Whether the data objects contain the content hash and the chunk manifest. Set at genesis,
+        /// the chains upgraded from the unhashed data objects are migrated on runtime upgrade.
+        pub DataObjectsHashed get(fn data_objects_hashed) build(|_| true): bool;
     }
 }
 
@@ -162,6 +220,7 @@ decl_event! {
     /// _Data directory_ events
     pub enum Event<T> where
         <T as Trait>::ContentId,
+        <T as system::Trait>::Hash,
         MemberId = MemberId<T>,
         StorageProviderId = StorageProviderId<T>
     {
@@ -175,7 +234,8 @@ decl_event! {
         /// Params:
         /// - Id of the relationship.
         /// - Id of the storage provider.
-        ContentAccepted(ContentId, StorageProviderId),
+        /// - Content hash committed by the storage provider.
+        ContentAccepted(ContentId, StorageProviderId, Hash),
 
         /// Emits when the storage provider rejects a content.
         /// Params:
@@ -198,7 +258,8 @@ decl_module! {
         const MaxObjectsPerInjection: u32 = T::MaxObjectsPerInjection::get();
 
         /// Adds the content to the system. Member id should match its origin. The created DataObject
-        /// awaits liaison to accept or reject it.
+        /// awaits liaison to accept or reject it. The content hash and the optional chunk manifest
+        /// are used to verify the content accepted by the liaison and stored by the providers.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn add_content(
             origin,
@@ -206,7 +267,9 @@ decl_module! {
             content_id: T::ContentId,
             type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
             size: u64,
-            ipfs_content_id: Vec<u8>
+            ipfs_content_id: Vec<u8>,
+            content_hash: T::Hash,
+            chunk_manifest: Option<ContentChunkManifest<T>>
         ) {
             T::MemberOriginValidator::ensure_actor_origin(
                 origin,
@@ -219,6 +282,10 @@ decl_module! {
             ensure!(!<DataObjectByContentId<T>>::contains_key(content_id),
                 Error::<T>::DataObjectAlreadyAdded);
 
+            if let Some(ref manifest) = chunk_manifest {
+                ensure!(manifest.chunk_size > 0, Error::<T>::InvalidChunkManifest);
+            }
+
             let liaison = T::StorageProviderHelper::get_random_storage_provider()?;
 
             // Let's create the entry then
@@ -230,6 +297,8 @@ decl_module! {
                 liaison,
                 liaison_judgement: LiaisonJudgement::Pending,
                 ipfs_content_id,
+                content_hash,
+                chunk_manifest,
             };
 
             //
@@ -241,22 +310,29 @@ decl_module! {
         }
 
         /// Storage provider accepts a content. Requires signed storage provider account and its id.
+        /// The liaison commits to the content by providing its hash, which must match the hash
+        /// declared by the content owner.
         /// The LiaisonJudgement can be updated, but only by the liaison.
         #[weight = 10_000_000] // TODO: adjust weight
         pub(crate) fn accept_content(
             origin,
             storage_provider_id: StorageProviderId<T>,
-            content_id: T::ContentId
+            content_id: T::ContentId,
+            content_hash: T::Hash
         ) {
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
 
+            let data = Self::data_object_by_content_id(&content_id).ok_or(Error::<T>::CidNotFound)?;
+
+            ensure!(data.content_hash == content_hash, Error::<T>::ContentHashMismatch);
+
             // == MUTATION SAFE ==
 
             Self::update_content_judgement(&storage_provider_id, content_id, LiaisonJudgement::Accepted)?;
 
             <KnownContentIds<T>>::mutate(|ids| ids.push(content_id));
 
-            Self::deposit_event(RawEvent::ContentAccepted(content_id, storage_provider_id));
+            Self::deposit_event(RawEvent::ContentAccepted(content_id, storage_provider_id, content_hash));
         }
 
         /// Storage provider rejects a content. Requires signed storage provider account and its id.
@@ -339,6 +415,47 @@ impl<T: Trait> Module<T> {
 
         Ok(())
     }
+
+    /// Verifies the Merkle proof of the chunk with the provided index against the Merkle root
+    /// of the content split into `chunks_count` chunks. The proof contains the sibling hashes
+    /// from the leaf level up to the root and must be exactly ceil(log2(chunks_count)) long.
+    pub fn verify_chunk_proof(
+        merkle_root: &T::Hash,
+        chunks_count: u64,
+        chunk_index: u64,
+        chunk: &[u8],
+        proof: &[T::Hash],
+    ) -> bool {
+        if chunk_index >= chunks_count
+            || proof.len() as u64 != Self::chunk_proof_depth(chunks_count)
+        {
+            return false;
+        }
+
+        let mut computed_hash = T::Hashing::hash_of(&(CHUNK_LEAF_PREFIX, chunk));
+        let mut index = chunk_index;
+
+        for sibling in proof {
+            computed_hash = if index % 2 == 0 {
+                T::Hashing::hash_of(&(CHUNK_NODE_PREFIX, computed_hash, *sibling))
+            } else {
+                T::Hashing::hash_of(&(CHUNK_NODE_PREFIX, *sibling, computed_hash))
+            };
+
+            index /= 2;
+        }
+
+        computed_hash == *merkle_root
+    }
+
+    /// Returns the Merkle tree depth for the provided chunks count: ceil(log2(chunks_count)).
+    pub fn chunk_proof_depth(chunks_count: u64) -> u64 {
+        if chunks_count <= 1 {
+            return 0;
+        }
+
+        u64::from(64 - (chunks_count - 1).leading_zeros())
+    }
 }
 
 /// Provides random storage provider id. We use it when assign the content to the storage provider.
@@ -0,0 +1,597 @@
+//! # Data distribution registry module
+//! Data distribution registry module for the Joystream platform keeps the distributors of the
+//! distribution working group and assigns the content to the distribution buckets. Distributors
+//! serve the content of their buckets and report the served bandwidth per epoch.
+//!
+//! ## Comments
+//!
+//! Data distribution registry module uses the distribution working group module to authorize
+//! actions. Buckets and distributors are managed by the distribution working group lead.
+//! Only registered distributors can submit bandwidth reports.
+//!
+//! ## Supported extrinsics
+//!
+//! - [create_distribution_bucket](./struct.Module.html#method.create_distribution_bucket) - Creates an empty distribution bucket.
+//! - [assign_content_to_bucket](./struct.Module.html#method.assign_content_to_bucket) - Assigns the content to the distribution bucket.
+//! - [unassign_content_from_bucket](./struct.Module.html#method.unassign_content_from_bucket) - Removes the content from its distribution bucket.
+//! - [add_distributor](./struct.Module.html#method.add_distributor) - Registers the distribution working group worker as a distributor.
+//! - [remove_distributor](./struct.Module.html#method.remove_distributor) - Removes the distributor from the registry.
+//! - [assign_distributor_to_bucket](./struct.Module.html#method.assign_distributor_to_bucket) - Assigns the distributor to the distribution bucket.
+//! - [unassign_distributor_from_bucket](./struct.Module.html#method.unassign_distributor_from_bucket) - Removes the distributor from the distribution bucket.
+//! - [submit_bandwidth_report](./struct.Module.html#method.submit_bandwidth_report) - Distributor reports the bytes served during the current epoch.
+//!
+//! ## Bandwidth rewards
+//!
+//! On every epoch end the distributors are queued for the settlement, which sets the reward
+//! amount of each distributor recurring reward from its bandwidth report: served megabytes
+//! multiplied by the reward per megabyte, capped by the max reward per epoch. The queue is
+//! settled over the next blocks, up to the max number of settlements per block. Distributors
+//! without a report keep their current reward amount, e.g. set by the lead. The payout interval
+//! of the distributor reward relationship is expected to match the epoch length.
+//!
+
+// Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
+//#![warn(missing_docs)]
+
+use codec::{Codec, Decode, Encode};
+use frame_support::storage::IterableStorageMap;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
+use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
+use sp_runtime::traits::{MaybeSerialize, Member, SaturatedConversion, Saturating};
+use sp_std::vec::Vec;
+
+use crate::data_directory::{self, ContentIdExists};
+use crate::{DistributionWorkingGroup, DistributionWorkingGroupInstance, DistributorId};
+
+/// Number of bytes in the rewarded bandwidth unit.
+pub const BYTES_IN_MEGABYTE: u64 = 1024 * 1024;
+
+/// The _Data distribution registry_ main _Trait_.
+pub trait Trait:
+    system::Trait + data_directory::Trait + working_group::Trait<DistributionWorkingGroupInstance>
+{
+    /// _Data distribution registry_ event type.
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Type for the distribution bucket id.
+    type DistributionBucketId: Parameter
+        + Member
+        + BaseArithmetic
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerialize
+        + PartialEq;
+
+    /// Ensures that a content exists
+    type ContentIdExists: data_directory::ContentIdExists<Self>;
+
+    /// Number of blocks in the bandwidth reporting epoch. Zero disables the rewards settlement.
+    type EpochLength: Get<Self::BlockNumber>;
+
+    /// Reward for the served megabyte.
+    type RewardPerMegabyte: Get<working_group::BalanceOfMint<Self>>;
+
+    /// Max reward of the distributor per epoch.
+    type MaxRewardPerEpoch: Get<working_group::BalanceOfMint<Self>>;
+
+    /// Max number of the distribution buckets per distributor.
+    type MaxBucketsPerDistributor: Get<u32>;
+
+    /// Max number of the distributor rewards settled per block.
+    type MaxSettlementsPerBlock: Get<u32>;
+}
+
+decl_error! {
+    /// _Data distribution registry_ module predefined errors
+    pub enum Error for Module<T: Trait>{
+        /// Content with this ID not found.
+        CidNotFound,
+
+        /// Distribution bucket with this ID not found.
+        DistributionBucketNotFound,
+
+        /// The content is already assigned to a distribution bucket.
+        ContentAlreadyAssigned,
+
+        /// The content is not assigned to a distribution bucket.
+        ContentNotAssigned,
+
+        /// Distributor with this ID not found.
+        DistributorNotFound,
+
+        /// The worker is already registered as a distributor.
+        DistributorAlreadyExists,
+
+        /// The distributor is already assigned to the distribution bucket.
+        DistributorAlreadyAssigned,
+
+        /// The distributor is not assigned to the distribution bucket.
+        DistributorNotAssigned,
+
+        /// Max number of the distribution buckets per distributor reached.
+        MaxBucketsPerDistributorReached,
+
+        /// The distributor already submitted the bandwidth report for the current epoch.
+        BandwidthReportAlreadySubmitted,
+    }
+}
+
+/// Defines the distribution bucket - the content group served by the same distributors.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Default)]
+pub struct DistributionBucket<DistributorId> {
+    /// Distributors serving the bucket content.
+    pub distributors: Vec<DistributorId>,
+
+    /// Number of the content items assigned to the bucket.
+    pub content_count: u64,
+}
+
+/// Alias for the distribution bucket.
+pub type DistributionBucketOf<T> = DistributionBucket<DistributorId<T>>;
+
+/// Defines the registered distributor.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Default)]
+pub struct Distributor<DistributionBucketId> {
+    /// Distribution buckets served by the distributor.
+    pub buckets: Vec<DistributionBucketId>,
+}
+
+/// Alias for the distributor.
+pub type DistributorOf<T> = Distributor<<T as Trait>::DistributionBucketId>;
+
+decl_storage! {
+    trait Store for Module<T: Trait> as DataDistributionRegistry {
+        /// Defines next distribution bucket id.
+        pub NextDistributionBucketId get(fn next_distribution_bucket_id): T::DistributionBucketId;
+
+        /// Distribution buckets by id.
+        pub DistributionBucketById get(fn distribution_bucket_by_id): map hasher(blake2_128_concat)
+            T::DistributionBucketId => Option<DistributionBucketOf<T>>;
+
+        /// Distribution bucket ids by the assigned content id.
+        pub BucketByContentId get(fn bucket_by_content_id): map hasher(blake2_128_concat)
+            T::ContentId => Option<T::DistributionBucketId>;
+
+        /// Registered distributors.
+        pub DistributorById get(fn distributor_by_id): map hasher(blake2_128_concat)
+            DistributorId<T> => Option<DistributorOf<T>>;
+
+        /// Current bandwidth reporting epoch.
+        pub CurrentEpoch get(fn current_epoch): u64;
+
+        /// Served bytes reported by the distributors per epoch.
+        pub BandwidthReports get(fn bandwidth_reports): double_map hasher(blake2_128_concat) u64,
+            hasher(blake2_128_concat) DistributorId<T> => Option<u64>;
+
+        /// Distributors waiting for the reward settlement with the settled epoch.
+        pub UnsettledDistributors get(fn unsettled_distributors): Vec<(u64, DistributorId<T>)>;
+    }
+}
+
+decl_event! {
+    /// _Data distribution registry_ events
+    pub enum Event<T> where
+        <T as data_directory::Trait>::ContentId,
+        <T as Trait>::DistributionBucketId,
+        DistributorId = DistributorId<T>,
+        Balance = working_group::BalanceOfMint<T>
+    {
+        /// Emits on creating the distribution bucket.
+        /// Params:
+        /// - Id of the distribution bucket.
+        DistributionBucketCreated(DistributionBucketId),
+
+        /// Emits on assigning the content to the distribution bucket.
+        /// Params:
+        /// - Id of the distribution bucket.
+        /// - Id of the content.
+        ContentAssignedToBucket(DistributionBucketId, ContentId),
+
+        /// Emits on removing the content from the distribution bucket.
+        /// Params:
+        /// - Id of the distribution bucket.
+        /// - Id of the content.
+        ContentUnassignedFromBucket(DistributionBucketId, ContentId),
+
+        /// Emits on registering the distributor.
+        /// Params:
+        /// - Id of the distributor.
+        DistributorAdded(DistributorId),
+
+        /// Emits on removing the distributor from the registry.
+        /// Params:
+        /// - Id of the distributor.
+        DistributorRemoved(DistributorId),
+
+        /// Emits on assigning the distributor to the distribution bucket.
+        /// Params:
+        /// - Id of the distribution bucket.
+        /// - Id of the distributor.
+        DistributorAssignedToBucket(DistributionBucketId, DistributorId),
+
+        /// Emits on removing the distributor from the distribution bucket.
+        /// Params:
+        /// - Id of the distribution bucket.
+        /// - Id of the distributor.
+        DistributorUnassignedFromBucket(DistributionBucketId, DistributorId),
+
+        /// Emits on submitting the bandwidth report.
+        /// Params:
+        /// - Id of the distributor.
+        /// - Epoch of the report.
+        /// - Served bytes.
+        BandwidthReportSubmitted(DistributorId, u64, u64),
+
+        /// Emits on updating the distributor reward at the epoch end.
+        /// Params:
+        /// - Id of the distributor.
+        /// - Settled epoch.
+        /// - Reward amount for the next epoch.
+        DistributorRewardUpdated(DistributorId, u64, Balance),
+    }
+}
+
+decl_module! {
+    /// _Data distribution registry_ substrate module.
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        /// Default deposit_event() handler.
+        fn deposit_event() = default;
+
+        /// Predefined errors.
+        type Error = Error<T>;
+
+        /// Number of blocks in the bandwidth reporting epoch.
+        const EpochLength: T::BlockNumber = T::EpochLength::get();
+
+        /// Reward for the served megabyte.
+        const RewardPerMegabyte: working_group::BalanceOfMint<T> = T::RewardPerMegabyte::get();
+
+        /// Max reward of the distributor per epoch.
+        const MaxRewardPerEpoch: working_group::BalanceOfMint<T> = T::MaxRewardPerEpoch::get();
+
+        /// Max number of the distribution buckets per distributor.
+        const MaxBucketsPerDistributor: u32 = T::MaxBucketsPerDistributor::get();
+
+        /// Max number of the distributor rewards settled per block.
+        const MaxSettlementsPerBlock: u32 = T::MaxSettlementsPerBlock::get();
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let mut processed_distributors = 0;
+
+            let epoch_length = T::EpochLength::get();
+            if !epoch_length.is_zero() && (now % epoch_length).is_zero() {
+                processed_distributors += Self::end_epoch();
+            }
+
+            processed_distributors += Self::settle_distributors();
+
+            // Weight grows with the number of the processed distributors.
+            Weight::from(processed_distributors + 1).saturating_mul(10_000_000) //TODO: adjust weight
+        }
+
+        /// Creates an empty distribution bucket. Requires distribution working group lead origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_distribution_bucket(origin) {
+            <DistributionWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            // == MUTATION SAFE ==
+
+            let bucket_id = Self::next_distribution_bucket_id();
+
+            <DistributionBucketById<T>>::insert(bucket_id, DistributionBucketOf::<T>::default());
+            <NextDistributionBucketId<T>>::mutate(|n| { *n += T::DistributionBucketId::one(); });
+
+            Self::deposit_event(RawEvent::DistributionBucketCreated(bucket_id));
+        }
+
+        /// Assigns the content to the distribution bucket. Requires distribution working group
+        /// lead origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn assign_content_to_bucket(
+            origin,
+            bucket_id: T::DistributionBucketId,
+            content_id: T::ContentId
+        ) {
+            <DistributionWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            Self::ensure_distribution_bucket_exists(&bucket_id)?;
+
+            ensure!(T::ContentIdExists::has_content(&content_id), Error::<T>::CidNotFound);
+
+            ensure!(
+                !<BucketByContentId<T>>::contains_key(content_id),
+                Error::<T>::ContentAlreadyAssigned
+            );
+
+            // == MUTATION SAFE ==
+
+            <BucketByContentId<T>>::insert(content_id, bucket_id);
+            <DistributionBucketById<T>>::mutate(bucket_id, |bucket| {
+                if let Some(bucket) = bucket {
+                    bucket.content_count += 1;
+                }
+            });
+
+            Self::deposit_event(RawEvent::ContentAssignedToBucket(bucket_id, content_id));
+        }
+
+        /// Removes the content from its distribution bucket. Requires distribution working group
+        /// lead origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn unassign_content_from_bucket(origin, content_id: T::ContentId) {
+            <DistributionWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let bucket_id = Self::bucket_by_content_id(content_id)
+                .ok_or(Error::<T>::ContentNotAssigned)?;
+
+            // == MUTATION SAFE ==
+
+            <BucketByContentId<T>>::remove(content_id);
+            <DistributionBucketById<T>>::mutate(bucket_id, |bucket| {
+                if let Some(bucket) = bucket {
+                    bucket.content_count = bucket.content_count.saturating_sub(1);
+                }
+            });
+
+            Self::deposit_event(RawEvent::ContentUnassignedFromBucket(bucket_id, content_id));
+        }
+
+        /// Registers the distribution working group worker as a distributor. Requires distribution
+        /// working group lead origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn add_distributor(origin, distributor_id: DistributorId<T>) {
+            <DistributionWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            <DistributionWorkingGroup<T>>::ensure_worker_exists(&distributor_id)?;
+
+            ensure!(
+                !<DistributorById<T>>::contains_key(distributor_id),
+                Error::<T>::DistributorAlreadyExists
+            );
+
+            // == MUTATION SAFE ==
+
+            <DistributorById<T>>::insert(distributor_id, DistributorOf::<T>::default());
+
+            Self::deposit_event(RawEvent::DistributorAdded(distributor_id));
+        }
+
+        /// Removes the distributor from the registry and from all its distribution buckets.
+        /// Requires distribution working group lead origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn remove_distributor(origin, distributor_id: DistributorId<T>) {
+            <DistributionWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            Self::ensure_distributor_exists(&distributor_id)?;
+
+            // == MUTATION SAFE ==
+
+            Self::remove_distributor_record(&distributor_id);
+        }
+
+        /// Assigns the distributor to the distribution bucket. Requires distribution working group
+        /// lead origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn assign_distributor_to_bucket(
+            origin,
+            bucket_id: T::DistributionBucketId,
+            distributor_id: DistributorId<T>
+        ) {
+            <DistributionWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let mut bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
+            let mut distributor = Self::ensure_distributor_exists(&distributor_id)?;
+
+            ensure!(
+                !distributor.buckets.contains(&bucket_id),
+                Error::<T>::DistributorAlreadyAssigned
+            );
+
+            ensure!(
+                distributor.buckets.len() < T::MaxBucketsPerDistributor::get() as usize,
+                Error::<T>::MaxBucketsPerDistributorReached
+            );
+
+            // == MUTATION SAFE ==
+
+            distributor.buckets.push(bucket_id);
+            bucket.distributors.push(distributor_id);
+
+            <DistributorById<T>>::insert(distributor_id, distributor);
+            <DistributionBucketById<T>>::insert(bucket_id, bucket);
+
+            Self::deposit_event(RawEvent::DistributorAssignedToBucket(bucket_id, distributor_id));
+        }
+
+        /// Removes the distributor from the distribution bucket. Requires distribution working
+        /// group lead origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn unassign_distributor_from_bucket(
+            origin,
+            bucket_id: T::DistributionBucketId,
+            distributor_id: DistributorId<T>
+        ) {
+            <DistributionWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let mut bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
+            let mut distributor = Self::ensure_distributor_exists(&distributor_id)?;
+
+            ensure!(
+                distributor.buckets.contains(&bucket_id),
+                Error::<T>::DistributorNotAssigned
+            );
+
+            // == MUTATION SAFE ==
+
+            distributor.buckets.retain(|id| *id != bucket_id);
+            bucket.distributors.retain(|id| *id != distributor_id);
+
+            <DistributorById<T>>::insert(distributor_id, distributor);
+            <DistributionBucketById<T>>::insert(bucket_id, bucket);
+
+            Self::deposit_event(RawEvent::DistributorUnassignedFromBucket(bucket_id, distributor_id));
+        }
+
+        /// Distributor reports the bytes served during the current epoch. One report per epoch
+        /// is allowed.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn submit_bandwidth_report(
+            origin,
+            distributor_id: DistributorId<T>,
+            served_bytes: u64
+        ) {
+            <DistributionWorkingGroup<T>>::ensure_worker_signed(origin, &distributor_id)?;
+
+            Self::ensure_distributor_exists(&distributor_id)?;
+
+            let epoch = Self::current_epoch();
+
+            ensure!(
+                !<BandwidthReports<T>>::contains_key(epoch, distributor_id),
+                Error::<T>::BandwidthReportAlreadySubmitted
+            );
+
+            // == MUTATION SAFE ==
+
+            <BandwidthReports<T>>::insert(epoch, distributor_id, served_bytes);
+
+            Self::deposit_event(RawEvent::BandwidthReportSubmitted(distributor_id, epoch, served_bytes));
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Returns the distributors serving the content.
+    pub fn distributors_for_content(content_id: &T::ContentId) -> Vec<DistributorId<T>> {
+        Self::bucket_by_content_id(content_id)
+            .and_then(Self::distribution_bucket_by_id)
+            .map(|bucket| bucket.distributors)
+            .unwrap_or_default()
+    }
+
+    /// Calculates the distributor reward for the served bytes.
+    pub fn bandwidth_reward(served_bytes: u64) -> working_group::BalanceOfMint<T> {
+        let served_megabytes: working_group::BalanceOfMint<T> =
+            (served_bytes / BYTES_IN_MEGABYTE).saturated_into();
+
+        served_megabytes
+            .saturating_mul(T::RewardPerMegabyte::get())
+            .min(T::MaxRewardPerEpoch::get())
+    }
+
+    fn ensure_distribution_bucket_exists(
+        bucket_id: &T::DistributionBucketId,
+    ) -> Result<DistributionBucketOf<T>, Error<T>> {
+        Self::distribution_bucket_by_id(bucket_id).ok_or(Error::<T>::DistributionBucketNotFound)
+    }
+
+    fn ensure_distributor_exists(
+        distributor_id: &DistributorId<T>,
+    ) -> Result<DistributorOf<T>, Error<T>> {
+        Self::distributor_by_id(distributor_id).ok_or(Error::<T>::DistributorNotFound)
+    }
+
+    // Removes the distributor from the registry and all its buckets if it is registered.
+    fn remove_distributor_record(distributor_id: &DistributorId<T>) {
+        if let Some(distributor) = <DistributorById<T>>::take(distributor_id) {
+            for bucket_id in distributor.buckets {
+                <DistributionBucketById<T>>::mutate(bucket_id, |bucket| {
+                    if let Some(bucket) = bucket {
+                        bucket.distributors.retain(|id| id != distributor_id);
+                    }
+                });
+            }
+
+            let mut unsettled_distributors = Self::unsettled_distributors();
+            for (epoch, id) in unsettled_distributors.iter() {
+                if id == distributor_id {
+                    <BandwidthReports<T>>::remove(epoch, id);
+                }
+            }
+            unsettled_distributors.retain(|(_, id)| id != distributor_id);
+            <UnsettledDistributors<T>>::put(unsettled_distributors);
+
+            <BandwidthReports<T>>::remove(Self::current_epoch(), distributor_id);
+
+            Self::deposit_event(RawEvent::DistributorRemoved(*distributor_id));
+        }
+    }
+
+    // Queues the distributors for the settlement of the current epoch and starts the next epoch.
+    // Returns the number of the queued distributors.
+    fn end_epoch() -> u32 {
+        let epoch = Self::current_epoch();
+
+        let mut queued_distributors = 0;
+        <UnsettledDistributors<T>>::mutate(|unsettled_distributors| {
+            for (distributor_id, _) in <DistributorById<T>>::iter() {
+                unsettled_distributors.push((epoch, distributor_id));
+                queued_distributors += 1;
+            }
+        });
+
+        <CurrentEpoch>::put(epoch + 1);
+
+        queued_distributors
+    }
+
+    // Settles the queued distributors up to the per block limit, the rest is postponed to the
+    // next block. Returns the number of the settled distributors.
+    fn settle_distributors() -> u32 {
+        let mut distributors = Self::unsettled_distributors();
+        if distributors.is_empty() {
+            return 0;
+        }
+
+        let max_settlements = T::MaxSettlementsPerBlock::get().max(1) as usize;
+        let postponed_distributors = if distributors.len() > max_settlements {
+            distributors.split_off(max_settlements)
+        } else {
+            Vec::new()
+        };
+
+        <UnsettledDistributors<T>>::put(postponed_distributors);
+
+        for (epoch, distributor_id) in distributors.iter() {
+            Self::settle_distributor(*epoch, distributor_id);
+        }
+
+        distributors.len() as u32
+    }
+
+    // Sets the distributor reward for the next epoch from its bandwidth report.
+    fn settle_distributor(epoch: u64, distributor_id: &DistributorId<T>) {
+        // Distributors without the report keep the current reward.
+        let served_bytes = match <BandwidthReports<T>>::take(epoch, distributor_id) {
+            Some(served_bytes) => served_bytes,
+            None => return,
+        };
+
+        let reward = Self::bandwidth_reward(served_bytes);
+
+        // Distributors without the recurring reward are skipped, unchanged rewards are kept.
+        match <DistributionWorkingGroup<T>>::worker_reward_amount(distributor_id) {
+            Some(current_reward) if current_reward != reward => {}
+            _ => return,
+        }
+
+        if <DistributionWorkingGroup<T>>::set_worker_reward_amount(distributor_id, reward).is_ok() {
+            Self::deposit_event(RawEvent::DistributorRewardUpdated(
+                *distributor_id,
+                epoch,
+                reward,
+            ));
+        }
+    }
+}
+
+impl<T: Trait> working_group::WorkerExitHandler<T, DistributionWorkingGroupInstance> for Module<T> {
+    fn worker_exited(distributor_id: &DistributorId<T>) {
+        Self::remove_distributor_record(distributor_id);
+    }
+}
@@ -12,6 +12,18 @@
 //! - [add_relationship](./struct.Module.html#method.add_relationship) - Add storage provider-to-content relationship.
 //! - [set_relationship_ready](./struct.Module.html#method.set_relationship_ready)- Activates storage provider-to-content relationship.
 //! - [unset_relationship_ready](./struct.Module.html#method.unset_relationship_ready) - Deactivates storage provider-to-content relationship.
+//! - [challenge_relationship](./struct.Module.html#method.challenge_relationship) - Member challenges the storage provider to prove the content possession.
+//! - [respond_to_challenge](./struct.Module.html#method.respond_to_challenge) - Storage provider answers the challenge with a Merkle proof.
+//!
+//! ## Proof-of-storage challenges
+//!
+//! Besides the member-initiated challenges the module periodically challenges random ready
+//! relationships. Members reserve a deposit for their challenges: it is returned when the challenge
+//! fails and slashed when the storage provider answers it. Unanswered challenges are recorded as
+//! failures of the storage provider. Once the failures threshold is reached, the storage provider
+//! stake is slashed through the storage working group or, if the slash is not configured or
+//! impossible, the slash is recommended to the lead. The number of challenges expired per block is
+//! limited, the remaining ones are postponed to the following blocks.
 //!
 
 // Clippy linter requirement.
@@ -24,13 +36,19 @@
 
 use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::DispatchResult;
+use frame_support::traits::{Get, Randomness, ReservableCurrency};
+use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
-use sp_arithmetic::traits::BaseArithmetic;
-use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
+use sp_runtime::traits::{MaybeSerialize, Member, SaturatedConversion};
+use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
 
+use common::currency::{BalanceOf, GovernanceCurrency};
+use common::origin::ActorOriginValidator;
+
 use crate::data_directory::{self, ContentIdExists};
-use crate::{StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
+use crate::{MemberId, StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
 
 const DEFAULT_FIRST_RELATIONSHIP_ID: u8 = 1;
 
@@ -56,6 +74,32 @@ pub trait Trait:
 
     /// Ensures that a content exists
     type ContentIdExists: data_directory::ContentIdExists<Self>;
+
+    /// Randomness source for the challenged chunk indices.
+    type Randomness: Randomness<Self::Hash>;
+
+    /// Number of blocks the storage provider has to answer a challenge.
+    type ChallengeResponsePeriod: Get<Self::BlockNumber>;
+
+    /// Number of blocks between the periodic challenges. Zero disables the periodic challenges.
+    type ChallengePeriod: Get<Self::BlockNumber>;
+
+    /// Max number of relationships challenged per challenge period.
+    type MaxChallengesPerPeriod: Get<u32>;
+
+    /// Max number of challenges expired per block.
+    type MaxChallengeExpirationsPerBlock: Get<u32>;
+
+    /// Deposit reserved from the member challenging the relationship.
+    type ChallengeDeposit: Get<BalanceOf<Self>>;
+
+    /// Number of failed challenges before the storage provider gets slashed.
+    /// Zero disables the slashing.
+    type ChallengeFailuresSlashThreshold: Get<u32>;
+
+    /// Stake slashed from the storage provider on reaching the failures threshold.
+    /// Zero only recommends the slash to the storage working group lead.
+    type ChallengeFailureSlashAmount: Get<working_group::BalanceOf<Self>>;
 }
 
 decl_error! {
@@ -72,6 +116,30 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Only ready relationships can be challenged.
+        RelationshipIsNotReady,
+
+        /// Content has no chunk manifest and cannot be challenged.
+        ContentHasNoChunkManifest,
+
+        /// The relationship already has an active challenge.
+        ChallengeAlreadyExists,
+
+        /// No active challenge found for the relationship.
+        ChallengeNotFound,
+
+        /// The challenge response deadline has passed.
+        ChallengeDeadlinePassed,
+
+        /// The provided chunk or Merkle proof is invalid.
+        InvalidChunkProof,
+
+        /// Only the storage provider in a DOSR can respond to its challenge.
+        OnlyStorageProviderMayRespondToChallenge,
+
+        /// Insufficient balance to reserve the challenge deposit.
+        InsufficientBalanceForChallengeDeposit,
     }
 }
 
@@ -88,6 +156,35 @@ pub struct DataObjectStorageRelationship<T: Trait> {
     pub ready: bool,
 }
 
+/// Possession challenge for the data object storage relationship.
+#[derive(Clone, Encode, Decode, PartialEq, Debug, Default)]
+pub struct RelationshipChallenge<MemberId, BlockNumber> {
+    /// Member who issued the challenge. None for the periodic challenges.
+    pub challenger: Option<MemberId>,
+
+    /// Index of the chunk the storage provider should prove.
+    pub chunk_index: u64,
+
+    /// Last block the challenge can be answered at.
+    pub deadline: BlockNumber,
+}
+
+/// Alias for the relationship challenge.
+pub type Challenge<T> = RelationshipChallenge<MemberId<T>, <T as system::Trait>::BlockNumber>;
+
+/// Deposit reserved for the member-initiated challenge.
+#[derive(Clone, Encode, Decode, PartialEq, Debug, Default)]
+pub struct ChallengeDeposit<AccountId, Balance> {
+    /// Account the deposit is reserved from.
+    pub account_id: AccountId,
+
+    /// Reserved amount.
+    pub amount: Balance,
+}
+
+/// Alias for the challenge deposit.
+pub type ChallengeDepositOf<T> = ChallengeDeposit<<T as system::Trait>::AccountId, BalanceOf<T>>;
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataObjectStorageRegistry {
 
@@ -105,6 +202,22 @@ decl_storage! {
         /// Keeps a list of storage relationships per content id.
         pub RelationshipsByContentId get(fn relationships_by_content_id): map hasher(blake2_128_concat)
             T::ContentId => Vec<T::DataObjectStorageRelationshipId>;
+
+        /// Active possession challenges by relationship id.
+        pub ChallengeByRelationshipId get(fn challenge_by_relationship_id): map hasher(blake2_128_concat)
+            T::DataObjectStorageRelationshipId => Option<Challenge<T>>;
+
+        /// Deposits reserved for the active member-initiated challenges by relationship id.
+        pub ChallengeDepositByRelationshipId get(fn challenge_deposit_by_relationship_id):
+            map hasher(blake2_128_concat) T::DataObjectStorageRelationshipId => Option<ChallengeDepositOf<T>>;
+
+        /// Challenged relationship ids by the block their challenges expire at.
+        pub ChallengeExpirations get(fn challenge_expirations): map hasher(blake2_128_concat)
+            T::BlockNumber => Vec<T::DataObjectStorageRelationshipId>;
+
+        /// Number of failed challenges since the last slash per storage provider.
+        pub FailedChallengesByStorageProvider get(fn failed_challenges_by_storage_provider):
+            map hasher(blake2_128_concat) StorageProviderId<T> => u32;
     }
 }
 
@@ -113,7 +226,9 @@ decl_event! {
     pub enum Event<T> where
         <T as data_directory::Trait>::ContentId,
         <T as Trait>::DataObjectStorageRelationshipId,
-        StorageProviderId = StorageProviderId<T>
+        StorageProviderId = StorageProviderId<T>,
+        MemberId = MemberId<T>,
+        Balance = working_group::BalanceOf<T>
     {
         /// Emits on adding of the data object storage relationship.
         /// Params:
@@ -127,6 +242,39 @@ decl_event! {
         /// - Id of the relationship.
         /// - Current state of the relationship (True=Active).
         DataObjectStorageRelationshipReadyUpdated(DataObjectStorageRelationshipId, bool),
+
+        /// Emits on challenging the data object storage relationship.
+        /// Params:
+        /// - Id of the relationship.
+        /// - Id of the challenger member (None for the periodic challenges).
+        /// - Index of the challenged chunk.
+        DataObjectStorageRelationshipChallenged(DataObjectStorageRelationshipId, Option<MemberId>, u64),
+
+        /// Emits when the storage provider successfully answers the challenge.
+        /// Params:
+        /// - Id of the relationship.
+        /// - Id of the storage provider.
+        ChallengeAnswered(DataObjectStorageRelationshipId, StorageProviderId),
+
+        /// Emits when the storage provider doesn't answer the challenge before the deadline.
+        /// Params:
+        /// - Id of the relationship.
+        /// - Id of the storage provider.
+        /// - Number of the storage provider failed challenges since the last slash.
+        ChallengeFailed(DataObjectStorageRelationshipId, StorageProviderId, u32),
+
+        /// Emits on slashing the storage provider for the failed challenges.
+        /// Params:
+        /// - Id of the storage provider.
+        /// - Slashed balance.
+        StorageProviderSlashed(StorageProviderId, Balance),
+
+        /// Emits when the storage provider reaches the failures threshold but cannot be slashed
+        /// automatically. The storage working group lead is expected to slash it.
+        /// Params:
+        /// - Id of the storage provider.
+        /// - Number of failed challenges.
+        StorageProviderSlashRecommended(StorageProviderId, u32),
     }
 }
 
@@ -139,6 +287,39 @@ decl_module! {
         /// Predefined errors.
         type Error = Error<T>;
 
+        /// Number of blocks the storage provider has to answer a challenge.
+        const ChallengeResponsePeriod: T::BlockNumber = T::ChallengeResponsePeriod::get();
+
+        /// Number of blocks between the periodic challenges.
+        const ChallengePeriod: T::BlockNumber = T::ChallengePeriod::get();
+
+        /// Max number of relationships challenged per challenge period.
+        const MaxChallengesPerPeriod: u32 = T::MaxChallengesPerPeriod::get();
+
+        /// Max number of challenges expired per block.
+        const MaxChallengeExpirationsPerBlock: u32 = T::MaxChallengeExpirationsPerBlock::get();
+
+        /// Deposit reserved from the member challenging the relationship.
+        const ChallengeDeposit: BalanceOf<T> = T::ChallengeDeposit::get();
+
+        /// Number of failed challenges before the storage provider gets slashed.
+        const ChallengeFailuresSlashThreshold: u32 = T::ChallengeFailuresSlashThreshold::get();
+
+        /// Stake slashed from the storage provider on reaching the failures threshold.
+        const ChallengeFailureSlashAmount: working_group::BalanceOf<T> =
+            T::ChallengeFailureSlashAmount::get();
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::expire_challenges(now);
+
+            let challenge_period = T::ChallengePeriod::get();
+            if !challenge_period.is_zero() && (now % challenge_period).is_zero() {
+                Self::issue_periodic_challenges(now);
+            }
+
+            10_000_000 //TODO: adjust weight
+        }
+
         /// Add storage provider-to-content relationship. The storage provider should be registered
         /// in the storage working group.
         #[weight = 10_000_000] // TODO: adjust weight
@@ -199,6 +380,102 @@ decl_module! {
         ) {
             Self::toggle_dosr_ready(origin, storage_provider_id, id, false)?;
         }
+
+        /// Challenges the storage provider to prove that it stores the content of the ready
+        /// relationship. The challenged chunk index is chosen randomly. Member id should match its origin.
+        /// The challenge deposit is reserved from the origin account: it is returned if the challenge
+        /// fails and slashed if the storage provider answers it.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn challenge_relationship(
+            origin,
+            member_id: MemberId<T>,
+            id: T::DataObjectStorageRelationshipId
+        ) {
+            let account_id = <T as data_directory::Trait>::MemberOriginValidator::ensure_actor_origin(
+                origin,
+                member_id,
+            )?;
+
+            let chunks_count = Self::ensure_relationship_can_be_challenged(id)?;
+
+            let deposit = T::ChallengeDeposit::get();
+            ensure!(
+                deposit.is_zero() || <T as GovernanceCurrency>::Currency::can_reserve(&account_id, deposit),
+                Error::<T>::InsufficientBalanceForChallengeDeposit
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if !deposit.is_zero() {
+                // Reserve can not fail after the successful check
+                let _ = <T as GovernanceCurrency>::Currency::reserve(&account_id, deposit);
+
+                <ChallengeDepositByRelationshipId<T>>::insert(
+                    id,
+                    ChallengeDeposit { account_id, amount: deposit },
+                );
+            }
+
+            Self::issue_challenge(id, Some(member_id), chunks_count);
+        }
+
+        /// Answers the active challenge with the requested chunk and its Merkle proof.
+        /// Only the storage provider of the relationship can answer before the deadline.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn respond_to_challenge(
+            origin,
+            storage_provider_id: StorageProviderId<T>,
+            id: T::DataObjectStorageRelationshipId,
+            chunk: Vec<u8>,
+            proof: Vec<T::Hash>
+        ) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            let dosr = Self::relationships(id).ok_or(Error::<T>::DataObjectStorageRelationshipNotFound)?;
+
+            ensure!(
+                dosr.storage_provider_id == storage_provider_id,
+                Error::<T>::OnlyStorageProviderMayRespondToChallenge
+            );
+
+            let challenge = Self::challenge_by_relationship_id(id).ok_or(Error::<T>::ChallengeNotFound)?;
+
+            ensure!(
+                <system::Module<T>>::block_number() <= challenge.deadline,
+                Error::<T>::ChallengeDeadlinePassed
+            );
+
+            let data_object = T::ContentIdExists::get_data_object(&dosr.content_id)?;
+            let manifest = data_object.chunk_manifest.ok_or(Error::<T>::ContentHasNoChunkManifest)?;
+
+            ensure!(chunk.len() as u64 <= manifest.chunk_size, Error::<T>::InvalidChunkProof);
+
+            ensure!(
+                <data_directory::Module<T>>::verify_chunk_proof(
+                    &manifest.merkle_root,
+                    manifest.chunks_count(data_object.size),
+                    challenge.chunk_index,
+                    &chunk,
+                    &proof,
+                ),
+                Error::<T>::InvalidChunkProof
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ChallengeByRelationshipId<T>>::remove(id);
+
+            // The challenger was wrong, slash its deposit.
+            if let Some(deposit) = <ChallengeDepositByRelationshipId<T>>::take(id) {
+                let _ = <T as GovernanceCurrency>::Currency::slash_reserved(&deposit.account_id, deposit.amount);
+            }
+
+            Self::deposit_event(RawEvent::ChallengeAnswered(id, storage_provider_id));
+        }
     }
 }
 
@@ -231,4 +508,162 @@ impl<T: Trait> Module<T> {
 
         Ok(())
     }
+
+    // Ensures the relationship exists, is ready, has no active challenge and its content has
+    // the chunk manifest. Returns the number of the content chunks.
+    fn ensure_relationship_can_be_challenged(
+        id: T::DataObjectStorageRelationshipId,
+    ) -> Result<u64, DispatchError> {
+        let dosr =
+            Self::relationships(id).ok_or(Error::<T>::DataObjectStorageRelationshipNotFound)?;
+
+        ensure!(dosr.ready, Error::<T>::RelationshipIsNotReady);
+
+        ensure!(
+            !<ChallengeByRelationshipId<T>>::contains_key(id),
+            Error::<T>::ChallengeAlreadyExists
+        );
+
+        let data_object = T::ContentIdExists::get_data_object(&dosr.content_id)?;
+        let content_size = data_object.size;
+        let chunks_count = data_object
+            .chunk_manifest
+            .map(|manifest| manifest.chunks_count(content_size))
+            .unwrap_or(0);
+
+        ensure!(chunks_count > 0, Error::<T>::ContentHasNoChunkManifest);
+
+        Ok(chunks_count)
+    }
+
+    // Creates the challenge for a random chunk and schedules its expiration.
+    fn issue_challenge(
+        id: T::DataObjectStorageRelationshipId,
+        challenger: Option<MemberId<T>>,
+        chunks_count: u64,
+    ) {
+        let now = <system::Module<T>>::block_number();
+        let chunk_index = Self::random_index(&(id, challenger, now).encode(), chunks_count);
+        let deadline = now + T::ChallengeResponsePeriod::get();
+
+        let challenge = RelationshipChallenge {
+            challenger,
+            chunk_index,
+            deadline,
+        };
+
+        <ChallengeByRelationshipId<T>>::insert(id, challenge);
+        <ChallengeExpirations<T>>::mutate(deadline + One::one(), |ids| ids.push(id));
+
+        Self::deposit_event(RawEvent::DataObjectStorageRelationshipChallenged(
+            id,
+            challenger,
+            chunk_index,
+        ));
+    }
+
+    // Challenges random relationships. Relationships that cannot be challenged are skipped.
+    fn issue_periodic_challenges(now: T::BlockNumber) {
+        let first_id: u64 = Self::first_relationship_id().saturated_into();
+        let next_id: u64 = Self::next_relationship_id().saturated_into();
+
+        if next_id <= first_id {
+            return;
+        }
+
+        for attempt in 0..T::MaxChallengesPerPeriod::get() {
+            let offset = Self::random_index(&(now, attempt).encode(), next_id - first_id);
+            let id = T::DataObjectStorageRelationshipId::saturated_from(first_id + offset);
+
+            if let Ok(chunks_count) = Self::ensure_relationship_can_be_challenged(id) {
+                Self::issue_challenge(id, None, chunks_count);
+            }
+        }
+    }
+
+    // Records the failures for the challenges that expire at the provided block. Challenges above
+    // the per block limit are postponed to the next block ahead of its own expirations.
+    fn expire_challenges(now: T::BlockNumber) {
+        let mut ids = <ChallengeExpirations<T>>::take(now);
+
+        let max_expirations = T::MaxChallengeExpirationsPerBlock::get().max(1) as usize;
+        if ids.len() > max_expirations {
+            let mut postponed_ids = ids.split_off(max_expirations);
+
+            <ChallengeExpirations<T>>::mutate(now + One::one(), |next_ids| {
+                postponed_ids.append(next_ids);
+                *next_ids = postponed_ids;
+            });
+        }
+
+        for id in ids {
+            // Answered challenges are removed, a new challenge could be issued meanwhile.
+            let expired = Self::challenge_by_relationship_id(id)
+                .map(|challenge| challenge.deadline < now)
+                .unwrap_or(false);
+
+            if !expired {
+                continue;
+            }
+
+            <ChallengeByRelationshipId<T>>::remove(id);
+
+            // The challenger was right, return its deposit.
+            if let Some(deposit) = <ChallengeDepositByRelationshipId<T>>::take(id) {
+                <T as GovernanceCurrency>::Currency::unreserve(&deposit.account_id, deposit.amount);
+            }
+
+            if let Some(dosr) = Self::relationships(id) {
+                Self::record_failed_challenge(id, dosr.storage_provider_id);
+            }
+        }
+    }
+
+    // Increments the storage provider failures and slashes it on reaching the threshold.
+    fn record_failed_challenge(
+        id: T::DataObjectStorageRelationshipId,
+        storage_provider_id: StorageProviderId<T>,
+    ) {
+        let failures = <FailedChallengesByStorageProvider<T>>::mutate(storage_provider_id, |n| {
+            *n = n.saturating_add(1);
+            *n
+        });
+
+        Self::deposit_event(RawEvent::ChallengeFailed(id, storage_provider_id, failures));
+
+        let threshold = T::ChallengeFailuresSlashThreshold::get();
+        if threshold == 0 || failures < threshold {
+            return;
+        }
+
+        <FailedChallengesByStorageProvider<T>>::remove(storage_provider_id);
+
+        let slash_amount = T::ChallengeFailureSlashAmount::get();
+        let slashed = !slash_amount.is_zero()
+            && <StorageWorkingGroup<T>>::slash_worker_stake(&storage_provider_id, slash_amount)
+                .is_ok();
+
+        if slashed {
+            Self::deposit_event(RawEvent::StorageProviderSlashed(
+                storage_provider_id,
+                slash_amount,
+            ));
+        } else {
+            Self::deposit_event(RawEvent::StorageProviderSlashRecommended(
+                storage_provider_id,
+                failures,
+            ));
+        }
+    }
+
+    // Derives the index in the [0, upper_bound) range from the randomness source.
+    fn random_index(subject: &[u8], upper_bound: u64) -> u64 {
+        let seed = T::Randomness::random(subject);
+        let mut rand: u64 = 0;
+        for (offset, byte) in seed.as_ref().iter().take(8).enumerate() {
+            rand += (*byte as u64) << (offset * 8);
+        }
+
+        rand % upper_bound
+    }
 }
@@ -2,9 +2,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod data_directory;
+pub mod data_distribution_registry;
 pub mod data_object_storage_registry;
 pub mod data_object_type_registry;
 
+mod migration;
 mod tests;
 
 // The storage working group instance alias.
@@ -13,8 +15,18 @@ pub type StorageWorkingGroupInstance = working_group::Instance2;
 // Alias for storage working group
 pub(crate) type StorageWorkingGroup<T> = working_group::Module<T, StorageWorkingGroupInstance>;
 
+// The distribution working group instance alias.
+pub type DistributionWorkingGroupInstance = working_group::Instance4;
+
+// Alias for distribution working group
+pub(crate) type DistributionWorkingGroup<T> =
+    working_group::Module<T, DistributionWorkingGroupInstance>;
+
 // Alias for the member id.
 pub(crate) type MemberId<T> = <T as membership::Trait>::MemberId;
 
 /// Storage provider is a worker from the working group module.
 pub type StorageProviderId<T> = working_group::WorkerId<T>;
+
+/// Distributor is a worker from the distribution working group.
+pub type DistributorId<T> = working_group::WorkerId<T>;
@@ -0,0 +1,55 @@
+use crate::data_directory::*;
+use crate::data_object_type_registry;
+use crate::{MemberId, StorageProviderId};
+use codec::Decode;
+use frame_support::storage::IterableStorageMap;
+use frame_support::StorageValue;
+use sp_std::vec::Vec;
+
+// Data object in the format preceding the content hashes and chunk manifests.
+#[derive(Decode)]
+struct LegacyDataObject<MemberId, BlockNumber, Moment, DataObjectTypeId, StorageProviderId> {
+    owner: MemberId,
+    added_at: BlockAndTime<BlockNumber, Moment>,
+    type_id: DataObjectTypeId,
+    size: u64,
+    liaison: StorageProviderId,
+    liaison_judgement: LiaisonJudgement,
+    ipfs_content_id: Vec<u8>,
+}
+
+type LegacyDataObjectOf<T> = LegacyDataObject<
+    MemberId<T>,
+    <T as system::Trait>::BlockNumber,
+    <T as pallet_timestamp::Trait>::Moment,
+    <T as data_object_type_registry::Trait>::DataObjectTypeId,
+    StorageProviderId<T>,
+>;
+
+impl<T: Trait> Module<T> {
+    /// Converts the existing data objects to the layout with the content hash and the chunk
+    /// manifest. The content hash of the migrated objects is unknown and set to the default value,
+    /// they have no chunk manifest and therefore can't be challenged.
+    /// Does nothing if the data objects were already migrated. Should be called on the runtime upgrade.
+    pub fn migrate_data_objects_to_hashed_content() {
+        if Self::data_objects_hashed() {
+            return;
+        }
+
+        <DataObjectByContentId<T>>::translate(|_, data_object: LegacyDataObjectOf<T>| {
+            Some(DataObjectInternal {
+                owner: data_object.owner,
+                added_at: data_object.added_at,
+                type_id: data_object.type_id,
+                size: data_object.size,
+                liaison: data_object.liaison,
+                liaison_judgement: data_object.liaison_judgement,
+                ipfs_content_id: data_object.ipfs_content_id,
+                content_hash: Default::default(),
+                chunk_manifest: None,
+            })
+        });
+
+        DataObjectsHashed::put(true);
+    }
+}
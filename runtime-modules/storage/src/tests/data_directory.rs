@@ -1,6 +1,9 @@
 #![cfg(test)]
 
 use frame_support::dispatch::DispatchError;
+use frame_support::storage::unhashed;
+use frame_support::{StorageMap, StorageValue};
+use sp_core::H256;
 use sp_std::collections::btree_map::BTreeMap;
 use system::RawOrigin;
 
@@ -19,6 +22,8 @@ fn succeed_adding_content() {
             1234,
             0,
             vec![1, 3, 3, 7],
+            H256::default(),
+            None,
         );
         assert!(res.is_ok());
     });
@@ -36,6 +41,8 @@ fn add_content_fails_with_invalid_origin() {
             1234,
             0,
             vec![1, 3, 3, 7],
+            H256::default(),
+            None,
         );
         assert_eq!(res, Err(DispatchError::Other("Bad origin")));
     });
@@ -61,6 +68,8 @@ fn accept_and_reject_content_fail_with_invalid_storage_provider() {
             1234,
             0,
             vec![1, 2, 3, 4],
+            H256::default(),
+            None,
         );
         assert!(res.is_ok());
 
@@ -79,6 +88,7 @@ fn accept_and_reject_content_fail_with_invalid_storage_provider() {
             Origin::signed(storage_provider_account_id),
             storage_provider_id,
             content_id,
+            H256::default(),
         );
         assert_eq!(res, Err(working_group::Error::<Test, crate::StorageWorkingGroupInstance>::WorkerDoesNotExist.into()));
 
@@ -111,6 +121,8 @@ fn accept_content_as_liaison() {
             1234,
             0,
             vec![1, 2, 3, 4],
+            H256::default(),
+            None,
         );
         assert!(res.is_ok());
 
@@ -128,8 +140,12 @@ fn accept_content_as_liaison() {
         let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
 
         // Accepting content should not work with some random origin
-        let res =
-            TestDataDirectory::accept_content(Origin::signed(55), storage_provider_id, content_id);
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(55),
+            storage_provider_id,
+            content_id,
+            H256::default(),
+        );
         assert!(res.is_err());
 
         // However, with the liaison as origin it should.
@@ -137,11 +153,176 @@ fn accept_content_as_liaison() {
             Origin::signed(storage_provider_account_id),
             storage_provider_id,
             content_id,
+            H256::default(),
         );
         assert_eq!(res, Ok(()));
     });
 }
 
+#[test]
+fn accept_content_fails_with_mismatching_content_hash() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = 1;
+        let content_hash = H256::from_low_u64_be(1337);
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            content_id,
+            1234,
+            0,
+            vec![1, 2, 3, 4],
+            content_hash,
+            None,
+        );
+        assert!(res.is_ok());
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+            H256::from_low_u64_be(42),
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::ContentHashMismatch.into())
+        );
+
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+            content_hash,
+        );
+        assert_eq!(res, Ok(()));
+    });
+}
+
+#[test]
+fn add_content_fails_with_zero_chunk_size() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            1,
+            1234,
+            0,
+            vec![1, 2, 3, 4],
+            H256::default(),
+            Some(data_directory::ChunkManifest {
+                merkle_root: H256::default(),
+                chunk_size: 0,
+            }),
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::InvalidChunkManifest.into())
+        );
+    });
+}
+
+#[test]
+fn verify_chunk_proof_works() {
+    with_default_mock_builder(|| {
+        let chunks = mock_content_chunks();
+        let (merkle_root, proof) = mock_chunk_merkle_proof(2);
+
+        let chunks_count = chunks.len() as u64;
+
+        assert!(TestDataDirectory::verify_chunk_proof(
+            &merkle_root,
+            chunks_count,
+            2,
+            &chunks[2],
+            &proof
+        ));
+
+        // Wrong chunk.
+        assert!(!TestDataDirectory::verify_chunk_proof(
+            &merkle_root,
+            chunks_count,
+            2,
+            &chunks[1],
+            &proof
+        ));
+
+        // Wrong index.
+        assert!(!TestDataDirectory::verify_chunk_proof(
+            &merkle_root,
+            chunks_count,
+            3,
+            &chunks[2],
+            &proof
+        ));
+
+        // Index outside the tree.
+        assert!(!TestDataDirectory::verify_chunk_proof(
+            &merkle_root,
+            chunks_count,
+            6,
+            &chunks[2],
+            &proof
+        ));
+
+        // Proof shorter than the tree depth.
+        assert!(!TestDataDirectory::verify_chunk_proof(
+            &merkle_root,
+            chunks_count,
+            2,
+            &chunks[2],
+            &proof[..1]
+        ));
+
+        // Proof longer than the tree depth.
+        let mut long_proof = proof.clone();
+        long_proof.push(merkle_root);
+        assert!(!TestDataDirectory::verify_chunk_proof(
+            &merkle_root,
+            chunks_count,
+            2,
+            &chunks[2],
+            &long_proof
+        ));
+    });
+}
+
+#[test]
+fn verify_chunk_proof_works_for_odd_chunks_count() {
+    with_default_mock_builder(|| {
+        let chunks = vec![vec![1; 4], vec![2; 4], vec![3; 2]];
+
+        for chunk_index in 0..chunks.len() {
+            let (merkle_root, proof) = chunk_merkle_proof(&chunks, chunk_index);
+
+            assert_eq!(proof.len(), 2);
+            assert!(TestDataDirectory::verify_chunk_proof(
+                &merkle_root,
+                chunks.len() as u64,
+                chunk_index as u64,
+                &chunks[chunk_index],
+                &proof
+            ));
+        }
+
+        // The duplicated last node doesn't make up a chunk.
+        let (merkle_root, proof) = chunk_merkle_proof(&chunks, 2);
+        assert!(!TestDataDirectory::verify_chunk_proof(
+            &merkle_root,
+            chunks.len() as u64,
+            3,
+            &chunks[2],
+            &proof
+        ));
+    });
+}
+
 #[test]
 fn reject_content_as_liaison() {
     with_default_mock_builder(|| {
@@ -162,6 +343,8 @@ fn reject_content_as_liaison() {
             1234,
             0,
             vec![1, 2, 3, 4],
+            H256::default(),
+            None,
         );
         assert!(res.is_ok());
 
@@ -213,6 +396,8 @@ fn data_object_injection_works() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![],
+            content_hash: H256::default(),
+            chunk_manifest: None,
         };
 
         let content_id_1 = 1;
@@ -258,6 +443,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             1,
             10,
             vec![8, 8, 8, 8],
+            H256::default(),
+            None,
         );
         assert!(res.is_ok());
         let res = TestDataDirectory::add_content(
@@ -267,6 +454,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             2,
             20,
             vec![9, 9, 9, 9],
+            H256::default(),
+            None,
         );
         assert!(res.is_ok());
 
@@ -283,6 +472,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![5, 6, 7],
+            content_hash: H256::default(),
+            chunk_manifest: None,
         };
 
         let object2 = data_directory::DataObjectInternal {
@@ -296,6 +487,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![5, 6, 7],
+            content_hash: H256::default(),
+            chunk_manifest: None,
         };
 
         objects.insert(content_id_1, object1.clone());
@@ -320,3 +513,70 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         );
     });
 }
+
+#[test]
+fn chunks_count_doesnt_overflow() {
+    let manifest = data_directory::ChunkManifest {
+        merkle_root: H256::default(),
+        chunk_size: 4,
+    };
+
+    assert_eq!(manifest.chunks_count(0), 0);
+    assert_eq!(manifest.chunks_count(4), 1);
+    assert_eq!(manifest.chunks_count(5), 2);
+    assert_eq!(
+        manifest.chunks_count(u64::max_value()),
+        u64::max_value() / 4 + 1
+    );
+}
+
+#[test]
+fn data_objects_migration_works() {
+    with_default_mock_builder(|| {
+        let content_id = 1;
+
+        // Data object in the layout without the content hash and the chunk manifest.
+        let legacy_data_object = (
+            5u64,
+            data_directory::BlockAndTime {
+                block: 10u64,
+                time: 1000u64,
+            },
+            1u64,
+            6666u64,
+            TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
+            data_directory::LiaisonJudgement::Accepted,
+            vec![5u8, 6, 7],
+        );
+        unhashed::put(
+            &<data_directory::DataObjectByContentId<Test>>::hashed_key_for(content_id),
+            &legacy_data_object,
+        );
+
+        // nothing happens on the migrated chains
+        TestDataDirectory::migrate_data_objects_to_hashed_content();
+        assert!(TestDataDirectory::data_object_by_content_id(content_id).is_none());
+
+        data_directory::DataObjectsHashed::put(false);
+        TestDataDirectory::migrate_data_objects_to_hashed_content();
+
+        assert!(TestDataDirectory::data_objects_hashed());
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(content_id),
+            Some(data_directory::DataObjectInternal {
+                type_id: 1,
+                size: 6666,
+                added_at: data_directory::BlockAndTime {
+                    block: 10,
+                    time: 1000,
+                },
+                owner: 5,
+                liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
+                liaison_judgement: data_directory::LiaisonJudgement::Accepted,
+                ipfs_content_id: vec![5, 6, 7],
+                content_hash: H256::default(),
+                chunk_manifest: None,
+            })
+        );
+    });
+}
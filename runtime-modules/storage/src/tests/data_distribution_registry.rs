@@ -0,0 +1,321 @@
+#![cfg(test)]
+
+use frame_support::traits::Get;
+use frame_support::{StorageMap, StorageValue};
+use system::RawOrigin;
+
+use super::mock::*;
+
+const DEFAULT_LEADER_ACCOUNT_ID: u64 = 1;
+const DEFAULT_LEADER_WORKER_ID: u32 = 1;
+const DEFAULT_DISTRIBUTOR_ACCOUNT_ID: u64 = 2;
+const DEFAULT_DISTRIBUTOR_ID: u32 = 2;
+
+fn insert_distribution_worker(worker_id: u32, role_account_id: u64) {
+    let mint_id = minting::Module::<Test>::add_mint(1000, None).unwrap();
+    let recipient_id = recurringrewards::Module::<Test>::add_recipient();
+    let relationship_id = recurringrewards::Module::<Test>::add_reward_relationship(
+        mint_id,
+        recipient_id,
+        role_account_id,
+        0,
+        100,
+        Some(<DistributionEpochLength as Get<u64>>::get()),
+    )
+    .unwrap();
+
+    let worker = working_group::Worker {
+        member_id: 1,
+        role_account_id,
+        reward_relationship: Some(relationship_id),
+        role_stake_profile: None,
+    };
+
+    <working_group::WorkerById<Test, DistributionWorkingGroupInstance>>::insert(worker_id, worker);
+}
+
+// Sets the distribution working group lead, registers the default distributor and assigns it
+// to the new distribution bucket with the existing content.
+fn set_up_distribution() -> u64 {
+    insert_distribution_worker(DEFAULT_LEADER_WORKER_ID, DEFAULT_LEADER_ACCOUNT_ID);
+    <working_group::CurrentLead<Test, DistributionWorkingGroupInstance>>::put(
+        DEFAULT_LEADER_WORKER_ID,
+    );
+
+    insert_distribution_worker(DEFAULT_DISTRIBUTOR_ID, DEFAULT_DISTRIBUTOR_ACCOUNT_ID);
+
+    let bucket_id = TestDataDistributionRegistry::next_distribution_bucket_id();
+
+    assert_eq!(
+        TestDataDistributionRegistry::create_distribution_bucket(lead_origin()),
+        Ok(())
+    );
+    assert_eq!(
+        TestDataDistributionRegistry::assign_content_to_bucket(
+            lead_origin(),
+            bucket_id,
+            TEST_MOCK_EXISTING_CID
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        TestDataDistributionRegistry::add_distributor(lead_origin(), DEFAULT_DISTRIBUTOR_ID),
+        Ok(())
+    );
+    assert_eq!(
+        TestDataDistributionRegistry::assign_distributor_to_bucket(
+            lead_origin(),
+            bucket_id,
+            DEFAULT_DISTRIBUTOR_ID
+        ),
+        Ok(())
+    );
+
+    bucket_id
+}
+
+fn lead_origin() -> Origin {
+    RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into()
+}
+
+fn distributor_reward_amount(distributor_id: u32) -> u64 {
+    let worker =
+        <working_group::WorkerById<Test, DistributionWorkingGroupInstance>>::get(distributor_id);
+
+    recurringrewards::Module::<Test>::reward_relationships(worker.reward_relationship.unwrap())
+        .amount_per_payout
+}
+
+#[test]
+fn distributors_are_assigned_to_content_buckets() {
+    with_default_mock_builder(|| {
+        let bucket_id = set_up_distribution();
+
+        assert_eq!(
+            TestDataDistributionRegistry::bucket_by_content_id(TEST_MOCK_EXISTING_CID),
+            Some(bucket_id)
+        );
+        assert_eq!(
+            TestDataDistributionRegistry::distributors_for_content(&TEST_MOCK_EXISTING_CID),
+            vec![DEFAULT_DISTRIBUTOR_ID]
+        );
+
+        let res = TestDataDistributionRegistry::assign_content_to_bucket(
+            lead_origin(),
+            bucket_id,
+            TEST_MOCK_EXISTING_CID + 1,
+        );
+        assert_eq!(
+            res,
+            Err(data_distribution_registry::Error::<Test>::CidNotFound.into())
+        );
+
+        let res = TestDataDistributionRegistry::assign_distributor_to_bucket(
+            lead_origin(),
+            bucket_id,
+            DEFAULT_DISTRIBUTOR_ID,
+        );
+        assert_eq!(
+            res,
+            Err(data_distribution_registry::Error::<Test>::DistributorAlreadyAssigned.into())
+        );
+
+        assert_eq!(
+            TestDataDistributionRegistry::unassign_distributor_from_bucket(
+                lead_origin(),
+                bucket_id,
+                DEFAULT_DISTRIBUTOR_ID,
+            ),
+            Ok(())
+        );
+        assert!(
+            TestDataDistributionRegistry::distributors_for_content(&TEST_MOCK_EXISTING_CID)
+                .is_empty()
+        );
+    });
+}
+
+#[test]
+fn bandwidth_reports_set_distributor_rewards_at_epoch_end() {
+    with_default_mock_builder(|| {
+        set_up_distribution();
+
+        let served_bytes = 10 * data_distribution_registry::BYTES_IN_MEGABYTE;
+        assert_eq!(
+            TestDataDistributionRegistry::submit_bandwidth_report(
+                RawOrigin::Signed(DEFAULT_DISTRIBUTOR_ACCOUNT_ID).into(),
+                DEFAULT_DISTRIBUTOR_ID,
+                served_bytes,
+            ),
+            Ok(())
+        );
+
+        let res = TestDataDistributionRegistry::submit_bandwidth_report(
+            RawOrigin::Signed(DEFAULT_DISTRIBUTOR_ACCOUNT_ID).into(),
+            DEFAULT_DISTRIBUTOR_ID,
+            served_bytes,
+        );
+        assert_eq!(
+            res,
+            Err(data_distribution_registry::Error::<Test>::BandwidthReportAlreadySubmitted.into())
+        );
+
+        run_to_block(<DistributionEpochLength as Get<u64>>::get());
+
+        // 10 megabytes by the reward of 2 per megabyte.
+        assert_eq!(distributor_reward_amount(DEFAULT_DISTRIBUTOR_ID), 20);
+        assert_eq!(TestDataDistributionRegistry::current_epoch(), 1);
+        assert!(
+            TestDataDistributionRegistry::bandwidth_reports(0, DEFAULT_DISTRIBUTOR_ID).is_none()
+        );
+
+        // The reward is capped by the max reward per epoch.
+        assert_eq!(
+            TestDataDistributionRegistry::submit_bandwidth_report(
+                RawOrigin::Signed(DEFAULT_DISTRIBUTOR_ACCOUNT_ID).into(),
+                DEFAULT_DISTRIBUTOR_ID,
+                1000 * data_distribution_registry::BYTES_IN_MEGABYTE,
+            ),
+            Ok(())
+        );
+
+        run_to_block(2 * <DistributionEpochLength as Get<u64>>::get());
+        assert_eq!(
+            distributor_reward_amount(DEFAULT_DISTRIBUTOR_ID),
+            <MaxRewardPerEpoch as Get<u64>>::get()
+        );
+
+        // No report - the reward is kept.
+        run_to_block(3 * <DistributionEpochLength as Get<u64>>::get());
+        assert_eq!(
+            distributor_reward_amount(DEFAULT_DISTRIBUTOR_ID),
+            <MaxRewardPerEpoch as Get<u64>>::get()
+        );
+    });
+}
+
+#[test]
+fn distributor_rewards_are_settled_over_blocks() {
+    with_default_mock_builder(|| {
+        const SECOND_DISTRIBUTOR_ID: u32 = 3;
+        const SECOND_DISTRIBUTOR_ACCOUNT_ID: u64 = 3;
+
+        set_up_distribution();
+
+        insert_distribution_worker(SECOND_DISTRIBUTOR_ID, SECOND_DISTRIBUTOR_ACCOUNT_ID);
+        assert_eq!(
+            TestDataDistributionRegistry::add_distributor(lead_origin(), SECOND_DISTRIBUTOR_ID),
+            Ok(())
+        );
+
+        let served_bytes = 10 * data_distribution_registry::BYTES_IN_MEGABYTE;
+        for (distributor_id, account_id) in [
+            (DEFAULT_DISTRIBUTOR_ID, DEFAULT_DISTRIBUTOR_ACCOUNT_ID),
+            (SECOND_DISTRIBUTOR_ID, SECOND_DISTRIBUTOR_ACCOUNT_ID),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                TestDataDistributionRegistry::submit_bandwidth_report(
+                    RawOrigin::Signed(*account_id).into(),
+                    *distributor_id,
+                    served_bytes,
+                ),
+                Ok(())
+            );
+        }
+
+        let epoch_length = <DistributionEpochLength as Get<u64>>::get();
+        run_to_block(epoch_length);
+
+        // One distributor is settled per block.
+        assert_eq!(
+            TestDataDistributionRegistry::unsettled_distributors().len(),
+            1
+        );
+
+        // Reports for the next epoch are kept apart from the unsettled ones.
+        let (_, unsettled_distributor_id) =
+            TestDataDistributionRegistry::unsettled_distributors()[0];
+        let unsettled_account_id = if unsettled_distributor_id == DEFAULT_DISTRIBUTOR_ID {
+            DEFAULT_DISTRIBUTOR_ACCOUNT_ID
+        } else {
+            SECOND_DISTRIBUTOR_ACCOUNT_ID
+        };
+        assert_eq!(
+            TestDataDistributionRegistry::submit_bandwidth_report(
+                RawOrigin::Signed(unsettled_account_id).into(),
+                unsettled_distributor_id,
+                0,
+            ),
+            Ok(())
+        );
+
+        run_to_block(epoch_length + 1);
+
+        assert!(TestDataDistributionRegistry::unsettled_distributors().is_empty());
+        assert_eq!(distributor_reward_amount(DEFAULT_DISTRIBUTOR_ID), 20);
+        assert_eq!(distributor_reward_amount(SECOND_DISTRIBUTOR_ID), 20);
+
+        // Unchanged rewards are not recorded again.
+        for (distributor_id, account_id) in [
+            (DEFAULT_DISTRIBUTOR_ID, DEFAULT_DISTRIBUTOR_ACCOUNT_ID),
+            (SECOND_DISTRIBUTOR_ID, SECOND_DISTRIBUTOR_ACCOUNT_ID),
+        ]
+        .iter()
+        {
+            if *distributor_id != unsettled_distributor_id {
+                assert_eq!(
+                    TestDataDistributionRegistry::submit_bandwidth_report(
+                        RawOrigin::Signed(*account_id).into(),
+                        *distributor_id,
+                        served_bytes,
+                    ),
+                    Ok(())
+                );
+            }
+        }
+
+        let settled_distributor_id = if unsettled_distributor_id == DEFAULT_DISTRIBUTOR_ID {
+            SECOND_DISTRIBUTOR_ID
+        } else {
+            DEFAULT_DISTRIBUTOR_ID
+        };
+        let reward_changes = || {
+            working_group::Module::<Test, DistributionWorkingGroupInstance>::num_reward_changes_by_worker_id(
+                settled_distributor_id,
+            )
+        };
+        let reward_changes_before = reward_changes();
+
+        run_to_block(2 * epoch_length + 1);
+
+        assert_eq!(reward_changes(), reward_changes_before);
+        assert_eq!(distributor_reward_amount(settled_distributor_id), 20);
+        assert_eq!(distributor_reward_amount(unsettled_distributor_id), 0);
+    });
+}
+
+#[test]
+fn distributor_is_removed_when_leaving_the_working_group() {
+    with_default_mock_builder(|| {
+        let bucket_id = set_up_distribution();
+
+        assert_eq!(
+            working_group::Module::<Test, DistributionWorkingGroupInstance>::leave_role(
+                RawOrigin::Signed(DEFAULT_DISTRIBUTOR_ACCOUNT_ID).into(),
+                DEFAULT_DISTRIBUTOR_ID,
+                b"rationale".to_vec(),
+            ),
+            Ok(())
+        );
+
+        assert!(TestDataDistributionRegistry::distributor_by_id(DEFAULT_DISTRIBUTOR_ID).is_none());
+        assert!(
+            TestDataDistributionRegistry::distribution_bucket_by_id(bucket_id)
+                .unwrap()
+                .distributors
+                .is_empty()
+        );
+    });
+}
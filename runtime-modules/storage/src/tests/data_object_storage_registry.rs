@@ -1,5 +1,8 @@
 #![cfg(test)]
 
+use frame_support::traits::{Currency, Get, ReservableCurrency};
+use frame_support::StorageMap;
+
 use super::mock::*;
 
 #[test]
@@ -162,3 +165,353 @@ fn test_toggle_ready() {
         );
     });
 }
+
+fn add_ready_relationship(account_id: u64, storage_provider_id: u32) -> u64 {
+    let dosr_id = TestDataObjectStorageRegistry::next_relationship_id();
+
+    assert_eq!(
+        TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            TEST_MOCK_EXISTING_CID,
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        TestDataObjectStorageRegistry::set_relationship_ready(
+            Origin::signed(account_id),
+            storage_provider_id,
+            dosr_id,
+        ),
+        Ok(())
+    );
+
+    dosr_id
+}
+
+#[test]
+fn challenge_relationship_succeeds() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let dosr_id = add_ready_relationship(account_id, storage_provider_id);
+        let member_id = 1;
+
+        let res = TestDataObjectStorageRegistry::challenge_relationship(
+            Origin::signed(1),
+            member_id,
+            dosr_id,
+        );
+        assert_eq!(res, Ok(()));
+
+        let challenge = TestDataObjectStorageRegistry::challenge_by_relationship_id(dosr_id)
+            .expect("Challenge should exist");
+        assert_eq!(challenge.challenger, Some(member_id));
+        assert!(challenge.chunk_index < mock_content_chunks().len() as u64);
+        assert_eq!(
+            Balances::reserved_balance(1),
+            <ChallengeDeposit as Get<u64>>::get()
+        );
+        assert_eq!(
+            challenge.deadline,
+            1 + <ChallengeResponsePeriod as Get<u64>>::get()
+        );
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::DataObjectStorageRelationshipChallenged(
+                    dosr_id,
+                    Some(member_id),
+                    challenge.chunk_index,
+                )
+            )
+        );
+
+        // Only one active challenge per relationship.
+        let res = TestDataObjectStorageRegistry::challenge_relationship(
+            Origin::signed(1),
+            member_id,
+            dosr_id,
+        );
+        assert_eq!(
+            res,
+            Err(data_object_storage_registry::Error::<Test>::ChallengeAlreadyExists.into())
+        );
+    });
+}
+
+#[test]
+fn challenge_relationship_fails_with_not_ready_relationship() {
+    with_default_mock_builder(|| {
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let dosr_id = TestDataObjectStorageRegistry::next_relationship_id();
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert!(res.is_ok());
+
+        let res =
+            TestDataObjectStorageRegistry::challenge_relationship(Origin::signed(1), 1, dosr_id);
+        assert_eq!(
+            res,
+            Err(data_object_storage_registry::Error::<Test>::RelationshipIsNotReady.into())
+        );
+    });
+}
+
+#[test]
+fn challenge_relationship_fails_with_insufficient_balance() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let dosr_id = add_ready_relationship(account_id, storage_provider_id);
+
+        let res =
+            TestDataObjectStorageRegistry::challenge_relationship(Origin::signed(5), 1, dosr_id);
+        assert_eq!(
+            res,
+            Err(
+                data_object_storage_registry::Error::<Test>::InsufficientBalanceForChallengeDeposit
+                    .into()
+            )
+        );
+        assert!(TestDataObjectStorageRegistry::challenge_by_relationship_id(dosr_id).is_none());
+    });
+}
+
+#[test]
+fn challenge_expirations_are_limited_per_block() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let first_dosr_id = add_ready_relationship(account_id, storage_provider_id);
+        let second_dosr_id = add_ready_relationship(account_id, storage_provider_id);
+
+        for dosr_id in &[first_dosr_id, second_dosr_id] {
+            let res = TestDataObjectStorageRegistry::challenge_relationship(
+                Origin::signed(1),
+                1,
+                *dosr_id,
+            );
+            assert_eq!(res, Ok(()));
+        }
+
+        let deadline = TestDataObjectStorageRegistry::challenge_by_relationship_id(first_dosr_id)
+            .unwrap()
+            .deadline;
+
+        // Only one challenge expires per block, the other one is postponed.
+        run_to_block(deadline + 1);
+        assert!(
+            TestDataObjectStorageRegistry::challenge_by_relationship_id(first_dosr_id).is_none()
+        );
+        assert!(
+            TestDataObjectStorageRegistry::challenge_by_relationship_id(second_dosr_id).is_some()
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::challenge_expirations(deadline + 2),
+            vec![second_dosr_id]
+        );
+
+        run_to_block(deadline + 2);
+        assert!(
+            TestDataObjectStorageRegistry::challenge_by_relationship_id(second_dosr_id).is_none()
+        );
+        assert_eq!(Balances::free_balance(1), TEST_MOCK_INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn respond_to_challenge_succeeds() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let dosr_id = add_ready_relationship(account_id, storage_provider_id);
+
+        let res =
+            TestDataObjectStorageRegistry::challenge_relationship(Origin::signed(1), 1, dosr_id);
+        assert_eq!(res, Ok(()));
+
+        let chunk_index = TestDataObjectStorageRegistry::challenge_by_relationship_id(dosr_id)
+            .unwrap()
+            .chunk_index as usize;
+        let (_, proof) = mock_chunk_merkle_proof(chunk_index);
+        let chunks = mock_content_chunks();
+
+        // Another storage provider.
+        let other_storage_provider_id = storage_provider_id + 1;
+        let other_account_id = account_id + 1;
+        <working_group::WorkerById<Test, StorageWorkingGroupInstance>>::insert(
+            other_storage_provider_id,
+            working_group::Worker {
+                member_id: 2,
+                role_account_id: other_account_id,
+                reward_relationship: None,
+                role_stake_profile: None,
+            },
+        );
+
+        let res = TestDataObjectStorageRegistry::respond_to_challenge(
+            Origin::signed(other_account_id),
+            other_storage_provider_id,
+            dosr_id,
+            chunks[chunk_index].clone(),
+            proof.clone(),
+        );
+        assert_eq!(
+            res,
+            Err(data_object_storage_registry::Error::<Test>::OnlyStorageProviderMayRespondToChallenge.into())
+        );
+
+        // Invalid chunk.
+        let res = TestDataObjectStorageRegistry::respond_to_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            dosr_id,
+            vec![0; 4],
+            proof.clone(),
+        );
+        assert_eq!(
+            res,
+            Err(data_object_storage_registry::Error::<Test>::InvalidChunkProof.into())
+        );
+
+        let res = TestDataObjectStorageRegistry::respond_to_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            dosr_id,
+            chunks[chunk_index].clone(),
+            proof,
+        );
+        assert_eq!(res, Ok(()));
+
+        assert!(TestDataObjectStorageRegistry::challenge_by_relationship_id(dosr_id).is_none());
+
+        // The challenge deposit is slashed.
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            Balances::free_balance(1),
+            TEST_MOCK_INITIAL_BALANCE - <ChallengeDeposit as Get<u64>>::get()
+        );
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::ChallengeAnswered(
+                    dosr_id,
+                    storage_provider_id,
+                )
+            )
+        );
+    });
+}
+
+#[test]
+fn unanswered_challenge_fails_after_deadline() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let dosr_id = add_ready_relationship(account_id, storage_provider_id);
+
+        let res =
+            TestDataObjectStorageRegistry::challenge_relationship(Origin::signed(1), 1, dosr_id);
+        assert_eq!(res, Ok(()));
+
+        let challenge =
+            TestDataObjectStorageRegistry::challenge_by_relationship_id(dosr_id).unwrap();
+        let (_, proof) = mock_chunk_merkle_proof(challenge.chunk_index as usize);
+
+        run_to_block(challenge.deadline + 1);
+
+        assert!(TestDataObjectStorageRegistry::challenge_by_relationship_id(dosr_id).is_none());
+
+        // The challenge deposit is returned.
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), TEST_MOCK_INITIAL_BALANCE);
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::failed_challenges_by_storage_provider(
+                storage_provider_id
+            ),
+            1
+        );
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::ChallengeFailed(
+                    dosr_id,
+                    storage_provider_id,
+                    1
+                )
+            )));
+
+        let res = TestDataObjectStorageRegistry::respond_to_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            dosr_id,
+            mock_content_chunks()[challenge.chunk_index as usize].clone(),
+            proof,
+        );
+        assert_eq!(
+            res,
+            Err(data_object_storage_registry::Error::<Test>::ChallengeNotFound.into())
+        );
+    });
+}
+
+#[test]
+fn periodic_challenges_recommend_slash_on_reaching_failures_threshold() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let dosr_id = add_ready_relationship(account_id, storage_provider_id);
+
+        let challenge_period = <ChallengePeriod as Get<u64>>::get();
+        run_to_block(challenge_period);
+
+        let challenge = TestDataObjectStorageRegistry::challenge_by_relationship_id(dosr_id)
+            .expect("Periodic challenge should exist");
+        assert_eq!(challenge.challenger, None);
+
+        // First failure.
+        run_to_block(challenge.deadline + 1);
+        assert_eq!(
+            TestDataObjectStorageRegistry::failed_challenges_by_storage_provider(
+                storage_provider_id
+            ),
+            1
+        );
+
+        // The next periodic challenge.
+        let next_challenge_block = (challenge.deadline / challenge_period + 1) * challenge_period;
+        run_to_block(next_challenge_block);
+
+        let challenge = TestDataObjectStorageRegistry::challenge_by_relationship_id(dosr_id)
+            .expect("Periodic challenge should exist");
+
+        // Second failure reaches the threshold. Slash amount is zero in the mock,
+        // so only the recommendation is emitted.
+        run_to_block(challenge.deadline + 1);
+        assert_eq!(
+            TestDataObjectStorageRegistry::failed_challenges_by_storage_provider(
+                storage_provider_id
+            ),
+            0
+        );
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageProviderSlashRecommended(
+                    storage_provider_id,
+                    <ChallengeFailuresSlashThreshold as Get<u32>>::get()
+                )
+            )));
+    });
+}
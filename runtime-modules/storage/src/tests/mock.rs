@@ -6,19 +6,22 @@ use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Hash, IdentityLookup},
     Perbill,
 };
 
 use crate::data_directory::ContentIdExists;
 use crate::data_object_type_registry::IsActiveDataObjectType;
-pub use crate::StorageWorkingGroupInstance;
-pub use crate::{data_directory, data_object_storage_registry, data_object_type_registry};
+pub use crate::{
+    data_directory, data_distribution_registry, data_object_storage_registry,
+    data_object_type_registry,
+};
+pub use crate::{DistributionWorkingGroupInstance, StorageWorkingGroupInstance};
 use common::currency::GovernanceCurrency;
 use membership;
 
 mod working_group_mod {
-    pub use super::StorageWorkingGroupInstance;
+    pub use super::{DistributionWorkingGroupInstance, StorageWorkingGroupInstance};
     pub use working_group::Event;
 }
 
@@ -35,9 +38,11 @@ impl_outer_event! {
         data_object_type_registry<T>,
         data_directory<T>,
         data_object_storage_registry<T>,
+        data_distribution_registry<T>,
         balances<T>,
         members<T>,
         working_group_mod StorageWorkingGroupInstance <T>,
+        working_group_mod DistributionWorkingGroupInstance <T>,
         system<T>,
     }
 }
@@ -49,6 +54,8 @@ pub const TEST_FIRST_METADATA_ID: u64 = 4000;
 
 pub const TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID: u32 = 1;
 pub const TEST_MOCK_EXISTING_CID: u64 = 42;
+pub const TEST_MOCK_CHUNK_SIZE: u64 = 4;
+pub const TEST_MOCK_INITIAL_BALANCE: u64 = 1000;
 
 pub struct AnyDataObjectTypeIsActive {}
 impl<T: data_object_type_registry::Trait> IsActiveDataObjectType<T> for AnyDataObjectTypeIsActive {
@@ -69,7 +76,7 @@ impl ContentIdExists<Test> for MockContent {
         match *which {
             TEST_MOCK_EXISTING_CID => Ok(data_directory::DataObjectInternal {
                 type_id: 1,
-                size: 1234,
+                size: TEST_MOCK_CHUNK_SIZE * mock_content_chunks().len() as u64,
                 added_at: data_directory::BlockAndTime {
                     block: 10,
                     time: 1024,
@@ -78,12 +85,54 @@ impl ContentIdExists<Test> for MockContent {
                 liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
                 liaison_judgement: data_directory::LiaisonJudgement::Pending,
                 ipfs_content_id: vec![],
+                content_hash: BlakeTwo256::hash(&mock_content_chunks().concat()),
+                chunk_manifest: Some(data_directory::ChunkManifest {
+                    merkle_root: mock_chunk_merkle_proof(0).0,
+                    chunk_size: TEST_MOCK_CHUNK_SIZE,
+                }),
             }),
             _ => Err("nope, missing"),
         }
     }
 }
 
+// Chunks of the mock existing content.
+pub fn mock_content_chunks() -> Vec<Vec<u8>> {
+    vec![vec![1; 4], vec![2; 4], vec![3; 4], vec![4; 4]]
+}
+
+// Builds the Merkle tree over the mock content chunks.
+// Returns the Merkle root and the proof for the provided chunk index.
+pub fn mock_chunk_merkle_proof(chunk_index: usize) -> (H256, Vec<H256>) {
+    chunk_merkle_proof(&mock_content_chunks(), chunk_index)
+}
+
+// Builds the Merkle tree over the provided chunks, pairing a node without a sibling with itself.
+// Returns the Merkle root and the proof for the provided chunk index.
+pub fn chunk_merkle_proof(chunks: &[Vec<u8>], chunk_index: usize) -> (H256, Vec<H256>) {
+    let mut level: Vec<H256> = chunks
+        .iter()
+        .map(|chunk| BlakeTwo256::hash_of(&(data_directory::CHUNK_LEAF_PREFIX, chunk)))
+        .collect();
+    let mut index = chunk_index;
+    let mut proof = Vec::new();
+
+    while level.len() > 1 {
+        proof.push(*level.get(index ^ 1).unwrap_or(&level[index]));
+
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                let right = *pair.get(1).unwrap_or(&pair[0]);
+                BlakeTwo256::hash_of(&(data_directory::CHUNK_NODE_PREFIX, pair[0], right))
+            })
+            .collect();
+        index /= 2;
+    }
+
+    (level[0], proof)
+}
+
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Test;
@@ -94,6 +143,18 @@ parameter_types! {
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MinimumPeriod: u64 = 5;
     pub const MaxObjectsPerInjection: u32 = 5;
+    pub const ChallengeResponsePeriod: u64 = 10;
+    pub const ChallengePeriod: u64 = 5;
+    pub const MaxChallengesPerPeriod: u32 = 1;
+    pub const MaxChallengeExpirationsPerBlock: u32 = 1;
+    pub const ChallengeDeposit: u64 = 10;
+    pub const ChallengeFailuresSlashThreshold: u32 = 2;
+    pub const ChallengeFailureSlashAmount: u64 = 0;
+    pub const DistributionEpochLength: u64 = 10;
+    pub const RewardPerMegabyte: u64 = 2;
+    pub const MaxRewardPerEpoch: u64 = 100;
+    pub const MaxBucketsPerDistributor: u32 = 2;
+    pub const MaxSettlementsPerBlock: u32 = 1;
 }
 
 impl system::Trait for Test {
@@ -153,6 +214,13 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WorkerExitHandler = ();
+}
+
+impl working_group::Trait<DistributionWorkingGroupInstance> for Test {
+    type Event = MetaEvent;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WorkerExitHandler = TestDataDistributionRegistry;
 }
 
 impl data_object_type_registry::Trait for Test {
@@ -187,6 +255,25 @@ impl data_object_storage_registry::Trait for Test {
     type Event = MetaEvent;
     type DataObjectStorageRelationshipId = u64;
     type ContentIdExists = MockContent;
+    type Randomness = ();
+    type ChallengeResponsePeriod = ChallengeResponsePeriod;
+    type ChallengePeriod = ChallengePeriod;
+    type MaxChallengesPerPeriod = MaxChallengesPerPeriod;
+    type MaxChallengeExpirationsPerBlock = MaxChallengeExpirationsPerBlock;
+    type ChallengeDeposit = ChallengeDeposit;
+    type ChallengeFailuresSlashThreshold = ChallengeFailuresSlashThreshold;
+    type ChallengeFailureSlashAmount = ChallengeFailureSlashAmount;
+}
+
+impl data_distribution_registry::Trait for Test {
+    type Event = MetaEvent;
+    type DistributionBucketId = u64;
+    type ContentIdExists = MockContent;
+    type EpochLength = DistributionEpochLength;
+    type RewardPerMegabyte = RewardPerMegabyte;
+    type MaxRewardPerEpoch = MaxRewardPerEpoch;
+    type MaxBucketsPerDistributor = MaxBucketsPerDistributor;
+    type MaxSettlementsPerBlock = MaxSettlementsPerBlock;
 }
 
 impl membership::Trait for Test {
@@ -269,6 +356,12 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .unwrap();
 
+        balances::GenesisConfig::<Test> {
+            balances: vec![(1, TEST_MOCK_INITIAL_BALANCE)],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
         data_object_storage_registry::GenesisConfig::<Test> {
             first_relationship_id: self.first_relationship_id,
         }
@@ -301,6 +394,7 @@ pub type System = system::Module<Test>;
 pub type TestDataObjectTypeRegistry = data_object_type_registry::Module<Test>;
 pub type TestDataDirectory = data_directory::Module<Test>;
 pub type TestDataObjectStorageRegistry = data_object_storage_registry::Module<Test>;
+pub type TestDataDistributionRegistry = data_distribution_registry::Module<Test>;
 
 pub fn with_default_mock_builder<R, F: FnOnce() -> R>(f: F) -> R {
     ExtBuilder::default()
@@ -339,10 +433,12 @@ pub fn run_to_block(n: u64) {
         <TestDataObjectTypeRegistry as OnFinalize<u64>>::on_finalize(System::block_number());
         <TestDataDirectory as OnFinalize<u64>>::on_finalize(System::block_number());
         <TestDataObjectStorageRegistry as OnFinalize<u64>>::on_finalize(System::block_number());
+        <TestDataDistributionRegistry as OnFinalize<u64>>::on_finalize(System::block_number());
         System::set_block_number(System::block_number() + 1);
         <System as OnInitialize<u64>>::on_initialize(System::block_number());
         <TestDataObjectTypeRegistry as OnInitialize<u64>>::on_initialize(System::block_number());
         <TestDataDirectory as OnInitialize<u64>>::on_initialize(System::block_number());
         <TestDataObjectStorageRegistry as OnInitialize<u64>>::on_initialize(System::block_number());
+        <TestDataDistributionRegistry as OnInitialize<u64>>::on_initialize(System::block_number());
     }
 }
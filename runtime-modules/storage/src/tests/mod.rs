@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 mod data_directory;
+mod data_distribution_registry;
 mod data_object_storage_registry;
 mod data_object_type_registry;
 mod mock;
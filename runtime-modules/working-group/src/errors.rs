@@ -305,6 +305,36 @@ decl_error! {
 
         /// Minting error: NextAdjustmentInPast
         MintingErrorNextAdjustmentInPast,
+
+        /// Member is suspended.
+        MemberSuspended,
+
+        /// Minting error: NotEnoughCapacity
+        MintingErrorNotEnoughCapacity,
+
+        /// Budget period cannot be zero.
+        BudgetPeriodCannotBeZero,
+
+        /// Budget plan contains too many expenses.
+        BudgetPlanTooManyExpenses,
+
+        /// Budget plan contains more worker rewards than active workers.
+        BudgetPlanTooManyWorkerRewards,
+
+        /// Spending amount cannot be zero.
+        SpendingAmountCannotBeZero,
+
+        /// Spending description or rationale text is too short.
+        SpendingTextTooShort,
+
+        /// Spending description or rationale text is too long.
+        SpendingTextTooLong,
+
+        /// Payment exceeds the discretionary spending cap for the current period.
+        DiscretionarySpendingCapExceeded,
+
+        /// Review period should not end before it starts or end in the future.
+        InvalidReviewPeriod,
     }
 }
 
@@ -682,3 +712,14 @@ impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<minting::Gener
         }
     }
 }
+
+impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<minting::TransferError>>
+    for Error<T, I>
+{
+    fn from(wrapper: WrappedError<minting::TransferError>) -> Self {
+        match wrapper.error {
+            minting::TransferError::MintNotFound => Error::CannotFindMint,
+            minting::TransferError::NotEnoughCapacity => Error::MintingErrorNotEnoughCapacity,
+        }
+    }
+}
@@ -31,8 +31,15 @@
 //! - [update_reward_amount](./struct.Module.html#method.update_reward_amount) -  Update the reward amount of the worker/lead.
 //! - [leave_role](./struct.Module.html#method.leave_role) - Leave the role by the active worker/lead.
 //! - [terminate_role](./struct.Module.html#method.terminate_role) - Terminate the worker/lead role.
+//! - [review_worker](./struct.Module.html#method.review_worker) - Submit the worker/lead performance review with optional reward change and slash.
 //! - [set_mint_capacity](./struct.Module.html#method.set_mint_capacity) -  Sets the capacity to enable working group budget.
 //!
+//! ### Budget
+//!
+//! - [submit_budget_plan](./struct.Module.html#method.submit_budget_plan) - Submit the periodic budget plan of the working group.
+//! - [spend_from_mint](./struct.Module.html#method.spend_from_mint) - Make a one-off payment from the working group mint.
+//! - [set_discretionary_spending_cap](./struct.Module.html#method.set_discretionary_spending_cap) - Sets the per-period cap on the lead payments.
+//!
 //! ### Stakes
 //!
 //! - [slash_stake](./struct.Module.html#method.slash_stake) - Slashes the worker/lead stake.
@@ -56,7 +63,7 @@ use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::storage::IterableStorageMap;
 use frame_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
 use frame_support::{decl_event, decl_module, decl_storage, ensure, print, StorageValue};
-use sp_arithmetic::traits::{Bounded, One, Zero};
+use sp_arithmetic::traits::{Bounded, CheckedAdd, One, Zero};
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::vec;
 use sp_std::vec::Vec;
@@ -68,8 +75,9 @@ use errors::WrappedError;
 
 pub use errors::Error;
 pub use types::{
-    Application, Opening, OpeningPolicyCommitment, OpeningType, RewardPolicy, RoleStakeProfile,
-    Worker,
+    Application, BudgetPlan, DiscretionarySpendingLimit, Opening, OpeningPolicyCommitment,
+    OpeningType, PlannedExpense, ReviewRating, RewardChange, RewardPolicy, RoleStakeProfile,
+    SpendingRecord, Worker, WorkerReview,
 };
 
 /// Stake identifier in staking module
@@ -111,6 +119,15 @@ pub type WorkerId<T> = <T as membership::Trait>::ActorId;
 /// Alias for the application id from the hiring module.
 pub type HiringApplicationId<T> = <T as hiring::Trait>::ApplicationId;
 
+/// Type identifier for the spending log record.
+pub type SpendingRecordId = u64;
+
+/// Type identifier for the worker performance review.
+pub type ReviewId = u64;
+
+/// Maximum number of one-off expenses in the budget plan.
+pub const MAX_BUDGET_PLAN_EXPENSES: usize = 100;
+
 // Type simplification
 type OpeningInfo<T> = (
     OpeningOf<T>,
@@ -143,6 +160,33 @@ type OpeningOf<T> = Opening<
     ApplicationId<T>,
 >;
 
+// Type simplification
+type BudgetPlanOf<T> = BudgetPlan<WorkerId<T>, BalanceOfMint<T>, <T as system::Trait>::BlockNumber>;
+
+// Type simplification
+type SpendingRecordOf<T> = SpendingRecord<
+    <T as system::Trait>::AccountId,
+    BalanceOfMint<T>,
+    <T as system::Trait>::BlockNumber,
+>;
+
+// Type simplification
+type DiscretionarySpendingLimitOf<T> =
+    DiscretionarySpendingLimit<BalanceOfMint<T>, <T as system::Trait>::BlockNumber>;
+
+// Type simplification
+type WorkerReviewOf<T> = WorkerReview<
+    WorkerId<T>,
+    <T as system::Trait>::BlockNumber,
+    <T as system::Trait>::Hash,
+    BalanceOfMint<T>,
+    BalanceOf<T>,
+>;
+
+// Type simplification
+type RewardChangeOf<T> =
+    RewardChange<BalanceOfMint<T>, <T as system::Trait>::BlockNumber, ReviewId>;
+
 // Type simplification
 type ApplicationOf<T> =
     Application<<T as system::Trait>::AccountId, OpeningId<T>, MemberId<T>, HiringApplicationId<T>>;
@@ -161,6 +205,32 @@ pub trait Trait<I: Instance>:
 
     /// Defines max workers number in the working group.
     type MaxWorkerNumberLimit: Get<u32>;
+
+    /// Handles the worker leaving the working group (exit or termination).
+    type WorkerExitHandler: WorkerExitHandler<Self, I>;
+}
+
+/// Handles the worker leaving the working group.
+pub trait WorkerExitHandler<T: Trait<I>, I: Instance> {
+    /// The worker with the given id left the working group: exited or was terminated.
+    /// The worker is already removed from the storage.
+    fn worker_exited(worker_id: &WorkerId<T>);
+}
+
+impl<T: Trait<I>, I: Instance> WorkerExitHandler<T, I> for () {
+    fn worker_exited(_worker_id: &WorkerId<T>) {}
+}
+
+/// Helper implementation so we can provide multiple handlers by grouping handlers in tuple pairs.
+/// For example for three handlers, A, B and C we can set the WorkerExitHandler type on the trait to:
+/// type WorkerExitHandler = ((A, B), C)
+impl<T: Trait<I>, I: Instance, X: WorkerExitHandler<T, I>, Y: WorkerExitHandler<T, I>>
+    WorkerExitHandler<T, I> for (X, Y)
+{
+    fn worker_exited(worker_id: &WorkerId<T>) {
+        X::worker_exited(worker_id);
+        Y::worker_exited(worker_id);
+    }
 }
 
 decl_event!(
@@ -277,6 +347,29 @@ decl_event!(
         /// - mint id.
         /// - new mint balance.
         MintCapacityChanged(MintId, MintBalanceOf),
+
+        /// Emits on submitting the budget plan by the lead.
+        /// Params:
+        /// - leader worker id.
+        BudgetPlanSubmitted(WorkerId),
+
+        /// Emits on setting the discretionary spending cap.
+        /// Params:
+        /// - cap per spending period.
+        DiscretionarySpendingCapSet(MintBalanceOf),
+
+        /// Emits on the one-off payment from the working group mint.
+        /// Params:
+        /// - spending log record id.
+        /// - recipient account id.
+        /// - payment amount.
+        SpentFromMint(SpendingRecordId, AccountId, MintBalanceOf),
+
+        /// Emits on submitting the worker performance review.
+        /// Params:
+        /// - worker id.
+        /// - review id.
+        WorkerReviewed(WorkerId, ReviewId),
     }
 );
 
@@ -325,6 +418,45 @@ decl_storage! {
         /// Required by StakingEventsHandler callback call to refund the balance on unstaking.
         pub MemberIdByHiringApplicationId get(fn member_id_by_hiring_application_id):
             map hasher(blake2_128_concat) HiringApplicationId<T> =>  MemberId<T>;
+
+        /// Current budget plan submitted by the lead.
+        pub CurrentBudgetPlan get(fn current_budget_plan): Option<BudgetPlanOf<T>>;
+
+        /// Per-period cap on the one-off payments from the working group mint.
+        pub DiscretionarySpending get(fn discretionary_spending): DiscretionarySpendingLimitOf<T>;
+
+        /// Log of the one-off payments from the working group mint.
+        pub SpendingLog get(fn spending_log): map hasher(blake2_128_concat)
+            SpendingRecordId => SpendingRecordOf<T>;
+
+        /// Next identifier for the spending log record.
+        pub NextSpendingRecordId get(fn next_spending_record_id): SpendingRecordId;
+
+        /// Maps identifier to the worker performance review. Reviews outlive the worker.
+        pub WorkerReviewById get(fn worker_review_by_id): map hasher(blake2_128_concat)
+            ReviewId => WorkerReviewOf<T>;
+
+        /// Next identifier for the worker performance review.
+        pub NextReviewId get(fn next_review_id): ReviewId;
+
+        /// Performance reviews of the worker, by their number starting at 0.
+        pub ReviewIdByWorkerId get(fn review_id_by_worker_id): double_map
+            hasher(blake2_128_concat) WorkerId<T>,
+            hasher(blake2_128_concat) u32 => ReviewId;
+
+        /// Number of the performance reviews of the worker.
+        pub NumReviewsByWorkerId get(fn num_reviews_by_worker_id): map hasher(blake2_128_concat)
+            WorkerId<T> => u32;
+
+        /// Reward amount changes of the worker, by their number starting at 0.
+        /// History outlives the worker.
+        pub RewardChangeByWorkerId get(fn reward_change_by_worker_id): double_map
+            hasher(blake2_128_concat) WorkerId<T>,
+            hasher(blake2_128_concat) u32 => RewardChangeOf<T>;
+
+        /// Number of the reward amount changes of the worker.
+        pub NumRewardChangesByWorkerId get(fn num_reward_changes_by_worker_id):
+            map hasher(blake2_128_concat) WorkerId<T> => u32;
     }
         add_extra_genesis {
         config(phantom): sp_std::marker::PhantomData<I>;
@@ -416,6 +548,8 @@ decl_module! {
         }
 
         /// Update the reward amount associated with a set reward relationship for the active worker.
+        /// The change is recorded in the worker reward history without a review id, use
+        /// `review_worker` to link the change to a performance review.
         /// Require signed leader origin or the root (to update leader reward amount).
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn update_reward_amount(
@@ -436,18 +570,114 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            // Update only the reward account.
-            ensure_on_wrapped_error!(
-                recurringrewards::Module::<T>::set_reward_relationship(
+            Self::update_worker_reward_amount(&worker_id, relationship_id, new_amount, None)?;
+        }
+
+        /// Submit the performance review of the active worker. The review can cut or raise
+        /// the worker reward and slash the worker stake, these changes are linked to the review.
+        /// Require signed leader origin or the root (to review the leader).
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn review_worker(
+            origin,
+            worker_id: WorkerId<T>,
+            period_start: T::BlockNumber,
+            period_end: T::BlockNumber,
+            rating: ReviewRating,
+            rationale_hash: T::Hash,
+            new_reward_amount: Option<BalanceOfMint<T>>,
+            slash_amount: Option<BalanceOf<T>>
+        ) {
+            // Ensure lead is set and is origin signer or it is the council.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            // Ensuring worker actually exists
+            let worker = Self::ensure_worker_exists(&worker_id)?;
+
+            let current_block = <system::Module<T>>::block_number();
+
+            ensure!(
+                period_start <= period_end && period_end <= current_block,
+                Error::<T, I>::InvalidReviewPeriod
+            );
+
+            // Ensure the worker actually has a recurring reward
+            let opt_relationship_id = if new_reward_amount.is_some() {
+                let relationship_id = Self::ensure_worker_has_recurring_reward(&worker)?;
+
+                // The reward update can't fail once the relationship exists.
+                ensure!(
+                    <recurringrewards::RewardRelationships<T>>::contains_key(relationship_id),
+                    Error::<T, I>::RecurringRewardsRewardRelationshipNotFound
+                );
+
+                Some(relationship_id)
+            } else {
+                None
+            };
+
+            if let Some(slash_amount) = slash_amount {
+                ensure!(slash_amount != <BalanceOf<T>>::zero(), Error::<T, I>::StakeBalanceCannotBeZero);
+
+                let stake_profile = worker
+                    .role_stake_profile
+                    .as_ref()
+                    .ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
+
+                // The slash can't fail once the stake is staked, so no changes are left
+                // behind by a failed review.
+                ensure!(
+                    <stake::Stakes<T>>::contains_key(&stake_profile.stake_id),
+                    Error::<T, I>::StakingErrorStakeNotFound
+                );
+
+                ensure!(
+                    matches!(
+                        <stake::Module<T>>::stakes(&stake_profile.stake_id).staking_status,
+                        stake::StakingStatus::Staked(_)
+                    ),
+                    Error::<T, I>::StakingErrorNotStaked
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let review_id = Self::next_review_id();
+
+            if let Some(slash_amount) = slash_amount {
+                Self::slash_worker_stake(&worker_id, slash_amount)?;
+            }
+
+            if let (Some(relationship_id), Some(new_amount)) = (opt_relationship_id, new_reward_amount) {
+                Self::update_worker_reward_amount(
+                    &worker_id,
                     relationship_id,
-                    None, // new_account
-                    Some(new_amount), // new_payout
-                    None, //new_next_payment_at
-                    None) //new_payout_interval
-            )?;
+                    new_amount,
+                    Some(review_id)
+                )?;
+            }
+
+            let review = WorkerReview {
+                worker_id,
+                period_start,
+                period_end,
+                rating,
+                rationale_hash,
+                new_reward_amount,
+                slashed_amount: slash_amount,
+                reviewed_at: current_block,
+            };
+
+            <WorkerReviewById<T, I>>::insert(review_id, review);
+            <NextReviewId<I>>::mutate(|id| *id += 1);
+
+            let review_nr = Self::num_reviews_by_worker_id(worker_id);
+            <ReviewIdByWorkerId<T, I>>::insert(worker_id, review_nr, review_id);
+            <NumReviewsByWorkerId<T, I>>::insert(worker_id, review_nr + 1);
 
             // Trigger event
-            Self::deposit_event(RawEvent::WorkerRewardAmountUpdated(worker_id));
+            Self::deposit_event(RawEvent::WorkerReviewed(worker_id, review_id));
         }
 
         /// Leave the role by the active worker.
@@ -618,6 +848,12 @@ decl_module! {
                 Error::<T, I>::OriginIsNeitherMemberControllerOrRoot
             );
 
+            // Suspended members can't apply
+            ensure!(
+                !membership::Module::<T>::is_member_suspended(&member_id),
+                Error::<T, I>::MemberSuspended
+            );
+
             // Ensure worker opening exists
             let (opening, _opening) = Self::ensure_opening_exists(&opening_id)?;
 
@@ -893,27 +1129,7 @@ decl_module! {
             // Ensure lead is set or it is the council terminating the leader.
             Self::ensure_origin_for_leader(origin, worker_id)?;
 
-            // Ensuring worker actually exists.
-            let worker = Self::ensure_worker_exists(&worker_id)?;
-
-            ensure!(balance != <BalanceOf<T>>::zero(), Error::<T, I>::StakeBalanceCannotBeZero);
-
-            let stake_profile = worker.role_stake_profile.ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
-
-            //
-            // == MUTATION SAFE ==
-            //
-
-            // This external module call both checks and mutates the state.
-            ensure_on_wrapped_error!(
-                <stake::Module<T>>::slash_immediate(
-                    &stake_profile.stake_id,
-                    balance,
-                    false
-                )
-            )?;
-
-            Self::deposit_event(RawEvent::StakeSlashed(worker_id));
+            Self::slash_worker_stake(&worker_id, balance)?;
         }
 
         /// Decreases the worker/lead stake and returns the remainder to the worker role_account_id.
@@ -1003,6 +1219,130 @@ decl_module! {
                 Self::deposit_event(RawEvent::MintCapacityChanged(mint_id, new_capacity));
             }
         }
+
+        // ****************** Budget **********************
+
+        /// Submit the periodic budget plan: reward totals per worker and one-off expenses.
+        /// Replaces the previous plan. Require signed leader origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn submit_budget_plan(
+            origin,
+            worker_rewards: BTreeMap<WorkerId<T>, BalanceOfMint<T>>,
+            expenses: Vec<PlannedExpense<BalanceOfMint<T>>>,
+            period: T::BlockNumber
+        ) {
+            // Ensure lead is set and is origin signer
+            Self::ensure_origin_is_active_leader(origin)?;
+
+            let leader_worker_id = Self::ensure_lead_is_set()?;
+
+            ensure!(period != Zero::zero(), Error::<T, I>::BudgetPeriodCannotBeZero);
+
+            ensure!(
+                expenses.len() <= MAX_BUDGET_PLAN_EXPENSES,
+                Error::<T, I>::BudgetPlanTooManyExpenses
+            );
+
+            ensure!(
+                worker_rewards.len() <= Self::active_worker_count() as usize,
+                Error::<T, I>::BudgetPlanTooManyWorkerRewards
+            );
+
+            for worker_id in worker_rewards.keys() {
+                Self::ensure_worker_exists(worker_id)?;
+            }
+
+            for expense in expenses.iter() {
+                Self::ensure_spending_text_is_valid(&expense.description)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let budget_plan = BudgetPlan {
+                worker_rewards,
+                expenses,
+                period,
+                submitted_at: <system::Module<T>>::block_number(),
+            };
+
+            <CurrentBudgetPlan<T, I>>::put(budget_plan);
+
+            Self::deposit_event(RawEvent::BudgetPlanSubmitted(leader_worker_id));
+        }
+
+        /// Make a one-off payment from the working group mint and record it in the spending log.
+        /// Require signed leader origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn spend_from_mint(
+            origin,
+            recipient: T::AccountId,
+            amount: BalanceOfMint<T>,
+            rationale: Vec<u8>
+        ) {
+            // Ensure lead is set and is origin signer
+            Self::ensure_origin_is_active_leader(origin)?;
+
+            ensure!(amount != Zero::zero(), Error::<T, I>::SpendingAmountCannotBeZero);
+
+            Self::ensure_spending_text_is_valid(&rationale)?;
+
+            let spending_limit = Self::ensure_discretionary_spending_allowed(amount)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This external module call both checks and mutates the state.
+            ensure_on_wrapped_error!(
+                <minting::Module<T>>::transfer_tokens(Self::mint(), amount, &recipient)
+            )?;
+
+            <DiscretionarySpending<T, I>>::put(spending_limit);
+
+            let record_id = Self::next_spending_record_id();
+
+            let record = SpendingRecord {
+                recipient: recipient.clone(),
+                amount,
+                rationale,
+                spent_at: <system::Module<T>>::block_number(),
+            };
+
+            <SpendingLog<T, I>>::insert(record_id, record);
+            <NextSpendingRecordId<I>>::mutate(|id| *id += 1);
+
+            Self::deposit_event(RawEvent::SpentFromMint(record_id, recipient, amount));
+        }
+
+        /// Sets the cap on the one-off payments of the lead per spending period.
+        /// Starts a new spending period. Requires root origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_discretionary_spending_cap(
+            origin,
+            cap: BalanceOfMint<T>,
+            period: T::BlockNumber
+        ) {
+            ensure_root(origin)?;
+
+            ensure!(period != Zero::zero(), Error::<T, I>::BudgetPeriodCannotBeZero);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let spending_limit = DiscretionarySpendingLimit {
+                cap,
+                period,
+                period_start: <system::Module<T>>::block_number(),
+                spent_in_period: Zero::zero(),
+            };
+
+            <DiscretionarySpending<T, I>>::put(spending_limit);
+
+            Self::deposit_event(RawEvent::DiscretionarySpendingCapSet(cap));
+        }
     }
 }
 
@@ -1320,6 +1660,45 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         }
     }
 
+    fn ensure_spending_text_is_valid(text: &[u8]) -> DispatchResult {
+        default_text_constraint()
+            .ensure_valid(
+                text.len(),
+                Error::<T, I>::SpendingTextTooShort.into(),
+                Error::<T, I>::SpendingTextTooLong.into(),
+            )
+            .map_err(|e| DispatchError::Other(e))
+    }
+
+    // Returns the discretionary spending limit updated with the payment amount.
+    fn ensure_discretionary_spending_allowed(
+        amount: BalanceOfMint<T>,
+    ) -> Result<DiscretionarySpendingLimitOf<T>, Error<T, I>> {
+        let mut spending_limit = Self::discretionary_spending();
+
+        let current_block = <system::Module<T>>::block_number();
+
+        // Start a new spending period.
+        if current_block >= spending_limit.period_start + spending_limit.period {
+            spending_limit.period_start = current_block;
+            spending_limit.spent_in_period = Zero::zero();
+        }
+
+        let spent_in_period = spending_limit
+            .spent_in_period
+            .checked_add(&amount)
+            .ok_or(Error::<T, I>::DiscretionarySpendingCapExceeded)?;
+
+        ensure!(
+            spent_in_period <= spending_limit.cap,
+            Error::<T, I>::DiscretionarySpendingCapExceeded
+        );
+
+        spending_limit.spent_in_period = spent_in_period;
+
+        Ok(spending_limit)
+    }
+
     fn ensure_worker_exit_rationale_text_is_valid(text: &[u8]) -> DispatchResult {
         Self::worker_exit_rationale_text()
             .ensure_valid(
@@ -1396,6 +1775,116 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             .collect()
     }
 
+    /// Slashes the worker stake without the origin checks. Intended for the modules built
+    /// on top of the working group (eg.: storage proofs). No actions on zero stake.
+    /// If slashing balance greater than the existing stake - stake is slashed to zero.
+    pub fn slash_worker_stake(worker_id: &WorkerId<T>, balance: BalanceOf<T>) -> DispatchResult {
+        // Ensuring worker actually exists.
+        let worker = Self::ensure_worker_exists(worker_id)?;
+
+        ensure!(
+            balance != <BalanceOf<T>>::zero(),
+            Error::<T, I>::StakeBalanceCannotBeZero
+        );
+
+        let stake_profile = worker
+            .role_stake_profile
+            .ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        // This external module call both checks and mutates the state.
+        ensure_on_wrapped_error!(<stake::Module<T>>::slash_immediate(
+            &stake_profile.stake_id,
+            balance,
+            false
+        ))?;
+
+        Self::deposit_event(RawEvent::StakeSlashed(*worker_id));
+
+        Ok(())
+    }
+
+    /// Returns the reward amount per payout of the worker recurring reward, if any.
+    pub fn worker_reward_amount(worker_id: &WorkerId<T>) -> Option<BalanceOfMint<T>> {
+        let worker = Self::ensure_worker_exists(worker_id).ok()?;
+
+        let relationship_id = worker.reward_relationship?;
+
+        Some(recurringrewards::Module::<T>::reward_relationships(relationship_id).amount_per_payout)
+    }
+
+    /// Sets the reward amount of the worker recurring reward without the origin checks.
+    /// Intended for the modules built on top of the working group (eg.: distributor bandwidth
+    /// rewards).
+    pub fn set_worker_reward_amount(
+        worker_id: &WorkerId<T>,
+        new_amount: BalanceOfMint<T>,
+    ) -> DispatchResult {
+        // Ensuring worker actually exists.
+        let worker = Self::ensure_worker_exists(worker_id)?;
+
+        // Ensure the worker actually has a recurring reward
+        let relationship_id = Self::ensure_worker_has_recurring_reward(&worker)?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        Self::update_worker_reward_amount(worker_id, relationship_id, new_amount, None)
+    }
+
+    // Updates the reward amount and records the change in the worker reward history.
+    fn update_worker_reward_amount(
+        worker_id: &WorkerId<T>,
+        relationship_id: T::RewardRelationshipId,
+        new_amount: BalanceOfMint<T>,
+        review_id: Option<ReviewId>,
+    ) -> DispatchResult {
+        let old_amount =
+            recurringrewards::Module::<T>::reward_relationships(relationship_id).amount_per_payout;
+
+        // Update only the reward amount.
+        ensure_on_wrapped_error!(recurringrewards::Module::<T>::set_reward_relationship(
+            relationship_id,
+            None,             // new_account
+            Some(new_amount), // new_payout
+            None,             //new_next_payment_at
+            None              //new_payout_interval
+        ))?;
+
+        let reward_change = RewardChange {
+            old_amount,
+            new_amount,
+            review_id,
+            changed_at: <system::Module<T>>::block_number(),
+        };
+
+        let change_nr = Self::num_reward_changes_by_worker_id(worker_id);
+        <RewardChangeByWorkerId<T, I>>::insert(worker_id, change_nr, reward_change);
+        <NumRewardChangesByWorkerId<T, I>>::insert(worker_id, change_nr + 1);
+
+        Self::deposit_event(RawEvent::WorkerRewardAmountUpdated(*worker_id));
+
+        Ok(())
+    }
+
+    /// Returns the reward amount changes of the worker in the chronological order.
+    pub fn get_worker_reward_history(worker_id: &WorkerId<T>) -> Vec<RewardChangeOf<T>> {
+        (0..Self::num_reward_changes_by_worker_id(worker_id))
+            .map(|change_nr| Self::reward_change_by_worker_id(worker_id, change_nr))
+            .collect()
+    }
+
+    /// Returns the performance review id list of the worker in the chronological order.
+    pub fn get_worker_review_ids(worker_id: &WorkerId<T>) -> Vec<ReviewId> {
+        (0..Self::num_reviews_by_worker_id(worker_id))
+            .map(|review_nr| Self::review_id_by_worker_id(worker_id, review_nr))
+            .collect()
+    }
+
     /// Returns all existing worker id list.
     pub fn get_all_worker_ids() -> Vec<WorkerId<T>> {
         <WorkerById<T, I>>::iter()
@@ -1465,6 +1954,8 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         WorkerById::<T, I>::remove(worker_id);
         Self::decrease_active_worker_counter();
 
+        T::WorkerExitHandler::worker_exited(worker_id);
+
         // Trigger the event
         let event = match exit_initiation_origin {
             ExitInitiationOrigin::Lead => {
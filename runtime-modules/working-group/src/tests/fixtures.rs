@@ -1,6 +1,7 @@
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::storage::{StorageMap, StorageValue};
-use std::collections::BTreeSet;
+use sp_core::H256;
+use std::collections::{BTreeMap, BTreeSet};
 use system::{EventRecord, Phase, RawOrigin};
 
 use super::mock::{
@@ -8,8 +9,9 @@ use super::mock::{
 };
 use crate::tests::fill_worker_position;
 use crate::types::{
-    Application, Opening, OpeningPolicyCommitment, OpeningType, RewardPolicy, RoleStakeProfile,
-    Worker,
+    Application, BudgetPlan, Opening, OpeningPolicyCommitment, OpeningType, PlannedExpense,
+    ReviewRating, RewardChange, RewardPolicy, RoleStakeProfile, SpendingRecord, Worker,
+    WorkerReview,
 };
 use crate::RawEvent;
 use common::constraints::InputValidationLengthConstraint;
@@ -181,6 +183,8 @@ impl UpdateWorkerRewardAmountFixture {
     }
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_amount = get_worker_reward_amount(self.worker_id);
+
         let actual_result = TestWorkingGroup::update_reward_amount(
             self.origin.clone().into(),
             self.worker_id,
@@ -196,6 +200,18 @@ impl UpdateWorkerRewardAmountFixture {
             let relationship = recurringrewards::RewardRelationships::<Test>::get(relationship_id);
 
             assert_eq!(relationship.amount_per_payout, self.amount);
+
+            let reward_history = TestWorkingGroup::get_worker_reward_history(&self.worker_id);
+
+            assert_eq!(
+                reward_history.last(),
+                Some(&RewardChange {
+                    old_amount,
+                    new_amount: self.amount,
+                    review_id: None,
+                    changed_at: System::block_number(),
+                })
+            );
         }
     }
 }
@@ -461,7 +477,13 @@ pub fn get_balance(account_id: u64) -> u64 {
 
 pub fn setup_members(count: u8) {
     let authority_account_id = 1;
-    Membership::set_screening_authority(RawOrigin::Root.into(), authority_account_id).unwrap();
+    Membership::add_screening_authority(
+        RawOrigin::Root.into(),
+        authority_account_id,
+        count as u32,
+        0,
+    )
+    .unwrap();
 
     for i in 0..count {
         let account_id: u64 = i as u64;
@@ -472,6 +494,7 @@ pub fn setup_members(count: u8) {
             Some(handle.to_vec()),
             None,
             None,
+            None,
         )
         .unwrap();
     }
@@ -930,3 +953,256 @@ impl SlashWorkerStakeFixture {
         }
     }
 }
+
+pub struct SubmitBudgetPlanFixture {
+    origin: RawOrigin<u64>,
+    worker_rewards: BTreeMap<u64, u64>,
+    expenses: Vec<PlannedExpense<u64>>,
+    period: u64,
+}
+
+impl SubmitBudgetPlanFixture {
+    pub fn default_for_worker_rewards(worker_rewards: BTreeMap<u64, u64>) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_rewards,
+            expenses: vec![PlannedExpense {
+                amount: 500,
+                description: b"hosting".to_vec(),
+            }],
+            period: 100,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_expenses(self, expenses: Vec<PlannedExpense<u64>>) -> Self {
+        Self { expenses, ..self }
+    }
+
+    pub fn with_period(self, period: u64) -> Self {
+        Self { period, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_budget_plan = TestWorkingGroup::current_budget_plan();
+
+        let actual_result = TestWorkingGroup::submit_budget_plan(
+            self.origin.clone().into(),
+            self.worker_rewards.clone(),
+            self.expenses.clone(),
+            self.period,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_budget_plan = TestWorkingGroup::current_budget_plan();
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_budget_plan,
+                Some(BudgetPlan {
+                    worker_rewards: self.worker_rewards.clone(),
+                    expenses: self.expenses.clone(),
+                    period: self.period,
+                    submitted_at: System::block_number(),
+                })
+            );
+        } else {
+            assert_eq!(new_budget_plan, old_budget_plan);
+        }
+    }
+}
+
+pub struct SpendFromMintFixture {
+    origin: RawOrigin<u64>,
+    recipient: u64,
+    amount: u64,
+    rationale: Vec<u8>,
+}
+
+impl Default for SpendFromMintFixture {
+    fn default() -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            recipient: 77,
+            amount: 100,
+            rationale: b"conference sponsorship".to_vec(),
+        }
+    }
+}
+
+impl SpendFromMintFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_amount(self, amount: u64) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_rationale(self, rationale: Vec<u8>) -> Self {
+        Self { rationale, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_balance = Balances::free_balance(&self.recipient);
+        let record_id = TestWorkingGroup::next_spending_record_id();
+
+        let actual_result = TestWorkingGroup::spend_from_mint(
+            self.origin.clone().into(),
+            self.recipient,
+            self.amount,
+            self.rationale.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_balance = Balances::free_balance(&self.recipient);
+
+        if actual_result.is_ok() {
+            assert_eq!(new_balance, old_balance + self.amount);
+
+            assert_eq!(TestWorkingGroup::next_spending_record_id(), record_id + 1);
+            assert_eq!(
+                TestWorkingGroup::spending_log(record_id),
+                SpendingRecord {
+                    recipient: self.recipient,
+                    amount: self.amount,
+                    rationale: self.rationale.clone(),
+                    spent_at: System::block_number(),
+                }
+            );
+        } else {
+            assert_eq!(new_balance, old_balance);
+            assert_eq!(TestWorkingGroup::next_spending_record_id(), record_id);
+        }
+    }
+}
+
+pub(crate) fn get_worker_reward_amount(worker_id: u64) -> u64 {
+    TestWorkingGroup::worker_by_id(worker_id)
+        .reward_relationship
+        .map(|relationship_id| {
+            recurringrewards::RewardRelationships::<Test>::get(relationship_id).amount_per_payout
+        })
+        .unwrap_or_default()
+}
+
+pub struct ReviewWorkerFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    period_start: u64,
+    period_end: u64,
+    rating: ReviewRating,
+    rationale_hash: H256,
+    new_reward_amount: Option<u64>,
+    slash_amount: Option<u64>,
+}
+
+impl ReviewWorkerFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_id,
+            period_start: 0,
+            period_end: System::block_number(),
+            rating: ReviewRating::Unsatisfactory,
+            rationale_hash: H256::repeat_byte(1),
+            new_reward_amount: None,
+            slash_amount: None,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_period(self, period_start: u64, period_end: u64) -> Self {
+        Self {
+            period_start,
+            period_end,
+            ..self
+        }
+    }
+
+    pub fn with_new_reward_amount(self, new_reward_amount: u64) -> Self {
+        Self {
+            new_reward_amount: Some(new_reward_amount),
+            ..self
+        }
+    }
+
+    pub fn with_slash_amount(self, slash_amount: u64) -> Self {
+        Self {
+            slash_amount: Some(slash_amount),
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let review_id = TestWorkingGroup::next_review_id();
+        let old_reward_amount = get_worker_reward_amount(self.worker_id);
+        let old_reward_history = TestWorkingGroup::get_worker_reward_history(&self.worker_id);
+
+        let actual_result = TestWorkingGroup::review_worker(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.period_start,
+            self.period_end,
+            self.rating,
+            self.rationale_hash,
+            self.new_reward_amount,
+            self.slash_amount,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_reward_history = TestWorkingGroup::get_worker_reward_history(&self.worker_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(TestWorkingGroup::next_review_id(), review_id + 1);
+            assert_eq!(
+                TestWorkingGroup::worker_review_by_id(review_id),
+                WorkerReview {
+                    worker_id: self.worker_id,
+                    period_start: self.period_start,
+                    period_end: self.period_end,
+                    rating: self.rating,
+                    rationale_hash: self.rationale_hash,
+                    new_reward_amount: self.new_reward_amount,
+                    slashed_amount: self.slash_amount,
+                    reviewed_at: System::block_number(),
+                }
+            );
+            assert!(TestWorkingGroup::get_worker_review_ids(&self.worker_id).contains(&review_id));
+
+            if let Some(new_reward_amount) = self.new_reward_amount {
+                assert_eq!(get_worker_reward_amount(self.worker_id), new_reward_amount);
+                assert_eq!(
+                    new_reward_history.last(),
+                    Some(&RewardChange {
+                        old_amount: old_reward_amount,
+                        new_amount: new_reward_amount,
+                        review_id: Some(review_id),
+                        changed_at: System::block_number(),
+                    })
+                );
+            } else {
+                assert_eq!(new_reward_history, old_reward_history);
+            }
+        } else {
+            assert_eq!(TestWorkingGroup::next_review_id(), review_id);
+            assert_eq!(get_worker_reward_amount(self.worker_id), old_reward_amount);
+            assert_eq!(new_reward_history, old_reward_history);
+        }
+    }
+}
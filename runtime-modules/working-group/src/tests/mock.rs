@@ -138,6 +138,7 @@ parameter_types! {
 impl Trait<TestWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WorkerExitHandler = ();
 }
 
 pub type Membership = membership::Module<Test>;
@@ -8,13 +8,14 @@ use std::collections::BTreeMap;
 use system::RawOrigin;
 
 use crate::tests::hiring_workflow::HiringWorkflow;
-use crate::types::{OpeningPolicyCommitment, OpeningType, RewardPolicy};
+use crate::types::{OpeningPolicyCommitment, OpeningType, PlannedExpense, RewardPolicy};
 use crate::{Error, RawEvent, Worker};
 use common::constraints::InputValidationLengthConstraint;
 use fixtures::*;
 use mock::{
-    build_test_externalities, run_to_block, Test, TestWorkingGroup, TestWorkingGroupInstance,
-    WORKING_GROUP_CONSTRAINT_DIFF, WORKING_GROUP_CONSTRAINT_MIN, WORKING_GROUP_MINT_CAPACITY,
+    build_test_externalities, run_to_block, Membership, System, Test, TestWorkingGroup,
+    TestWorkingGroupInstance, WORKING_GROUP_CONSTRAINT_DIFF, WORKING_GROUP_CONSTRAINT_MIN,
+    WORKING_GROUP_MINT_CAPACITY,
 };
 
 #[test]
@@ -424,6 +425,27 @@ fn apply_on_opening_fails_with_not_set_members() {
     });
 }
 
+#[test]
+fn apply_on_opening_fails_with_suspended_member() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddWorkerOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        // Default applicant member id.
+        let member_id = 1;
+        assert!(Membership::suspend_member(RawOrigin::Root.into(), member_id).is_ok());
+
+        let apply_on_opening_fixture =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id);
+
+        apply_on_opening_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::MemberSuspended.into(),
+        ));
+    });
+}
+
 #[test]
 fn apply_on_opening_fails_with_hiring_error() {
     build_test_externalities().execute_with(|| {
@@ -2352,3 +2374,323 @@ fn fill_opening_cannot_hire_more_workers_using_several_applicationst_han_allows_
         hiring_workflow.execute()
     });
 }
+
+#[test]
+fn submit_budget_plan_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let worker_id = fill_default_worker_position();
+        let leader_worker_id = TestWorkingGroup::current_lead().unwrap();
+
+        let mut worker_rewards = BTreeMap::new();
+        worker_rewards.insert(worker_id, 10000);
+        worker_rewards.insert(leader_worker_id, 20000);
+
+        SubmitBudgetPlanFixture::default_for_worker_rewards(worker_rewards).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::BudgetPlanSubmitted(leader_worker_id));
+    });
+}
+
+#[test]
+fn submit_budget_plan_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        SubmitBudgetPlanFixture::default_for_worker_rewards(BTreeMap::new())
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+            ));
+    });
+}
+
+#[test]
+fn submit_budget_plan_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_worker_id = 11;
+        let mut worker_rewards = BTreeMap::new();
+        worker_rewards.insert(invalid_worker_id, 10000);
+
+        SubmitBudgetPlanFixture::default_for_worker_rewards(worker_rewards.clone())
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::WorkerDoesNotExist.into(),
+            ));
+
+        worker_rewards.insert(invalid_worker_id + 1, 10000);
+
+        SubmitBudgetPlanFixture::default_for_worker_rewards(worker_rewards).call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::BudgetPlanTooManyWorkerRewards.into(),
+        ));
+
+        SubmitBudgetPlanFixture::default_for_worker_rewards(BTreeMap::new())
+            .with_period(0)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::BudgetPeriodCannotBeZero.into(),
+            ));
+
+        SubmitBudgetPlanFixture::default_for_worker_rewards(BTreeMap::new())
+            .with_expenses(vec![PlannedExpense {
+                amount: 500,
+                description: Vec::new(),
+            }])
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::SpendingTextTooShort.into(),
+            ));
+    });
+}
+
+#[test]
+fn spend_from_mint_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        HireLeadFixture::default().hire_lead();
+
+        let cap = 1000;
+        assert_eq!(
+            TestWorkingGroup::set_discretionary_spending_cap(RawOrigin::Root.into(), cap, 10),
+            Ok(())
+        );
+
+        let mint_id = TestWorkingGroup::mint();
+        let old_capacity = <minting::Module<Test>>::mints(mint_id).capacity();
+
+        let amount = 100;
+        SpendFromMintFixture::default()
+            .with_amount(amount)
+            .call_and_assert(Ok(()));
+
+        let new_capacity = <minting::Module<Test>>::mints(mint_id).capacity();
+        assert_eq!(new_capacity, old_capacity - amount);
+        assert_eq!(
+            TestWorkingGroup::discretionary_spending().spent_in_period,
+            amount
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::SpentFromMint(0, 77, amount));
+    });
+}
+
+#[test]
+fn spend_from_mint_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        assert_eq!(
+            TestWorkingGroup::set_discretionary_spending_cap(RawOrigin::Root.into(), 1000, 10),
+            Ok(())
+        );
+
+        SpendFromMintFixture::default()
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+            ));
+
+        SpendFromMintFixture::default()
+            .with_amount(0)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::SpendingAmountCannotBeZero.into(),
+            ));
+
+        SpendFromMintFixture::default()
+            .with_rationale(Vec::new())
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::SpendingTextTooShort.into(),
+            ));
+    });
+}
+
+#[test]
+fn spend_from_mint_respects_discretionary_spending_cap() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        // No discretionary spending before the council sets the cap.
+        SpendFromMintFixture::default().call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::DiscretionarySpendingCapExceeded.into(),
+        ));
+
+        let period = 10;
+        assert_eq!(
+            TestWorkingGroup::set_discretionary_spending_cap(RawOrigin::Root.into(), 1000, period),
+            Ok(())
+        );
+
+        SpendFromMintFixture::default()
+            .with_amount(600)
+            .call_and_assert(Ok(()));
+
+        SpendFromMintFixture::default()
+            .with_amount(600)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::DiscretionarySpendingCapExceeded.into(),
+            ));
+
+        // The cap is renewed in the next spending period.
+        run_to_block(period + 1);
+
+        SpendFromMintFixture::default()
+            .with_amount(600)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::next_spending_record_id(), 2);
+    });
+}
+
+#[test]
+fn set_discretionary_spending_cap_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        assert_eq!(
+            TestWorkingGroup::set_discretionary_spending_cap(RawOrigin::None.into(), 1000, 10),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_eq!(
+            TestWorkingGroup::set_discretionary_spending_cap(RawOrigin::Root.into(), 1000, 0),
+            Err(Error::<Test, TestWorkingGroupInstance>::BudgetPeriodCannotBeZero.into())
+        );
+    });
+}
+
+#[test]
+fn review_worker_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let worker_id = fill_default_worker_position();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerReviewed(worker_id, 0));
+    });
+}
+
+#[test]
+fn review_worker_with_reward_cut_and_slash_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let stake = 100;
+        let worker_id = fill_worker_position_with_stake(stake);
+
+        run_to_block(5);
+
+        let slash_amount = 10;
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_new_reward_amount(500)
+            .with_slash_amount(slash_amount)
+            .call_and_assert(Ok(()));
+
+        let stake_id = get_worker_by_id(worker_id)
+            .role_stake_profile
+            .unwrap()
+            .stake_id;
+        let stake_after_review = <stake::Module<Test>>::stakes(stake_id);
+        assert_eq!(get_stake_balance(stake_after_review), stake - slash_amount);
+
+        // Reward changes without the review are kept in the history too.
+        UpdateWorkerRewardAmountFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let reward_history = TestWorkingGroup::get_worker_reward_history(&worker_id);
+        assert_eq!(reward_history.len(), 2);
+        assert_eq!(reward_history[0].review_id, Some(0));
+        assert_eq!(reward_history[1].review_id, None);
+    });
+}
+
+#[test]
+fn review_worker_fails_without_changes_when_stake_cannot_be_slashed() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        run_to_block(5);
+
+        let stake_id = get_worker_by_id(worker_id)
+            .role_stake_profile
+            .unwrap()
+            .stake_id;
+        <stake::Stakes<Test>>::mutate(stake_id, |stake| {
+            stake.staking_status = stake::StakingStatus::NotStaked
+        });
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_new_reward_amount(500)
+            .with_slash_amount(10)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::StakingErrorNotStaked.into(),
+            ));
+
+        assert_eq!(
+            TestWorkingGroup::num_reward_changes_by_worker_id(worker_id),
+            0
+        );
+    });
+}
+
+#[test]
+fn review_leader_succeeds_with_root_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default()
+            .with_reward_policy(RewardPolicy {
+                amount_per_payout: 1000,
+                next_payment_at_block: 20,
+                payout_interval: None,
+            })
+            .hire_lead();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_new_reward_amount(1500)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn review_worker_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_no_reward();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+            ));
+
+        let period_end_in_future = System::block_number() + 1;
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_period(0, period_end_in_future)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::InvalidReviewPeriod.into(),
+            ));
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_new_reward_amount(500)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::WorkerHasNoReward.into(),
+            ));
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_slash_amount(10)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::NoWorkerStakeProfile.into(),
+            ));
+    });
+}
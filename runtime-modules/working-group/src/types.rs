@@ -1,7 +1,8 @@
 #![warn(missing_docs)]
 
 use codec::{Decode, Encode};
-use sp_std::collections::btree_set::BTreeSet;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -249,3 +250,136 @@ pub struct RewardPolicy<Balance, BlockNumber> {
     /// Optional payout interval.
     pub payout_interval: Option<BlockNumber>,
 }
+
+/// One-off expense planned by the working group lead.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct PlannedExpense<Balance> {
+    /// Expense amount.
+    pub amount: Balance,
+
+    /// Expense description.
+    pub description: Vec<u8>,
+}
+
+/// Periodic budget plan of the working group submitted by the lead.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct BudgetPlan<WorkerId: Ord, Balance, BlockNumber> {
+    /// Planned reward totals per worker for the period.
+    pub worker_rewards: BTreeMap<WorkerId, Balance>,
+
+    /// Planned one-off expenses for the period.
+    pub expenses: Vec<PlannedExpense<Balance>>,
+
+    /// Budget period length (in blocks).
+    pub period: BlockNumber,
+
+    /// Block number when the plan was submitted.
+    pub submitted_at: BlockNumber,
+}
+
+/// Payment from the working group mint made by the lead.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct SpendingRecord<AccountId, Balance, BlockNumber> {
+    /// Payment recipient.
+    pub recipient: AccountId,
+
+    /// Payment amount.
+    pub amount: Balance,
+
+    /// Payment rationale.
+    pub rationale: Vec<u8>,
+
+    /// Block number when the payment was made.
+    pub spent_at: BlockNumber,
+}
+
+/// Per-period cap on the discretionary spending of the lead.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct DiscretionarySpendingLimit<Balance, BlockNumber> {
+    /// Maximum total of the payments per period.
+    pub cap: Balance,
+
+    /// Spending period length (in blocks).
+    pub period: BlockNumber,
+
+    /// Block number when the current period started.
+    pub period_start: BlockNumber,
+
+    /// Total of the payments in the current period.
+    pub spent_in_period: Balance,
+}
+
+/// Rating of the worker performance review.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewRating {
+    /// Performance exceeds expectations.
+    Excellent,
+
+    /// Performance meets expectations.
+    Good,
+
+    /// Performance partially meets expectations.
+    Satisfactory,
+
+    /// Performance does not meet expectations.
+    Unsatisfactory,
+}
+
+/// Must be default constructable because it indirectly is a value in a storage map.
+/// ***SHOULD NEVER ACTUALLY GET CALLED, IS REQUIRED TO DUE BAD STORAGE MODEL IN SUBSTRATE***
+impl Default for ReviewRating {
+    fn default() -> Self {
+        Self::Satisfactory
+    }
+}
+
+/// Performance review of the worker authored by the lead (or by the council for the lead).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct WorkerReview<WorkerId, BlockNumber, Hash, RewardBalance, StakeBalance> {
+    /// Reviewed worker id.
+    pub worker_id: WorkerId,
+
+    /// First block of the reviewed period.
+    pub period_start: BlockNumber,
+
+    /// Last block of the reviewed period.
+    pub period_end: BlockNumber,
+
+    /// Review rating.
+    pub rating: ReviewRating,
+
+    /// Hash of the review rationale text.
+    pub rationale_hash: Hash,
+
+    /// New reward amount set by the review.
+    pub new_reward_amount: Option<RewardBalance>,
+
+    /// Stake amount slashed by the review.
+    pub slashed_amount: Option<StakeBalance>,
+
+    /// Block number when the review was submitted.
+    pub reviewed_at: BlockNumber,
+}
+
+/// Change of the worker reward amount.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct RewardChange<Balance, BlockNumber, ReviewId> {
+    /// Reward amount per payout before the change.
+    pub old_amount: Balance,
+
+    /// Reward amount per payout after the change.
+    pub new_amount: Balance,
+
+    /// Review which caused the change, `None` for the changes made without a review.
+    pub review_id: Option<ReviewId>,
+
+    /// Block number when the reward was changed.
+    pub changed_at: BlockNumber,
+}
@@ -40,5 +40,6 @@ impl content_directory::ActorAuthenticator for Runtime {
             member_id, account_id,
         )
         .is_ok()
+            && !membership::Module::<Runtime>::is_member_suspended(member_id)
     }
 }
@@ -1,31 +1,37 @@
 /*
  * Forum module integration
  *
- * ForumUserRegistry could have been implemented directly on
- * the membership module, and likewise ForumUser on Profile,
- * however this approach is more loosely coupled.
- *
- * Further exploration required to decide what the long
- * run convention should be.
+ * Forum users are members, their origins are validated by the membership origin validator.
+ * The threads and posts authored before the member based authorship are migrated by resolving
+ * the author accounts to their member ids.
+ * Deposits slashed from the moderated threads and posts are returned to the council mint.
  */
 
-use crate::{AccountId, Runtime};
+use frame_support::traits::{Imbalance, OnUnbalanced};
+use sp_runtime::traits::Saturating;
+
+use crate::{AccountId, MemberId, Runtime};
+
+/// Resolves the member id of the forum author account for the authorship migration.
+/// Authors without the membership are left unresolved, the forum attributes them to the
+/// unknown author.
+pub fn resolve_author_member_id(account_id: &AccountId) -> Option<MemberId> {
+    membership::Module::<Runtime>::member_id_by_account(account_id)
+}
 
-/// Shim registry which will proxy ForumUserRegistry behaviour to the members module
-pub struct ShimMembershipRegistry {}
+/// Burns the slashed forum deposits and increases the council mint capacity by the slashed
+/// amount, so the council can spend it.
+pub struct SlashedDepositsToCouncilMint;
 
-impl forum::ForumUserRegistry<AccountId> for ShimMembershipRegistry {
-    fn get_forum_user(id: &AccountId) -> Option<forum::ForumUser<AccountId>> {
-        if membership::Module::<Runtime>::is_member_account(id) {
-            // For now we don't retrieve the members profile since it is not used for anything,
-            // but in the future we may need it to read out more
-            // information possibly required to construct a
-            // ForumUser.
+impl OnUnbalanced<forum::NegativeImbalance<Runtime>> for SlashedDepositsToCouncilMint {
+    fn on_nonzero_unbalanced(imbalance: forum::NegativeImbalance<Runtime>) {
+        let mint_id = governance::council::Module::<Runtime>::council_mint();
 
-            // Now convert member profile to a forum user
-            Some(forum::ForumUser { id: id.clone() })
-        } else {
-            None
+        if let Ok(capacity) = minting::Module::<Runtime>::get_mint_capacity(mint_id) {
+            let _ = minting::Module::<Runtime>::set_mint_capacity(
+                mint_id,
+                capacity.saturating_add(imbalance.peek()),
+            );
         }
     }
 }
@@ -91,9 +91,11 @@ mod tests {
             let account_id = AccountId32::default();
             let origin = RawOrigin::Signed(account_id.clone());
             let authority_account_id = AccountId32::default();
-            Membership::set_screening_authority(
+            Membership::add_screening_authority(
                 RawOrigin::Root.into(),
                 authority_account_id.clone(),
+                1,
+                0,
             )
             .unwrap();
 
@@ -103,6 +105,7 @@ mod tests {
                 Some(b"handle".to_vec()),
                 None,
                 None,
+                None,
             )
             .unwrap();
             let member_id = 0; // newly created member_id
@@ -121,9 +124,11 @@ mod tests {
             let error =
                 "Membership validation failed: given account doesn't match with profile accounts";
             let authority_account_id = AccountId32::default();
-            Membership::set_screening_authority(
+            Membership::add_screening_authority(
                 RawOrigin::Root.into(),
                 authority_account_id.clone(),
+                1,
+                0,
             )
             .unwrap();
 
@@ -133,6 +138,7 @@ mod tests {
                 Some(b"handle".to_vec()),
                 None,
                 None,
+                None,
             )
             .unwrap();
             let member_id = 0; // newly created member_id
@@ -154,9 +160,11 @@ mod tests {
             let origin = RawOrigin::Signed(account_id.clone());
             let error = "Council validation failed: account id doesn't belong to a council member";
             let authority_account_id = AccountId32::default();
-            Membership::set_screening_authority(
+            Membership::add_screening_authority(
                 RawOrigin::Root.into(),
                 authority_account_id.clone(),
+                1,
+                0,
             )
             .unwrap();
 
@@ -166,6 +174,7 @@ mod tests {
                 Some(b"handle".to_vec()),
                 None,
                 None,
+                None,
             )
             .unwrap();
             let member_id = 0; // newly created member_id
@@ -18,7 +18,7 @@ impl<T: membership::Trait>
     for MembershipOriginValidator<T>
 {
     /// Check for valid combination of origin and actor_id. Actor_id should be valid member_id of
-    /// the membership module
+    /// the membership module, suspended members are refused.
     fn ensure_actor_origin(
         origin: <T as system::Trait>::Origin,
         actor_id: MemberId<T>,
@@ -31,11 +31,16 @@ impl<T: membership::Trait>
 
         if let Ok(profile) = profile_result {
             // whether the account_id belongs to the actor
-            if profile.controller_account == account_id {
-                return Ok(account_id);
-            } else {
+            if profile.controller_account != account_id {
                 return Err("Membership validation failed: given account doesn't match with profile accounts");
             }
+
+            // suspended members can't act as members
+            if profile.suspended {
+                return Err("Membership validation failed: member is suspended");
+            }
+
+            return Ok(account_id);
         }
 
         Err("Membership validation failed: cannot find a profile for a member")
@@ -80,9 +85,11 @@ mod tests {
             let account_id = AccountId32::default();
             let origin = RawOrigin::Signed(account_id.clone());
             let authority_account_id = AccountId32::default();
-            Membership::set_screening_authority(
+            Membership::add_screening_authority(
                 RawOrigin::Root.into(),
                 authority_account_id.clone(),
+                1,
+                0,
             )
             .unwrap();
 
@@ -92,6 +99,7 @@ mod tests {
                 Some(b"handle".to_vec()),
                 None,
                 None,
+                None,
             )
             .unwrap();
             let member_id = 0; // newly created member_id
@@ -110,9 +118,11 @@ mod tests {
             let error =
                 "Membership validation failed: given account doesn't match with profile accounts";
             let authority_account_id = AccountId32::default();
-            Membership::set_screening_authority(
+            Membership::add_screening_authority(
                 RawOrigin::Root.into(),
                 authority_account_id.clone(),
+                1,
+                0,
             )
             .unwrap();
 
@@ -122,6 +132,7 @@ mod tests {
                 Some(b"handle".to_vec()),
                 None,
                 None,
+                None,
             )
             .unwrap();
             let member_id = 0; // newly created member_id
@@ -135,4 +146,39 @@ mod tests {
             assert_eq!(validation_result, Err(error));
         });
     }
+
+    #[test]
+    fn membership_origin_validator_fails_with_suspended_member() {
+        initial_test_ext().execute_with(|| {
+            let account_id = AccountId32::default();
+            let origin = RawOrigin::Signed(account_id.clone());
+            let error = "Membership validation failed: member is suspended";
+            let authority_account_id = AccountId32::default();
+            Membership::add_screening_authority(
+                RawOrigin::Root.into(),
+                authority_account_id.clone(),
+                1,
+                0,
+            )
+            .unwrap();
+
+            Membership::add_screened_member(
+                RawOrigin::Signed(authority_account_id).into(),
+                account_id,
+                Some(b"handle".to_vec()),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+            let member_id = 0; // newly created member_id
+
+            Membership::suspend_member(RawOrigin::Root.into(), member_id).unwrap();
+
+            let validation_result =
+                MembershipOriginValidator::<Runtime>::ensure_actor_origin(origin.into(), member_id);
+
+            assert_eq!(validation_result, Err(error));
+        });
+    }
 }
@@ -22,6 +22,9 @@ macro_rules! wrap_working_group_call {
                 Call::ContentDirectoryWorkingGroup($working_group_instance_call)
             }
             WorkingGroup::Storage => Call::StorageWorkingGroup($working_group_instance_call),
+            WorkingGroup::Distribution => {
+                Call::DistributionWorkingGroup($working_group_instance_call)
+            }
         }
     }};
 }
@@ -126,6 +129,18 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                     Wg::terminate_role_call(terminate_role_params)
                 )
             }
+            ProposalDetails::AddPaidMembershipTerms(fee, text) => {
+                Call::Members(membership::Call::add_paid_membership_terms(fee, text))
+            }
+            ProposalDetails::SetPaidMembershipTermsActive(paid_terms_id, active) => Call::Members(
+                membership::Call::set_paid_membership_terms_active(paid_terms_id, active),
+            ),
+            ProposalDetails::SetPaidMembershipFee(paid_terms_id, fee) => Call::Members(
+                membership::Call::set_paid_membership_fee(paid_terms_id, fee),
+            ),
+            ProposalDetails::SetNewMembershipsAllowed(allowed) => {
+                Call::Members(membership::Call::set_new_memberships_allowed(allowed))
+            }
         };
 
         call.encode()
@@ -60,7 +60,7 @@ pub use membership;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_staking::StakerStatus;
 pub use proposals_codex::ProposalsConfigParameters;
-pub use storage::{data_directory, data_object_type_registry};
+pub use storage::{data_directory, data_distribution_registry, data_object_type_registry};
 pub use versioned_store;
 pub use versioned_store_permissions;
 pub use working_group;
@@ -75,7 +75,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 7,
-    spec_version: 9,
+    spec_version: 10,
     impl_version: 0,
     apis: crate::runtime_api::EXPORTED_RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -455,8 +455,20 @@ impl stake::Trait for Runtime {
     type StakingEventsHandler = (
         crate::integration::proposals::StakingEventsHandler<Self>,
         (
-            crate::integration::working_group::ContentDirectoryWGStakingEventsHandler<Self>,
-            crate::integration::working_group::StorageWgStakingEventsHandler<Self>,
+            crate::integration::working_group::WorkingGroupStakingEventsHandler<
+                Self,
+                ContentDirectoryWorkingGroupInstance,
+            >,
+            (
+                crate::integration::working_group::WorkingGroupStakingEventsHandler<
+                    Self,
+                    StorageWorkingGroupInstance,
+                >,
+                crate::integration::working_group::WorkingGroupStakingEventsHandler<
+                    Self,
+                    DistributionWorkingGroupInstance,
+                >,
+            ),
         ),
     );
     type StakeId = u64;
@@ -487,6 +499,13 @@ impl memo::Trait for Runtime {
 
 parameter_types! {
     pub const MaxObjectsPerInjection: u32 = 100;
+    pub const StorageChallengeResponsePeriod: BlockNumber = 600; // ~1 hour
+    pub const StorageChallengePeriod: BlockNumber = 100;
+    pub const StorageMaxChallengesPerPeriod: u32 = 5;
+    pub const StorageMaxChallengeExpirationsPerBlock: u32 = 20;
+    pub const StorageChallengeDeposit: Balance = 100;
+    pub const StorageChallengeFailuresSlashThreshold: u32 = 3;
+    pub const StorageChallengeFailureSlashAmount: Balance = 0; // recommend only
 }
 
 impl storage::data_object_type_registry::Trait for Runtime {
@@ -507,6 +526,33 @@ impl storage::data_object_storage_registry::Trait for Runtime {
     type Event = Event;
     type DataObjectStorageRelationshipId = u64;
     type ContentIdExists = DataDirectory;
+    type Randomness = RandomnessCollectiveFlip;
+    type ChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type ChallengePeriod = StorageChallengePeriod;
+    type MaxChallengesPerPeriod = StorageMaxChallengesPerPeriod;
+    type MaxChallengeExpirationsPerBlock = StorageMaxChallengeExpirationsPerBlock;
+    type ChallengeDeposit = StorageChallengeDeposit;
+    type ChallengeFailuresSlashThreshold = StorageChallengeFailuresSlashThreshold;
+    type ChallengeFailureSlashAmount = StorageChallengeFailureSlashAmount;
+}
+
+parameter_types! {
+    pub const DistributionEpochLength: BlockNumber = 14400; // 24hr
+    pub const DistributionRewardPerMegabyte: Balance = 1;
+    pub const DistributionMaxRewardPerEpoch: Balance = 100_000;
+    pub const MaxBucketsPerDistributor: u32 = 100;
+    pub const DistributionMaxSettlementsPerBlock: u32 = 20;
+}
+
+impl storage::data_distribution_registry::Trait for Runtime {
+    type Event = Event;
+    type DistributionBucketId = u64;
+    type ContentIdExists = DataDirectory;
+    type EpochLength = DistributionEpochLength;
+    type RewardPerMegabyte = DistributionRewardPerMegabyte;
+    type MaxRewardPerEpoch = DistributionMaxRewardPerEpoch;
+    type MaxBucketsPerDistributor = MaxBucketsPerDistributor;
+    type MaxSettlementsPerBlock = DistributionMaxSettlementsPerBlock;
 }
 
 impl membership::Trait for Runtime {
@@ -517,9 +563,18 @@ impl membership::Trait for Runtime {
     type ActorId = ActorId;
 }
 
+parameter_types! {
+    pub const MaxForumCategoryDepth: u16 = 3;
+    pub const ForumArchivedThreadModerationPeriod: BlockNumber = DAYS;
+}
+
 impl forum::Trait for Runtime {
     type Event = Event;
-    type MembershipRegistry = integration::forum::ShimMembershipRegistry;
+    type SlashedDepositHandler = integration::forum::SlashedDepositsToCouncilMint;
+    type MaxCategoryDepth = MaxForumCategoryDepth;
+    type ArchivedThreadModerationPeriod = ForumArchivedThreadModerationPeriod;
+    type MemberId = MemberId;
+    type MemberOriginValidator = MembershipOriginValidator<Self>;
     type ThreadId = ThreadId;
     type PostId = PostId;
 }
@@ -530,6 +585,9 @@ pub type StorageWorkingGroupInstance = working_group::Instance2;
 // The content directory working group instance alias.
 pub type ContentDirectoryWorkingGroupInstance = working_group::Instance3;
 
+// The distribution working group instance alias.
+pub type DistributionWorkingGroupInstance = working_group::Instance4;
+
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
 }
@@ -537,15 +595,32 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WorkerExitHandler = Discovery;
 }
 
 impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WorkerExitHandler = ();
+}
+
+impl working_group::Trait<DistributionWorkingGroupInstance> for Runtime {
+    type Event = Event;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WorkerExitHandler = (DataDistributionRegistry, Discovery);
+}
+
+parameter_types! {
+    pub const MaxServiceEndpointsPerRecord: u32 = 10;
+    pub const MaxServiceEndpointLength: u32 = 256;
+    pub const MaxServiceRecordsPrunedPerBlock: u32 = 50;
 }
 
 impl service_discovery::Trait for Runtime {
     type Event = Event;
+    type MaxEndpointsPerRecord = MaxServiceEndpointsPerRecord;
+    type MaxEndpointLength = MaxServiceEndpointLength;
+    type MaxRecordsPrunedPerBlock = MaxServiceRecordsPrunedPerBlock;
 }
 
 parameter_types! {
@@ -681,5 +756,8 @@ construct_runtime!(
         // reserved for the future use: ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Event<T>},
         StorageWorkingGroup: working_group::<Instance2>::{Module, Call, Storage, Config<T>, Event<T>},
         ContentDirectoryWorkingGroup: working_group::<Instance3>::{Module, Call, Storage, Config<T>, Event<T>},
+        DistributionWorkingGroup: working_group::<Instance4>::{Module, Call, Storage, Config<T>, Event<T>},
+        // --- Distribution
+        DataDistributionRegistry: data_distribution_registry::{Module, Call, Storage, Event<T>},
     }
 );
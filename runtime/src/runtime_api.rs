@@ -13,8 +13,9 @@ use sp_std::vec::Vec;
 use crate::constants::PRIMARY_PROBABILITY;
 use crate::integration::content_directory::ContentDirectoryWorkingGroup;
 use crate::{
-    content_directory, AccountId, AuthorityDiscoveryId, Balance, BlockNumber, EpochDuration,
-    GrandpaAuthorityList, GrandpaId, Hash, Index, RuntimeVersion, Signature, VERSION,
+    content_directory, data_directory, AccountId, ActorId, AuthorityDiscoveryId, Balance,
+    BlockNumber, EpochDuration, GrandpaAuthorityList, GrandpaId, Hash, Index, MemberId,
+    ProposalsConfigParameters, RuntimeVersion, Signature, VERSION,
 };
 use crate::{
     AllModules, AuthorityDiscovery, Babe, Call, Grandpa, Historical, InherentDataExt,
@@ -75,6 +76,32 @@ impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
         // configured and get an initial default value of zero. This corrects this problem.
         content_directory::Module::<Runtime>::set_initial_ids_to_one();
 
+        // Forum threads and posts were authored by accounts before, replace them with the
+        // member ids of the authors.
+        forum::Module::<Runtime>::migrate_authors_to_members(
+            crate::integration::forum::resolve_author_member_id,
+        );
+
+        // Forum categories kept no subcategory counts per level, count the existing subcategories.
+        forum::Module::<Runtime>::initialize_subcategory_counts();
+
+        // Member handles were case-sensitive before, key them by the normalized handles.
+        membership::Module::<Runtime>::migrate_handles_to_normalized();
+
+        // There was a single screening authority before, keep it with a limited member quota.
+        membership::Module::<Runtime>::migrate_screening_authority(1_000);
+
+        // Paid membership terms proposals are new, set their voting and grace periods.
+        proposals_codex::Module::<Runtime>::migrate_membership_terms_proposal_periods(
+            ProposalsConfigParameters::default(),
+        );
+
+        // Data objects had no content hash and chunk manifest, convert them to the new layout.
+        data_directory::Module::<Runtime>::migrate_data_objects_to_hashed_content();
+
+        // Discovery records had no service type and endpoints, convert them to the new layout.
+        service_discovery::Module::<Runtime>::migrate_records_to_service_types();
+
         10_000_000 // TODO: adjust weight
     }
 }
@@ -226,6 +253,24 @@ impl_runtime_apis! {
         }
     }
 
+    impl service_discovery::ServiceDiscoveryApi<Block, ActorId, BlockNumber> for Runtime {
+        fn live_service_providers(
+            service_type: service_discovery::ServiceType,
+        ) -> Vec<(ActorId, service_discovery::ServiceProviderRecord<BlockNumber>)> {
+            service_discovery::Module::<Runtime>::live_service_providers(service_type)
+        }
+    }
+
+    impl membership::MembershipSubscriptionApi<Block, MemberId, BlockNumber> for Runtime {
+        fn has_active_subscription(member_id: MemberId) -> bool {
+            membership::Module::<Runtime>::has_active_subscription(&member_id)
+        }
+
+        fn subscription_expires_at(member_id: MemberId) -> Option<BlockNumber> {
+            membership::Module::<Runtime>::subscription_expires_at(member_id)
+        }
+    }
+
     impl sp_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             SessionKeys::generate(seed)
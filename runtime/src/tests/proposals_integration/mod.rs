@@ -34,8 +34,13 @@ pub type ProposalCodex = proposals_codex::Module<Runtime>;
 
 fn setup_members(count: u8) {
     let authority_account_id = <Runtime as system::Trait>::AccountId::default();
-    Membership::set_screening_authority(RawOrigin::Root.into(), authority_account_id.clone())
-        .unwrap();
+    Membership::add_screening_authority(
+        RawOrigin::Root.into(),
+        authority_account_id.clone(),
+        count as u32,
+        0,
+    )
+    .unwrap();
 
     for i in 0..count {
         let account_id: [u8; 32] = [i; 32];
@@ -45,6 +50,7 @@ fn setup_members(count: u8) {
             Some(account_id.to_vec()),
             None,
             None,
+            None,
         )
         .unwrap();
     }
@@ -657,3 +663,57 @@ fn set_validator_count_proposal_execution_succeeds() {
         assert_eq!(<pallet_staking::ValidatorCount>::get(), new_validator_count);
     });
 }
+
+#[test]
+fn add_paid_membership_terms_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let paid_terms_id = Membership::next_paid_membership_terms_id();
+        let fee = 100;
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_add_paid_membership_terms_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(50_000_u32)),
+                fee,
+                b"terms".to_vec(),
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(
+            Membership::paid_membership_terms_by_id(paid_terms_id).fee,
+            fee
+        );
+        assert!(Membership::active_paid_membership_terms().contains(&paid_terms_id));
+    });
+}
+
+#[test]
+fn set_new_memberships_allowed_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        assert!(Membership::new_memberships_allowed());
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_new_memberships_allowed_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                false,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert!(!Membership::new_memberships_allowed());
+    });
+}
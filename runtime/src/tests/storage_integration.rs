@@ -30,7 +30,8 @@ fn storage_provider_helper_succeeds() {
 
 		let account_info = service_discovery::ServiceProviderRecord{
 			identity: Vec::new(),
-			expires_at: 1000
+			expires_at: 1000,
+			..Default::default()
 		};
 
 		<service_discovery::AccountInfoByStorageProviderId<Runtime>>::insert(worker_id1,account_info.clone());
@@ -9,6 +9,7 @@ import { DiscoveryClient } from '@joystream/service-discovery'
 import Debug from 'debug'
 import chalk from 'chalk'
 import { aliceKeyPair } from './dev'
+import { computeContentHash } from '@joystream/storage-utils/contentHash'
 const debug = Debug('joystream:storage-cli:upload')
 
 // Defines maximum content length for the assets (files). Limits the upload.
@@ -18,6 +19,7 @@ const MAX_CONTENT_LENGTH = 500 * 1024 * 1024 // 500Mb
 interface AddContentParams {
   accountId: string
   ipfsCid: string
+  contentHash: string
   contentId: ContentId
   fileSize: BN
   dataObjectTypeId: number
@@ -74,6 +76,15 @@ export class UploadCommand extends BaseCommand {
     return await ipfsHash.of(file)
   }
 
+  // Reads the file from the filesystem and computes the content hash committed in the runtime.
+  private async computeContentHash(): Promise<string> {
+    const file = fs.createReadStream(this.mediaSourceFilePath).on('error', (err) => {
+      this.fail(`File read failed: ${err}`)
+    })
+
+    return await computeContentHash(file)
+  }
+
   // Read the file size from the file system.
   private getFileSize(): number {
     const stats = fs.statSync(this.mediaSourceFilePath)
@@ -98,6 +109,7 @@ export class UploadCommand extends BaseCommand {
     return {
       accountId,
       ipfsCid: await this.computeIpfsHash(),
+      contentHash: await this.computeContentHash(),
       contentId: ContentId.generate(this.api.api.registry),
       fileSize: new BN(this.getFileSize()),
       dataObjectTypeId,
@@ -114,7 +126,8 @@ export class UploadCommand extends BaseCommand {
         p.contentId,
         p.dataObjectTypeId,
         p.fileSize,
-        p.ipfsCid
+        p.ipfsCid,
+        p.contentHash
       )
 
       if (dataObject.isNone) {
@@ -20,6 +20,7 @@
 
 const debug = require('debug')('joystream:colossus:api:asset')
 const filter = require('@joystream/storage-node-backend/filter')
+const { createContentHasher } = require('@joystream/storage-utils/contentHash')
 const ipfsProxy = require('../../../lib/middleware/ipfs_proxy')
 
 function errorHandler(response, err, code) {
@@ -136,8 +137,16 @@ module.exports = function (storage, runtime, ipfsHttpGatewayUrl, anonymous) {
               return
             }
 
+            const contentHash = contentHasher.digest()
+            if (contentHash !== dataObject.content_hash.toHex()) {
+              debug('Rejecting content. Content hash does not match value in objectId')
+              await runtime.assets.rejectContent(roleAddress, providerId, id)
+              res.status(400).send({ message: "Uploaded content doesn't match content hash" })
+              return
+            }
+
             debug('accepting Content')
-            await runtime.assets.acceptContent(roleAddress, providerId, id)
+            await runtime.assets.acceptContent(roleAddress, providerId, id, contentHash)
 
             debug('creating storage relationship for newly uploaded content')
             // Create storage relationship and flip it to ready.
@@ -155,6 +164,10 @@ module.exports = function (storage, runtime, ipfsHttpGatewayUrl, anonymous) {
         })
 
         stream.on('error', (err) => errorHandler(res, err))
+
+        // Hash the uploaded content to commit to it when accepting.
+        const contentHasher = createContentHasher()
+        req.on('data', (chunk) => contentHasher.update(chunk))
         req.pipe(stream)
       } catch (err) {
         errorHandler(res, err)
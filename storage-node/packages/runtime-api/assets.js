@@ -27,11 +27,29 @@ class AssetsApi {
   }
 
   /*
-   * Create and return a data object.
+   * Create and return a data object. The content hash is the blake2-256 hash of
+   * the whole content, the chunk manifest is optional.
    */
-  async createDataObject(accountId, memberId, contentId, doTypeId, size, ipfsCid) {
+  async createDataObject(
+    accountId,
+    memberId,
+    contentId,
+    doTypeId,
+    size,
+    ipfsCid,
+    contentHash,
+    chunkManifest = null
+  ) {
     contentId = parseContentId(contentId)
-    const tx = this.base.api.tx.dataDirectory.addContent(memberId, contentId, doTypeId, size, ipfsCid)
+    const tx = this.base.api.tx.dataDirectory.addContent(
+      memberId,
+      contentId,
+      doTypeId,
+      size,
+      ipfsCid,
+      contentHash,
+      chunkManifest
+    )
     await this.base.signAndSend(accountId, tx)
 
     // If the data object constructed properly, we should now be able to return
@@ -78,11 +96,12 @@ class AssetsApi {
   }
 
   /*
-   * Sets the data object liaison judgement to Accepted
+   * Sets the data object liaison judgement to Accepted, committing to the hash
+   * of the received content.
    */
-  async acceptContent(providerAccoundId, storageProviderId, contentId) {
+  async acceptContent(providerAccoundId, storageProviderId, contentId, contentHash) {
     contentId = parseContentId(contentId)
-    const tx = this.base.api.tx.dataDirectory.acceptContent(storageProviderId, contentId)
+    const tx = this.base.api.tx.dataDirectory.acceptContent(storageProviderId, contentId, contentHash)
     return this.base.signAndSend(providerAccoundId, tx)
   }
 
@@ -0,0 +1,42 @@
+'use strict'
+
+const { blake2bInit, blake2bUpdate, blake2bFinal } = require('blakejs')
+
+// The runtime stores the blake2-256 hash of the whole content. The owner declares it when
+// adding the content and the liaison commits to it when accepting the content.
+const CONTENT_HASH_LENGTH = 32
+
+/*
+ * Creates an incremental content hasher. Feed the content with update() and
+ * get the hex encoded hash with digest().
+ */
+function createContentHasher() {
+  const context = blake2bInit(CONTENT_HASH_LENGTH)
+
+  return {
+    update(chunk) {
+      blake2bUpdate(context, chunk)
+    },
+    digest() {
+      return '0x' + Buffer.from(blake2bFinal(context)).toString('hex')
+    },
+  }
+}
+
+/*
+ * Computes the content hash of the readable stream.
+ */
+function computeContentHash(stream) {
+  return new Promise((resolve, reject) => {
+    const hasher = createContentHasher()
+
+    stream.on('data', (chunk) => hasher.update(chunk))
+    stream.on('end', () => resolve(hasher.digest()))
+    stream.on('error', reject)
+  })
+}
+
+module.exports = {
+  createContentHasher,
+  computeContentHash,
+}
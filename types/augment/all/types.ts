@@ -244,6 +244,12 @@ export interface Class extends Struct {
   readonly default_entity_creation_voucher_upper_bound: EntityId;
 }
 
+/** @name ChunkManifest */
+export interface ChunkManifest extends Struct {
+  readonly merkle_root: Hash;
+  readonly chunk_size: u64;
+}
+
 /** @name ClassId */
 export interface ClassId extends u64 {}
 
@@ -273,6 +279,9 @@ export interface ClassPermissionsType extends Null {}
 /** @name ClassPropertyValue */
 export interface ClassPropertyValue extends Null {}
 
+/** @name ContentChunkManifest */
+export interface ContentChunkManifest extends ChunkManifest {}
+
 /** @name ContentId */
 export interface ContentId extends U8aFixed {}
 
@@ -388,6 +397,8 @@ export interface DataObject extends Struct {
   readonly liaison: StorageProviderId;
   readonly liaison_judgement: LiaisonJudgement;
   readonly ipfs_content_id: Text;
+  readonly content_hash: Hash;
+  readonly chunk_manifest: Option<ChunkManifest>;
 }
 
 /** @name DataObjectsMap */
@@ -1094,6 +1105,13 @@ export interface Schema extends Struct {
 /** @name SchemaId */
 export interface SchemaId extends u16 {}
 
+/** @name ScreeningQuota */
+export interface ScreeningQuota extends Struct {
+  readonly member_quota: u32;
+  readonly members_screened: u32;
+  readonly max_initial_balance: u128;
+}
+
 /** @name SealedVote */
 export interface SealedVote extends Struct {
   readonly voter: GenericAccountId;
@@ -1,18 +1,35 @@
-import { Text, u32 } from '@polkadot/types'
+import { Null, Text, u32, Vec } from '@polkadot/types'
 import { RegistryTypes } from '@polkadot/types/types'
-import { JoyStructDecorated } from './common'
+import { JoyEnum, JoyStructDecorated } from './common'
 
 export class IPNSIdentity extends Text {}
 export class Url extends Text {}
 
+export const ServiceTypeDef = {
+  Storage: Null,
+  Distributor: Null,
+} as const
+export type ServiceTypeKey = keyof typeof ServiceTypeDef
+export class ServiceType extends JoyEnum(ServiceTypeDef) {}
+
+export class ServiceEndpoint extends JoyEnum({
+  Http: Url,
+  WebSocket: Url,
+  Libp2p: Text,
+} as const) {}
+
 export class ServiceProviderRecord extends JoyStructDecorated({
   identity: IPNSIdentity,
   expires_at: u32, // BlockNumber
+  service_type: ServiceType,
+  endpoints: Vec.with(ServiceEndpoint),
 }) {}
 
 export const discoveryTypes: RegistryTypes = {
   Url,
   IPNSIdentity,
+  ServiceType,
+  ServiceEndpoint,
   ServiceProviderRecord,
 }
 
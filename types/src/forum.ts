@@ -1,7 +1,9 @@
-import { bool, u32, u64, Text, Option, Vec as Vector } from '@polkadot/types'
-import { BlockAndTime, ThreadId, PostId, JoyStructCustom, JoyStructDecorated } from './common'
+import { bool, u32, u64, Null, Text, Option, Vec as Vector } from '@polkadot/types'
+import { BlockAndTime, ThreadId, PostId, JoyEnum, JoyStructCustom, JoyStructDecorated } from './common'
 import { RegistryTypes } from '@polkadot/types/types'
+import { Balance, BlockNumber, Hash } from '@polkadot/types/interfaces'
 import AccountId from '@polkadot/types/generic/AccountId'
+import { MemberId } from './members'
 
 export type ModerationActionType = {
   moderated_at: BlockAndTime
@@ -31,12 +33,17 @@ export class ModerationAction extends JoyStructCustom({
 
 export type PostTextChangeType = {
   expired_at: BlockAndTime
-  text: Text
+  text: PostRevisionText
 }
 
+export class PostRevisionText extends JoyEnum({
+  Text: Text,
+  Hash: Hash,
+}) {}
+
 export class PostTextChange extends JoyStructCustom({
   expired_at: BlockAndTime,
-  text: Text,
+  text: PostRevisionText,
 })
 // FIXME: Make it JoyStructDecorated compatible
 {
@@ -44,8 +51,8 @@ export class PostTextChange extends JoyStructCustom({
     return this.getField('expired_at')
   }
 
-  get text(): string {
-    return this.getString('text')
+  get text(): PostRevisionText {
+    return this.getField('text')
   }
 }
 
@@ -187,7 +194,7 @@ export type ThreadType = {
   num_unmoderated_posts: u32
   num_moderated_posts: u32
   created_at: BlockAndTime
-  author_id: AccountId
+  author_id: MemberId
 }
 
 export class Thread extends JoyStructCustom({
@@ -199,7 +206,7 @@ export class Thread extends JoyStructCustom({
   num_unmoderated_posts: u32,
   num_moderated_posts: u32,
   created_at: BlockAndTime,
-  author_id: AccountId,
+  author_id: MemberId,
 })
 // FIXME: Make it JoyStructDecorated compatible
 {
@@ -243,11 +250,29 @@ export class Thread extends JoyStructCustom({
     return this.getField('created_at')
   }
 
-  get author_id(): AccountId {
+  get author_id(): MemberId {
     return this.getField('author_id')
   }
 }
 
+export const PostReactionDef = {
+  Like: Null,
+  Dislike: Null,
+  Insightful: Null,
+  Funny: Null,
+} as const
+export type PostReactionKey = keyof typeof PostReactionDef
+export class PostReaction extends JoyEnum(PostReactionDef) {}
+
+export class PostReactionCounts extends JoyStructDecorated({
+  like: u32,
+  dislike: u32,
+  insightful: u32,
+  funny: u32,
+}) {}
+
+export class OptionPostId extends Option.with(PostId) {}
+
 export type PostType = {
   id: PostId
   thread_id: ThreadId
@@ -256,7 +281,11 @@ export type PostType = {
   moderation: OptionModerationAction
   text_change_history: VecPostTextChange
   created_at: BlockAndTime
-  author_id: AccountId
+  author_id: MemberId
+  reaction_counts: PostReactionCounts
+  reply_to: OptionPostId
+  edit_count: u32
+  deleted: bool
 }
 
 // TODO deprectated: replaced w/ Post
@@ -268,7 +297,11 @@ export class Post extends JoyStructCustom({
   moderation: OptionModerationAction,
   text_change_history: VecPostTextChange,
   created_at: BlockAndTime,
-  author_id: AccountId,
+  author_id: MemberId,
+  reaction_counts: PostReactionCounts,
+  reply_to: OptionPostId,
+  edit_count: u32,
+  deleted: bool,
 })
 // FIXME: Make it JoyStructDecorated compatible
 {
@@ -304,9 +337,25 @@ export class Post extends JoyStructCustom({
     return this.getField('created_at')
   }
 
-  get author_id(): AccountId {
+  get author_id(): MemberId {
     return this.getField('author_id')
   }
+
+  get reaction_counts(): PostReactionCounts {
+    return this.getField('reaction_counts')
+  }
+
+  get reply_to(): PostId | null {
+    return this.getField('reply_to').unwrapOr(null)
+  }
+
+  get edit_count(): u32 {
+    return this.getField('edit_count')
+  }
+
+  get deleted(): boolean {
+    return this.getField('deleted').valueOf()
+  }
 }
 
 export type ReplyType = {
@@ -346,7 +395,46 @@ export class Reply extends JoyStructCustom({
   }
 }
 
+export class PollAlternative extends JoyStructDecorated({
+  text: Text,
+  vote_count: u32,
+}) {}
+
+export class Poll extends JoyStructDecorated({
+  description: Text,
+  end_block: BlockNumber,
+  alternatives: Vector.with(PollAlternative),
+}) {}
+
+export class PollInput extends JoyStructDecorated({
+  description: Text,
+  end_block: BlockNumber,
+  alternatives: Vector.with(Text),
+}) {}
+
+export const BanScopeDef = {
+  Forum: Null,
+  Category: CategoryId,
+} as const
+export type BanScopeKey = keyof typeof BanScopeDef
+export class BanScope extends JoyEnum(BanScopeDef) {}
+
+export class Ban extends JoyStructDecorated({
+  expires_at: Option.with(BlockNumber),
+}) {}
+
+export class PostingActivity extends JoyStructDecorated({
+  period_started_at: BlockNumber,
+  count: u32,
+}) {}
+
+export class Deposit extends JoyStructDecorated({
+  account_id: AccountId,
+  amount: Balance,
+}) {}
+
 export const forumTypes: RegistryTypes = {
+  PostRevisionText,
   PostTextChange,
   ModerationAction,
   ChildPositionInParentCategory,
@@ -356,6 +444,15 @@ export const forumTypes: RegistryTypes = {
   Post,
   ReplyId,
   Reply,
+  PollAlternative,
+  Poll,
+  PollInput,
+  PostReaction,
+  PostReactionCounts,
+  BanScope,
+  Ban,
+  PostingActivity,
+  Deposit,
 }
 
 export default forumTypes
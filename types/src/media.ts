@@ -1,7 +1,7 @@
 import { Option, Vec as Vector, BTreeMap, u64, bool, Text, Null } from '@polkadot/types'
 import { BlockAndTime, JoyEnum, JoyStructDecorated, Hash } from './common'
 import { MemberId } from './members'
-import { StorageProviderId } from './working-group' // this should be in discovery really
+import { StorageProviderId, WorkerId } from './working-group' // this should be in discovery really
 import { randomAsU8a } from '@polkadot/util-crypto'
 import { encodeAddress, decodeAddress } from '@polkadot/keyring'
 import { RegistryTypes, Registry } from '@polkadot/types/types'
@@ -40,6 +40,11 @@ export const LiaisonJudgementDef = {
 export type LiaisonJudgementKey = keyof typeof LiaisonJudgementDef
 export class LiaisonJudgement extends JoyEnum(LiaisonJudgementDef) {}
 
+export class ChunkManifest extends JoyStructDecorated({
+  merkle_root: Hash,
+  chunk_size: u64,
+}) {}
+
 export class DataObject extends JoyStructDecorated({
   owner: MemberId,
   added_at: BlockAndTime,
@@ -48,6 +53,8 @@ export class DataObject extends JoyStructDecorated({
   liaison: StorageProviderId,
   liaison_judgement: LiaisonJudgement,
   ipfs_content_id: Text,
+  content_hash: Hash,
+  chunk_manifest: Option.with(ChunkManifest),
 }) {
   /** Actually it's 'size', but 'size' is already reserved by a parent class. */
   get size_in_bytes(): u64 {
@@ -68,15 +75,31 @@ export class DataObjectType extends JoyStructDecorated({
 
 export class DataObjectsMap extends BTreeMap.with(ContentId, DataObject) {}
 
+export class DistributionBucketId extends u64 {}
+
+export class DistributionBucket extends JoyStructDecorated({
+  distributors: Vector.with(WorkerId),
+  content_count: u64,
+}) {}
+
+export class Distributor extends JoyStructDecorated({
+  buckets: Vector.with(DistributionBucketId),
+}) {}
+
 export const mediaTypes: RegistryTypes = {
   ContentId,
   LiaisonJudgement,
+  ChunkManifest,
+  ContentChunkManifest: ChunkManifest,
   DataObject,
   DataObjectStorageRelationshipId,
   DataObjectStorageRelationship,
   DataObjectTypeId,
   DataObjectType,
   DataObjectsMap,
+  DistributionBucketId,
+  DistributionBucket,
+  Distributor,
 }
 
 export default mediaTypes
@@ -1,4 +1,4 @@
-import { Option, Null, bool, u32, u64, u128, Text } from '@polkadot/types'
+import { Option, Null, bool, u32, u64, u128, Text, Vec } from '@polkadot/types'
 import { BlockNumber, Moment } from '@polkadot/types/interfaces'
 import AccountId from '@polkadot/types/generic/AccountId'
 import { RegistryTypes } from '@polkadot/types/types'
@@ -12,10 +12,12 @@ export class ActorId extends u64 {}
 export class Paid extends PaidTermId {}
 export class Screening extends AccountId {}
 export class Genesis extends Null {}
+export class Invited extends MemberId {}
 export class EntryMethod extends JoyEnum({
   Paid,
   Screening,
   Genesis,
+  Invited,
 }) {}
 
 export type IMembership = {
@@ -50,6 +52,47 @@ export class PaidMembershipTerms extends JoyStructDecorated({
   text: Text,
 }) {}
 
+export class SubscriptionPlan extends JoyStructDecorated({
+  price: u128, // BalanceOf
+  period: BlockNumber,
+  active: bool,
+}) {}
+
+export class ScreeningQuota extends JoyStructDecorated({
+  member_quota: u32,
+  members_screened: u32,
+  max_initial_balance: u128, // BalanceOf
+}) {}
+
+export class ProfileVerification extends JoyStructDecorated({
+  verified: bool,
+  verifier: AccountId,
+  rationale: Text,
+}) {}
+
+export class IdentityClaim extends JoyStructDecorated({
+  service: Text,
+  username: Text,
+  proof: Text,
+}) {}
+
+export class HandleTransferOffer extends JoyStructDecorated({
+  to_member_id: MemberId,
+  replacement_handle: Text,
+}) {}
+
+export class RecoveryConfig extends JoyStructDecorated({
+  friends: Vec.with(MemberId),
+  threshold: u32,
+  delay_period: BlockNumber,
+}) {}
+
+export class ActiveRecovery extends JoyStructDecorated({
+  new_root_account: AccountId,
+  initiated_at: BlockNumber,
+  vouchers: Vec.with(MemberId),
+}) {}
+
 export const membersTypes: RegistryTypes = {
   EntryMethod,
   MemberId,
@@ -57,6 +100,13 @@ export const membersTypes: RegistryTypes = {
   SubscriptionId,
   Membership,
   PaidMembershipTerms,
+  SubscriptionPlan,
+  ScreeningQuota,
+  ProfileVerification,
+  IdentityClaim,
+  HandleTransferOffer,
+  RecoveryConfig,
+  ActiveRecovery,
   ActorId,
 }
 
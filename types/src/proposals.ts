@@ -3,7 +3,7 @@ import { bool, u128 } from '@polkadot/types/primitive'
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
 import AccountId from '@polkadot/types/generic/AccountId'
 import { ThreadId, WorkingGroup, JoyEnum, JoyStructDecorated } from './common'
-import { MemberId } from './members'
+import { MemberId, PaidTermId } from './members'
 import { RoleParameters } from './roles'
 import { StakeId } from './stake'
 import { ElectionParameters } from './council'
@@ -267,6 +267,10 @@ export class ProposalDetails extends JoyEnum({
   SlashWorkingGroupLeaderStake: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   SetWorkingGroupLeaderReward: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   TerminateWorkingGroupLeaderRole: TerminateRoleParameters,
+  AddPaidMembershipTerms: Tuple.with(['Balance', Bytes]),
+  SetPaidMembershipTermsActive: Tuple.with([PaidTermId, bool]),
+  SetPaidMembershipFee: Tuple.with([PaidTermId, 'Balance']),
+  SetNewMembershipsAllowed: bool,
 } as const) {}
 
 // export default proposalTypes;
@@ -1,7 +1,7 @@
-import { Bytes, BTreeMap, BTreeSet, Option } from '@polkadot/types'
-import { Null, u32, u128 } from '@polkadot/types/primitive'
+import { Bytes, BTreeMap, BTreeSet, Option, Vec } from '@polkadot/types'
+import { Null, u32, u64, u128 } from '@polkadot/types/primitive'
 import AccountId from '@polkadot/types/generic/AccountId'
-import { BlockNumber, Balance } from '@polkadot/types/interfaces'
+import { BlockNumber, Balance, Hash } from '@polkadot/types/interfaces'
 import { MemberId, ActorId } from '../members'
 import { RewardRelationshipId } from '../recurring-rewards'
 import { StakeId } from '../stake'
@@ -159,6 +159,61 @@ export class RewardPolicy
   })
   implements IRewardPolicy {}
 
+export class SpendingRecordId extends u64 {}
+
+export class PlannedExpense extends JoyStructDecorated({
+  amount: u128, // Balance
+  description: Bytes,
+}) {}
+
+export class BudgetPlan extends JoyStructDecorated({
+  worker_rewards: BTreeMap.with(WorkerId, u128), // BTreeMap<WorkerId, Balance>
+  expenses: Vec.with(PlannedExpense),
+  period: u32, // BlockNumber
+  submitted_at: u32, // BlockNumber
+}) {}
+
+export class SpendingRecord extends JoyStructDecorated({
+  recipient: AccountId,
+  amount: u128, // Balance
+  rationale: Bytes,
+  spent_at: u32, // BlockNumber
+}) {}
+
+export class DiscretionarySpendingLimit extends JoyStructDecorated({
+  cap: u128, // Balance
+  period: u32, // BlockNumber
+  period_start: u32, // BlockNumber
+  spent_in_period: u128, // Balance
+}) {}
+
+export class ReviewId extends u64 {}
+
+export class ReviewRating extends JoyEnum({
+  Excellent: Null,
+  Good: Null,
+  Satisfactory: Null,
+  Unsatisfactory: Null,
+} as const) {}
+
+export class WorkerReview extends JoyStructDecorated({
+  worker_id: WorkerId,
+  period_start: u32, // BlockNumber
+  period_end: u32, // BlockNumber
+  rating: ReviewRating,
+  rationale_hash: Hash,
+  new_reward_amount: Option.with(u128), // Option<Balance>
+  slashed_amount: Option.with(u128), // Option<Balance>
+  reviewed_at: u32, // BlockNumber
+}) {}
+
+export class RewardChange extends JoyStructDecorated({
+  old_amount: u128, // Balance
+  new_amount: u128, // Balance
+  review_id: Option.with(ReviewId),
+  changed_at: u32, // BlockNumber
+}) {}
+
 // Needed for types augment tool
 export { OpeningId, ApplicationId }
 
@@ -178,6 +233,15 @@ export const workingGroupTypes: RegistryTypes = {
   // Expose in registry for api.createType purposes:
   WorkingGroupOpeningPolicyCommitment,
   RoleStakeProfile,
+  SpendingRecordId,
+  PlannedExpense,
+  BudgetPlanOf: BudgetPlan,
+  SpendingRecordOf: SpendingRecord,
+  DiscretionarySpendingLimitOf: DiscretionarySpendingLimit,
+  ReviewId,
+  ReviewRating,
+  WorkerReviewOf: WorkerReview,
+  RewardChangeOf: RewardChange,
 }
 
 export default workingGroupTypes